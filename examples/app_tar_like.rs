@@ -48,9 +48,11 @@ fn main() {
             "extract-to-dir",
             "Extract an archive in a target folder",
             FormView::new()
+                .description("Unpacks files from an archive into a directory.")
                 .field(
                     Autocomplete::new("archive-path", DirItems::new())
                         .help("Path to compressed file")
+                        .placeholder("e.g. archive.tar.gz")
                         .validator(Required)
                         .validator(FileExists),
                 )
@@ -0,0 +1,178 @@
+//! Derive macro implementing [fui::model::FormModel] for structs.
+//!
+//! It's re-exported by `fui` (with `derive` feature), see [fui::model] for details.
+//!
+//! [fui::model::FormModel]: ../fui/model/trait.FormModel.html
+//! [fui::model]: ../fui/model/index.html
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+#[macro_use]
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{Data, DeriveInput, Error, Fields, GenericArgument, Lit, Meta, NestedMeta, PathArguments,
+          Type};
+
+/// Derives `FormModel` building form with a field for each of struct's fields.
+#[proc_macro_derive(FormModel, attributes(field))]
+pub fn derive_form_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => return Err(Error::new_spanned(input, "FormModel needs named fields")),
+        },
+        _ => return Err(Error::new_spanned(input, "FormModel can be derived only for structs")),
+    };
+    let mut widgets = Vec::new();
+    let mut values = Vec::new();
+    for field in fields.iter() {
+        let ident = field.ident.as_ref().unwrap();
+        let options = parse_options(field)?;
+        let label = &options.label;
+        let (constructor, optional) = constructor(&field.ty, &options.widget)?;
+        let help = options.help.iter();
+        let mut validators = options.validators;
+        if !optional && is_number(&field.ty) {
+            validators.insert(0, quote!(Required));
+        }
+        widgets.push(quote! {
+            { use ::fui::fields::*; #constructor(#label) }
+                #(.help(#help))*
+                #(.validator({ use ::fui::validators::*; #validators }))*
+        });
+        values.push(quote! {
+            #ident: ::fui::model::FormValue::from_value(&data[#label])
+                .map_err(|e| format!("{}: {}", #label, e))?
+        });
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::fui::model::FormModel for #name #ty_generics #where_clause {
+            fn form() -> ::fui::form::FormView {
+                ::fui::form::FormView::new()
+                    #(.field(#widgets))*
+            }
+            fn from_data(data: &::fui::Value) -> Result<Self, String> {
+                Ok(#name {
+                    #(#values),*
+                })
+            }
+        }
+    })
+}
+
+/// Options of struct's field set with `#[field(...)]`.
+struct FieldOptions {
+    label: String,
+    help: Option<String>,
+    widget: Option<TokenStream2>,
+    validators: Vec<TokenStream2>,
+}
+
+fn parse_options(field: &syn::Field) -> syn::Result<FieldOptions> {
+    let ident = field.ident.as_ref().unwrap();
+    let mut options = FieldOptions {
+        label: ident.to_string().replace('_', "-"),
+        help: None,
+        widget: None,
+        validators: Vec::new(),
+    };
+    let attrs = field
+        .attrs
+        .iter()
+        .filter(|a| a.path.segments.len() == 1 && a.path.segments[0].ident == "field");
+    for attr in attrs {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(Error::new_spanned(meta, "expected #[field(name = \"value\")]")),
+        };
+        for nested in list.nested.iter() {
+            let (name, value) = match *nested {
+                NestedMeta::Meta(Meta::NameValue(ref pair)) => match pair.lit {
+                    Lit::Str(ref value) => (pair.ident.to_string(), value.value()),
+                    ref lit => return Err(Error::new_spanned(lit, "expected string")),
+                },
+                ref other => return Err(Error::new_spanned(other, "expected name = \"value\"")),
+            };
+            let tokens = || {
+                value
+                    .parse::<TokenStream2>()
+                    .map_err(|_| Error::new_spanned(nested, "expected Rust expression"))
+            };
+            match name.as_str() {
+                "label" => options.label = value.clone(),
+                "help" => options.help = Some(value.clone()),
+                "widget" => options.widget = Some(tokens()?),
+                "validator" => options.validators.push(tokens()?),
+                _ => {
+                    let msg = "unknown option, expected: label, help, widget, validator";
+                    return Err(Error::new_spanned(nested, msg));
+                }
+            }
+        }
+    }
+    Ok(options)
+}
+
+/// Splits type to name of its last segment and its first generic argument (e.g. `Option<u8>`
+/// gives `Option` & `u8`).
+fn split_type(ty: &Type) -> Option<(String, Option<&Type>)> {
+    let segment = match *ty {
+        Type::Path(ref path) => path.path.segments.last()?.into_value(),
+        _ => return None,
+    };
+    let argument = match segment.arguments {
+        PathArguments::AngleBracketed(ref args) => args.args
+            .iter()
+            .filter_map(|a| match *a {
+                GenericArgument::Type(ref ty) => Some(ty),
+                _ => None,
+            })
+            .next(),
+        _ => None,
+    };
+    Some((segment.ident.to_string(), argument))
+}
+
+fn is_number(ty: &Type) -> bool {
+    let numbers = [
+        "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize", "f32", "f64",
+    ];
+    match split_type(ty) {
+        Some((name, _)) => numbers.contains(&name.as_str()),
+        None => false,
+    }
+}
+
+/// Finds constructor of field for `ty` (unless `widget` is set) and tells if it's optional.
+fn constructor(ty: &Type, widget: &Option<TokenStream2>) -> syn::Result<(TokenStream2, bool)> {
+    let unsupported = || Error::new_spanned(ty, "unsupported type, set widget of the field");
+    let (name, argument) = split_type(ty).ok_or_else(unsupported)?;
+    if name == "Option" {
+        let inner = argument.ok_or_else(unsupported)?;
+        return constructor(inner, widget).map(|(c, _)| (c, true));
+    }
+    if let Some(ref widget) = *widget {
+        return Ok((quote!(#widget::new), false));
+    }
+    let constructor = match name.as_str() {
+        "String" => quote!(Text::new),
+        "bool" => quote!(Checkbox::new),
+        "f32" | "f64" => quote!(Number::float),
+        "Vec" => quote!(Tags::new),
+        _ if is_number(ty) => quote!(Number::integer),
+        _ => return Err(unsupported()),
+    };
+    Ok((constructor, false))
+}
@@ -0,0 +1,57 @@
+extern crate fui;
+extern crate serde_json;
+
+use fui::model::FormModel;
+
+#[derive(Debug, FormModel, PartialEq)]
+struct Archive {
+    #[field(help = "Files to archive", validator = "Required")]
+    files: Vec<String>,
+    #[field(validator = "Between(1, 9)")]
+    level: u8,
+    compression: Option<f64>,
+    #[field(label = "dry", help = "Only print what would be done")]
+    dry_run: bool,
+    #[field(widget = "Multiline")]
+    comment: String,
+}
+
+#[test]
+fn form_is_built_from_struct() {
+    let form = Archive::form();
+    let args = form.fields2clap_args();
+    let names = args.iter().map(|a| a.b.name).collect::<Vec<&str>>();
+    assert_eq!(names, vec!["files", "level", "compression", "dry", "no-dry", "comment"]);
+    assert_eq!(args[0].b.help, Some("Files to archive"));
+
+    let form = form.with_values(&[("files", "a.txt"), ("level", "10")]);
+    let errors = form.validate_all().unwrap_err();
+    assert!(errors.contains_key("level"));
+    assert!(!errors.contains_key("files"));
+    assert!(!errors.contains_key("compression"));
+}
+
+#[test]
+fn struct_is_built_from_data() {
+    let form = Archive::form().with_values(&[
+        ("files", "a.txt"),
+        ("level", "3"),
+        ("dry", "true"),
+        ("comment", "first\nsecond"),
+    ]);
+    let data = form.validate_all().unwrap();
+    let archive = Archive {
+        files: vec!["a.txt".to_string()],
+        level: 3,
+        compression: None,
+        dry_run: true,
+        comment: "first\nsecond".to_string(),
+    };
+    assert_eq!(Archive::from_data(&data), Ok(archive));
+
+    let data = serde_json::from_str(r#"{ "files": [], "level": 300, "dry": false }"#).unwrap();
+    assert_eq!(
+        Archive::from_data(&data),
+        Err("level: Value must fit in u8".to_string())
+    );
+}
@@ -1,9 +1,11 @@
 //! Data providers for `views` with suggestion feature (like `Autocomplete`, `Multiselect`).
 
-use glob::{glob_with, MatchOptions};
+use glob::{glob_with, GlobError, MatchOptions};
+use std::cell::RefCell;
+use std::cmp;
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::fmt::Display;
 use std::rc::Rc;
 
@@ -31,10 +33,22 @@ enum DirItemType {
 /// let only_dirs = DirItems::dirs(); // suggests only dirs
 /// # }
 /// ```
+///
+/// Items which can't be read (missing permissions, removed dir, etc.) are skipped and the problem
+/// is available through [last_error].
+///
+/// [last_error]: #method.last_error
 #[derive(Clone, Debug)]
 pub struct DirItems {
     dir_item_type: DirItemType,
     use_full_paths: bool,
+    last_error: RefCell<Option<String>>,
+}
+
+impl Default for DirItems {
+    fn default() -> Self {
+        DirItems::new()
+    }
 }
 
 impl DirItems {
@@ -43,6 +57,7 @@ impl DirItems {
         DirItems {
             dir_item_type: DirItemType::All,
             use_full_paths: false,
+            last_error: RefCell::new(None),
         }
     }
     /// Creates a new `DirItems` which suggests only dirs.
@@ -50,6 +65,7 @@ impl DirItems {
         DirItems {
             dir_item_type: DirItemType::Dir,
             use_full_paths: false,
+            last_error: RefCell::new(None),
         }
     }
 
@@ -58,6 +74,29 @@ impl DirItems {
         self.use_full_paths = true;
         self
     }
+
+    /// Returns IO error which occurred during the last `query` (if any).
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.borrow().clone()
+    }
+
+    /// Converts globbed `entry` to suggestion, `None` means `entry` doesn't match item type.
+    fn entry2item(&self, entry: Result<PathBuf, GlobError>) -> Result<Option<String>, String> {
+        let path = entry.map_err(|e| format!("{}", e))?;
+        if let DirItemType::Dir = self.dir_item_type {
+            let metadata = path.metadata()
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+            if !metadata.is_dir() {
+                return Ok(None);
+            }
+        }
+        let path = if self.use_full_paths {
+            fs::canonicalize(&path).map_err(|e| format!("{}: {}", path.display(), e))?
+        } else {
+            path
+        };
+        Ok(Some(format!("{}", path.display())))
+    }
 }
 
 /// Add star to last component of path.
@@ -66,7 +105,7 @@ fn add_glob<P: AsRef<str>>(path: P) -> String {
         return format!("{}*", path.as_ref());
     }
     let as_path = Path::new(path.as_ref());
-    if let Some(c) = as_path.components().last() {
+    if let Some(c) = as_path.components().next_back() {
         let last = c.as_os_str().to_str().unwrap();
         let converted = if !last.contains('*') {
             let last = if last == "/" {
@@ -86,13 +125,19 @@ fn add_glob<P: AsRef<str>>(path: P) -> String {
 
 impl Feeder for DirItems {
     fn query(&self, text: &str, position: usize, items_count: usize) -> Vec<String> {
-        let path = if text == "" {
-            format!("./")
+        *self.last_error.borrow_mut() = None;
+        let path = if text.is_empty() {
+            "./".to_string()
         } else if text.starts_with('~') {
-            let path = text.replace("~", env::home_dir().unwrap().to_str().unwrap());
-            format!("{}", path)
+            match env::home_dir() {
+                Some(home) => text.replace("~", &format!("{}", home.display())),
+                None => {
+                    *self.last_error.borrow_mut() = Some("Can't find home dir".to_string());
+                    return Vec::new();
+                }
+            }
         } else {
-            format!("{}", text)
+            text.to_string()
         };
         let path = add_glob(path);
         if let Ok(v) = glob_with(
@@ -103,31 +148,13 @@ impl Feeder for DirItems {
                 require_literal_leading_dot: true,
             },
         ) {
-            v.filter(|x| {
-                if let Err(e) = x.as_ref() {
-                    eprintln!("{:?}", e);
-                    false
-                } else {
-                    true
+            v.filter_map(|x| match self.entry2item(x) {
+                Ok(item) => item,
+                Err(e) => {
+                    *self.last_error.borrow_mut() = Some(e);
+                    None
                 }
-            }).filter(|x| {
-                    let path = x.as_ref().unwrap().metadata().unwrap();
-                    match self.dir_item_type {
-                        DirItemType::Dir => path.is_dir(),
-                        DirItemType::All => true,
-                    }
-                })
-                .map(|x| {
-                    let path = x.unwrap();
-                    let path = if self.use_full_paths {
-                        fs::canonicalize(path).unwrap()
-                    } else {
-                        path
-                    };
-                    let text = format!("{}", path.display());
-                    text
-                })
-                .skip(position)
+            }).skip(position)
                 .take(items_count)
                 .collect()
         } else {
@@ -136,6 +163,100 @@ impl Feeder for DirItems {
     }
 }
 
+/// Suggests `items` matching query fuzzily (fzf-style): query's chars have to appear in an item
+/// in the same order, but not necessarily next to each other (e.g. `xtr` matches `extract`).
+///
+/// Suggestions are sorted from the best match: consecutive chars and chars starting words score
+/// more, gaps between chars score less (shorter items win ties).
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Feeder, Fuzzy};
+/// # fn main() {
+/// let feeder = Fuzzy::new(vec!["remote add", "rename", "reset"]);
+/// assert_eq!(feeder.query("ra", 0, 10), vec!["remote add", "rename"]);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Fuzzy {
+    items: Vec<String>,
+}
+
+impl Fuzzy {
+    /// Creates a new `Fuzzy` suggesting `items`.
+    pub fn new<T: Display>(items: Vec<T>) -> Self {
+        Fuzzy {
+            items: items.iter().map(|x| format!("{}", x)).collect(),
+        }
+    }
+}
+
+/// Scores how well `item` matches `query` (`None` means it doesn't match).
+fn fuzzy_score(item: &str, query: &str) -> Option<i64> {
+    let item = item.to_lowercase().chars().collect::<Vec<char>>();
+    let mut score = 0;
+    let mut next = 0;
+    let mut last: Option<usize> = None;
+    for q in query.to_lowercase().chars() {
+        let idx = (next..item.len()).find(|&idx| item[idx] == q)?;
+        score += 1;
+        if idx == 0 || !item[idx - 1].is_alphanumeric() {
+            score += 3;
+        }
+        match last {
+            Some(last) if last + 1 == idx => score += 5,
+            // long gaps are as bad as short ones, so word starts far away still win
+            Some(last) => score -= cmp::min(idx - last - 1, 3) as i64,
+            None => {}
+        }
+        last = Some(idx);
+        next = idx + 1;
+    }
+    Some(score)
+}
+
+impl Feeder for Fuzzy {
+    fn query(&self, text: &str, position: usize, items_count: usize) -> Vec<String> {
+        let mut found = self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, item)| fuzzy_score(item, text).map(|score| (score, idx)))
+            .collect::<Vec<(i64, usize)>>();
+        // the best first, then the shorter one, then the first of items
+        if !text.is_empty() {
+            let len = |idx: usize| self.items[idx].len();
+            found.sort_by(|a, b| {
+                b.0.cmp(&a.0)
+                    .then(len(a.1).cmp(&len(b.1)))
+                    .then(a.1.cmp(&b.1))
+            });
+        }
+        found
+            .into_iter()
+            .skip(position)
+            .take(items_count)
+            .map(|(_, idx)| self.items[idx].clone())
+            .collect()
+    }
+}
+
+impl<T: Display + 'static> Feeder for Vec<T> {
+    fn query(&self, text: &str, position: usize, items_count: usize) -> Vec<String> {
+        self.iter()
+            .map(|x| format!("{}", x))
+            .filter(|x| x.to_lowercase().contains(text))
+            .skip(position)
+            .take(items_count)
+            .collect()
+    }
+}
+
+impl Feeder for Rc<Feeder> {
+    fn query(&self, text: &str, position: usize, items_count: usize) -> Vec<String> {
+        (**self).query(text, position, items_count)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,21 +380,53 @@ mod tests {
             HashSet::<String>::new()
         );
     }
-}
 
-impl<T: Display + 'static> Feeder for Vec<T> {
-    fn query(&self, text: &str, position: usize, items_count: usize) -> Vec<String> {
-        self.iter()
-            .map(|x| format!("{}", x))
-            .filter(|x| x.to_lowercase().contains(text))
-            .skip(position)
-            .take(items_count)
-            .collect()
+    #[test]
+    fn test_dir_item_has_no_error_when_dir_is_readable() {
+        let di = DirItems::new();
+        di.query("", 0, 100);
+        assert_eq!(di.last_error(), None);
     }
-}
 
-impl Feeder for Rc<Feeder> {
-    fn query(&self, text: &str, position: usize, items_count: usize) -> Vec<String> {
-        (**self).query(text, position, items_count)
+    #[cfg(unix)]
+    #[test]
+    fn test_dir_item_works_with_unreadable_dir() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = env::temp_dir().join(format!("fui-unreadable-{}", std::process::id()));
+        fs::create_dir_all(dir.join("subdir")).unwrap();
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o000)).unwrap();
+        // permissions are ignored (e.g. for root), so there is nothing to check
+        let is_readable = fs::read_dir(&dir).is_ok();
+
+        let di = DirItems::new();
+        let found = di.query(&format!("{}/", dir.display()), 0, 10);
+
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o755)).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        if is_readable {
+            return;
+        }
+        assert_eq!(found, Vec::<String>::new());
+        assert!(di.last_error().is_some());
+    }
+
+    #[test]
+    fn fuzzy_matches_chars_in_order() {
+        let feeder = Fuzzy::new(vec!["extract", "archive", "list", "Extract all"]);
+        assert_eq!(feeder.query("xtr", 0, 10), vec!["extract", "Extract all"]);
+        assert_eq!(feeder.query("rtx", 0, 10), Vec::<String>::new());
+        assert_eq!(feeder.query("", 0, 2), vec!["extract", "archive"]);
+        assert_eq!(feeder.query("", 2, 10), vec!["list", "Extract all"]);
+    }
+
+    #[test]
+    fn fuzzy_prefers_consecutive_chars_and_word_starts() {
+        let feeder = Fuzzy::new(vec!["remove tag", "retag", "remote add", "tag"]);
+        assert_eq!(
+            feeder.query("tag", 0, 10),
+            vec!["tag", "remove tag", "retag"]
+        );
+        assert_eq!(feeder.query("ra", 0, 10)[0], "remote add");
     }
 }
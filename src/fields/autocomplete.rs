@@ -3,11 +3,13 @@ use std::rc::Rc;
 use clap;
 use cursive::view::AnyView;
 use cursive::views::{LinearLayout, TextView};
+use serde_json::map::Map;
 use serde_json::value::Value;
 
 use feeders::Feeder;
 use fields::WidgetManager;
 use fields;
+use validators::Context;
 use views;
 
 /// Convienient wrapper around `Field<AutocompleteManager, String>`.
@@ -19,17 +21,24 @@ impl Autocomplete {
         label: IS,
         feeder: F,
     ) -> fields::Field<AutocompleteManager, String> {
-        fields::Field::new(label, AutocompleteManager(Rc::new(feeder)), "".to_string())
+        let manager = AutocompleteManager {
+            feeder: Rc::new(feeder),
+            placeholder: "".to_string(),
+        };
+        fields::Field::new(label, manager, "".to_string())
     }
 }
 
 #[derive(Clone)]
-pub struct AutocompleteManager(Rc<Feeder>);
+pub struct AutocompleteManager {
+    feeder: Rc<Feeder>,
+    placeholder: String,
+}
 
 impl WidgetManager for AutocompleteManager {
     fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
-        let view = self.build_value_view(&initial);
-        fields::label_with_help_layout(view, &label, &help)
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
     }
     fn get_value(&self, view: &AnyView) -> String {
         let boxed_widget = (*view).as_any().downcast_ref::<Box<AnyView>>().unwrap();
@@ -49,7 +58,7 @@ impl WidgetManager for AutocompleteManager {
             .unwrap();
         let value = (*ac).get_value();
 
-        (&*value).clone()
+        (*value).clone()
     }
     fn set_error(&self, view: &mut AnyView, error: &str) {
         let boxed_widget = (*view).as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
@@ -65,8 +74,36 @@ impl WidgetManager for AutocompleteManager {
             .unwrap();
         error_field.set_content(error);
     }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let boxed_widget = (*view).as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap();
+        let boxed_field = (*widget)
+            .get_child_mut(1)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<Box<AnyView>>()
+            .unwrap();
+        let ac = (**boxed_field)
+            .as_any_mut()
+            .downcast_mut::<views::Autocomplete>()
+            .unwrap();
+        ac.set_value(value);
+    }
     fn build_value_view(&self, value: &str) -> Box<AnyView> {
-        Box::new(views::Autocomplete::new(Rc::clone(&self.0)).value(value))
+        let view = views::Autocomplete::new(Rc::clone(&self.feeder))
+            .value(value)
+            .placeholder(&self.placeholder);
+        Box::new(view)
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("Up / Ctrl+p", "previous suggestion"),
+            ("Down / Ctrl+n", "next suggestion"),
+            ("Ctrl+u", "clear value"),
+        ]
     }
 }
 
@@ -76,16 +113,18 @@ impl fields::FormField for fields::Field<AutocompleteManager, String> {
     }
     fn build_widget(&self) -> Box<AnyView> {
         self.widget_manager
-            .build_widget(&self.label, &self.help, &self.initial)
+            .build_widget(&self.label, &self.help, &self.get_initial())
     }
 
     fn validate(&self, data: &str) -> Result<Value, String> {
-        for v in &self.validators {
-            if let Some(e) = v.validate(data) {
-                return Err(e);
-            }
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        match self.run_validators(data, context) {
+            Some(e) => Err(e),
+            None => Ok(Value::String(data.to_owned())),
         }
-        Ok(Value::String(data.to_owned()))
     }
 
     /// Gets label of the field
@@ -93,10 +132,24 @@ impl fields::FormField for fields::Field<AutocompleteManager, String> {
         &self.label
     }
 
-    fn clap_arg(&self) -> clap::Arg {
-        clap::Arg::with_name(&self.label)
-            .help(&self.help)
-            .long(&self.label)
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({"type": "string"}))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.clone())
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
             .required(self.is_required())
             .takes_value(true)
     }
@@ -105,3 +158,15 @@ impl fields::FormField for fields::Field<AutocompleteManager, String> {
         args.value_of(&self.label).unwrap_or("").to_string()
     }
 }
+
+impl fields::Field<AutocompleteManager, String> {
+    /// Sets text shown (dimmed) while `field` is empty.
+    ///
+    /// Unlike [initial] it's never treated as `field`'s value.
+    ///
+    /// [initial]: struct.Field.html#method.initial
+    pub fn placeholder<IS: Into<String>>(mut self, placeholder: IS) -> Self {
+        self.widget_manager.placeholder = placeholder.into();
+        self
+    }
+}
@@ -3,6 +3,7 @@ use std::str::FromStr;
 use clap;
 use cursive::view::AnyView;
 use cursive::views;
+use serde_json::map::Map;
 use serde_json::value::Value;
 
 use fields;
@@ -13,18 +14,30 @@ pub struct Checkbox;
 
 impl Checkbox {
     /// Creates a new `Field<CheckboxManager, bool>`.
+    ///
+    /// On CLI it's represented by pair of flags: `--label` (sets `true`) and `--no-label` (sets
+    /// `false`), the last one given wins. If none of them is given `initial` value is used.
     pub fn new<IS: Into<String>>(label: IS) -> fields::Field<CheckboxManager, bool> {
-        fields::Field::new(label, CheckboxManager, false)
+        let label = label.into();
+        let mngr = CheckboxManager {
+            negated_label: format!("no-{}", label),
+            negated_help: format!("Opposite of --{}", label),
+        };
+        fields::Field::new(label, mngr, false)
     }
 }
 
 #[derive(Clone)]
-pub struct CheckboxManager;
+pub struct CheckboxManager {
+    // name of CLI flag which sets `false`
+    negated_label: String,
+    negated_help: String,
+}
 
 impl fields::WidgetManager for CheckboxManager {
     fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
-        let checkbox = self.build_value_view(&initial);
-        fields::label_with_help_layout(checkbox, &label, &help)
+        let checkbox = self.build_value_view(initial);
+        fields::label_with_help_layout(checkbox, label, help)
     }
     fn get_value(&self, view: &AnyView) -> String {
         let boxed_widget = view.as_any().downcast_ref::<Box<AnyView>>().unwrap();
@@ -48,8 +61,29 @@ impl fields::WidgetManager for CheckboxManager {
     fn set_error(&self, _view: &mut AnyView, _error: &str) {
         // no operation, checkbox is always valid
     }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![("Space / Enter", "toggle")]
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let boxed_widget = view.as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any_mut()
+            .downcast_mut::<views::LinearLayout>()
+            .unwrap();
+        let boxed_field = widget
+            .get_child_mut(1)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<Box<AnyView>>()
+            .unwrap();
+        let checkbox = (**boxed_field)
+            .as_any_mut()
+            .downcast_mut::<views::Checkbox>()
+            .unwrap();
+        checkbox.set_checked(FromStr::from_str(value).unwrap_or(false));
+    }
     fn build_value_view(&self, value: &str) -> Box<AnyView> {
-        let value = FromStr::from_str(value).unwrap();
+        let value = FromStr::from_str(value).unwrap_or(false);
         let mut checkbox = views::Checkbox::new();
         checkbox.set_checked(value);
         Box::new(checkbox)
@@ -61,33 +95,73 @@ impl fields::FormField for fields::Field<CheckboxManager, bool> {
         &self.widget_manager
     }
     fn build_widget(&self) -> Box<AnyView> {
-        let initial = format!("{}", self.initial);
+        let initial = self.get_initial();
         self.widget_manager
             .build_widget(&self.label, &self.help, &initial)
     }
     fn get_label(&self) -> &str {
         &self.label
     }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({"type": "boolean"}))
+    }
+    fn get_initial(&self) -> String {
+        // env value which isn't a bool falls back to `initial`
+        let value = self
+            .get_env_value()
+            .and_then(|v| FromStr::from_str(&v).ok())
+            .unwrap_or(self.initial);
+        format!("{}", value)
+    }
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
     fn validate(&self, data: &str) -> Result<Value, String> {
-        let value = FromStr::from_str(data)
-            .map(|v| Value::Bool(v))
-            .map_err(|_| "Value can't be converterd to bool".to_string());
-        value
+        FromStr::from_str(data)
+            .map(Value::Bool)
+            .map_err(|_| "Value can't be converterd to bool".to_string())
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
+            .takes_value(false)
+            .overrides_with(&self.widget_manager.negated_label)
+    }
+
+    fn clap_args(&self) -> Vec<clap::Arg<'_, '_>> {
+        let negated_label = &self.widget_manager.negated_label;
+        let negated = clap::Arg::with_name(negated_label)
+            .long(negated_label)
+            .help(&self.widget_manager.negated_help)
+            .overrides_with(&self.label);
+        vec![self.clap_arg(), negated]
     }
 
-    fn clap_arg(&self) -> clap::Arg {
-        clap::Arg::with_name(&self.label)
-            .help(&self.help)
-            .long(&self.label)
+    fn is_flag(&self) -> bool {
+        true
+    }
+
+    fn value2clap_args(&self, value: &Value) -> Vec<String> {
+        if value.as_bool().unwrap_or(false) {
+            vec![format!("--{}", self.label)]
+        } else {
+            vec![format!("--{}", self.widget_manager.negated_label)]
+        }
     }
 
     fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
         let v = if args.is_present(&self.label) {
-            "true"
+            true
+        } else if args.is_present(&self.widget_manager.negated_label) {
+            false
         } else {
-            "false"
+            self.get_initial() == "true"
         };
-        v.to_string()
+        format!("{}", v)
     }
 }
 
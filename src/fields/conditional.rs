@@ -0,0 +1,109 @@
+use std::rc::Rc;
+
+use clap;
+use cursive::view::AnyView;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields::{FormField, WidgetManager};
+use validators::Context;
+
+/// Wraps `field` shown only when value of another field passes predicate.
+///
+/// It's created with [Field::visible_when].
+///
+/// [Field::visible_when]: struct.Field.html#method.visible_when
+pub struct Conditional<F: FormField> {
+    field: F,
+    depends_on: String,
+    predicate: Rc<Fn(&str) -> bool>,
+}
+
+impl<F: FormField> Conditional<F> {
+    /// Creates a new `Conditional` showing `field` when value of field labeled `depends_on`
+    /// passes `predicate`.
+    pub fn new<IS, P>(field: F, depends_on: IS, predicate: P) -> Self
+    where
+        IS: Into<String>,
+        P: Fn(&str) -> bool + 'static,
+    {
+        Conditional {
+            field,
+            depends_on: depends_on.into(),
+            predicate: Rc::new(predicate),
+        }
+    }
+}
+
+impl<F: FormField> FormField for Conditional<F> {
+    fn build_widget(&self) -> Box<AnyView> {
+        self.field.build_widget()
+    }
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.field.validate(data)
+    }
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        self.field.validate_with(data, context)
+    }
+    fn get_label(&self) -> &str {
+        self.field.get_label()
+    }
+
+    fn get_help(&self) -> &str {
+        self.field.get_help()
+    }
+    fn schema(&self) -> Map<String, Value> {
+        self.field.schema()
+    }
+    fn get_initial(&self) -> String {
+        self.field.get_initial()
+    }
+    fn get_width(&self) -> Option<usize> {
+        self.field.get_width()
+    }
+    fn get_widget_manager(&self) -> &WidgetManager {
+        self.field.get_widget_manager()
+    }
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        // it may be skipped depending on other arguments
+        self.field.clap_arg().required(false)
+    }
+    fn clap_args(&self) -> Vec<clap::Arg<'_, '_>> {
+        self.field
+            .clap_args()
+            .into_iter()
+            .map(|a| a.required(false))
+            .collect()
+    }
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        self.field.clap_args2str(args)
+    }
+    fn is_required(&self) -> bool {
+        self.field.is_required()
+    }
+    fn contribute(&self, value: Value) -> Map<String, Value> {
+        self.field.contribute(value)
+    }
+    fn is_visible(&self, context: &Context) -> bool {
+        let value = context
+            .get(&self.depends_on)
+            .map(|v| v.as_str())
+            .unwrap_or("");
+        (self.predicate)(value) && self.field.is_visible(context)
+    }
+    fn is_secret(&self) -> bool {
+        self.field.is_secret()
+    }
+    fn is_flag(&self) -> bool {
+        self.field.is_flag()
+    }
+    fn value2str(&self, value: &Value) -> String {
+        self.field.value2str(value)
+    }
+    fn value2clap_args(&self, value: &Value) -> Vec<String> {
+        self.field.value2clap_args(value)
+    }
+    fn reset(&self, view: &mut AnyView) {
+        self.field.reset(view)
+    }
+}
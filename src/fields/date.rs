@@ -0,0 +1,113 @@
+use clap;
+use cursive::view::AnyView;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use utils::parse_date;
+use validators::Context;
+use views::DateEdit;
+
+/// Convienient wrapper around `Field<DatePickerManager, String>`.
+///
+/// Value is a date in ISO-8601 format (`YYYY-MM-DD`), e.g. `2018-03-21`.
+pub struct DatePicker;
+
+impl DatePicker {
+    /// Creates a new `Field<DatePickerManager, String>`.
+    pub fn new<IS: Into<String>>(label: IS) -> fields::Field<DatePickerManager, String> {
+        fields::Field::new(label, DatePickerManager, "".to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct DatePickerManager;
+
+impl WidgetManager for DatePickerManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let edit = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<DateEdit>()
+            .unwrap();
+        (*edit.get_inner().get_inner().get_content()).clone()
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let edit = fields::get_value_view_mut(view)
+            .as_any_mut()
+            .downcast_mut::<DateEdit>()
+            .unwrap();
+        edit.get_inner_mut().get_inner_mut().set_content(value);
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![("Up / Down", "next / previous day")]
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        Box::new(DateEdit::new().value(value))
+    }
+}
+
+impl fields::FormField for fields::Field<DatePickerManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        if let Some(e) = self.run_validators(data, context) {
+            return Err(e);
+        }
+        if data.is_empty() || parse_date(data).is_some() {
+            Ok(Value::String(data.to_owned()))
+        } else {
+            Err("Value must be a date (YYYY-MM-DD)".to_string())
+        }
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({"type": "string"}))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.clone())
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
+            .required(self.is_required())
+            .takes_value(true)
+            .value_name("YYYY-MM-DD")
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        args.value_of(&self.label)
+            .map(|v| v.to_string())
+            .unwrap_or(self.get_initial())
+    }
+}
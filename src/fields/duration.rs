@@ -0,0 +1,135 @@
+use clap;
+use cursive::view::AnyView;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use fields::text::TextManager;
+use utils::{format_duration, parse_duration};
+use validators::Context;
+
+/// Convienient wrapper around `Field<DurationManager, String>`.
+///
+/// Accepts durations like `1h30m` or `90s` (units: `d`, `h`, `m`, `s`) and emits seconds, see
+/// [normalized] for emitting text.
+///
+/// [normalized]: struct.Field.html#method.normalized
+pub struct Duration;
+
+impl Duration {
+    /// Creates a new `Field<DurationManager, String>`.
+    pub fn new<IS: Into<String>>(label: IS) -> fields::Field<DurationManager, String> {
+        let manager = DurationManager {
+            text: TextManager::with_placeholder("e.g. 1h30m"),
+            normalized: false,
+        };
+        fields::Field::new(label, manager, "".to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct DurationManager {
+    text: TextManager,
+    normalized: bool,
+}
+
+impl WidgetManager for DurationManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        self.text.build_widget(label, help, initial)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        self.text.get_value(view)
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        self.text.set_error(view, error)
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        self.text.set_value(view, value)
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        self.text.build_value_view(value)
+    }
+}
+
+impl fields::FormField for fields::Field<DurationManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        if let Some(e) = self.run_validators(data, context) {
+            return Err(e);
+        }
+        if data.trim().is_empty() {
+            return Ok(Value::Null);
+        }
+        let secs = parse_duration(data).ok_or("Value must be a duration (e.g. 1h30m)".to_string())?;
+        if self.widget_manager.normalized {
+            Ok(Value::String(format_duration(secs)))
+        } else {
+            Ok(Value::from(secs))
+        }
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        // seconds unless it's normalized, empty duration is `null`
+        let kind = if self.widget_manager.normalized { "string" } else { "integer" };
+        self.build_schema(json!({ "type": [kind, "null"] }))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.clone())
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
+            .required(self.is_required())
+            .takes_value(true)
+            .value_name("DURATION")
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        args.value_of(&self.label)
+            .map(|v| v.to_string())
+            .unwrap_or(self.get_initial())
+    }
+}
+
+impl fields::Field<DurationManager, String> {
+    /// Sets text shown (dimmed) while `field` is empty (by default it's `e.g. 1h30m`).
+    ///
+    /// Unlike [initial] it's never treated as `field`'s value.
+    ///
+    /// [initial]: struct.Field.html#method.initial
+    pub fn placeholder<IS: Into<String>>(mut self, placeholder: IS) -> Self {
+        self.widget_manager.text.set_placeholder(placeholder);
+        self
+    }
+    /// Makes value to be emitted as normalized text (e.g. `90m` becomes `1h30m`) instead of
+    /// seconds.
+    pub fn normalized(mut self) -> Self {
+        self.widget_manager.normalized = true;
+        self
+    }
+}
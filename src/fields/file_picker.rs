@@ -0,0 +1,159 @@
+use clap;
+use cursive::view::AnyView;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use utils::has_extension;
+use validators::Context;
+use views::FilePickerEdit;
+
+/// Convienient wrapper around `Field<FilePickerManager, String>`.
+///
+/// Path can be typed or picked from a tree of dirs (opened with Enter).
+pub struct FilePicker;
+
+impl FilePicker {
+    /// Creates a new `Field<FilePickerManager, String>` for picking files.
+    pub fn new<IS: Into<String>>(label: IS) -> fields::Field<FilePickerManager, String> {
+        fields::Field::new(label, FilePickerManager::new(false), "".to_string())
+    }
+
+    /// Creates a new `Field<FilePickerManager, String>` for picking dirs.
+    pub fn dirs<IS: Into<String>>(label: IS) -> fields::Field<FilePickerManager, String> {
+        fields::Field::new(label, FilePickerManager::new(true), "".to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct FilePickerManager {
+    dirs_only: bool,
+    extensions: Vec<String>,
+    placeholder: String,
+}
+
+impl FilePickerManager {
+    fn new(dirs_only: bool) -> Self {
+        FilePickerManager {
+            dirs_only,
+            extensions: Vec::new(),
+            placeholder: "".to_string(),
+        }
+    }
+}
+
+impl WidgetManager for FilePickerManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let edit = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<FilePickerEdit>()
+            .unwrap();
+        (*edit.get_inner().get_inner().get_content()).clone()
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let edit = fields::get_value_view_mut(view)
+            .as_any_mut()
+            .downcast_mut::<FilePickerEdit>()
+            .unwrap();
+        edit.get_inner_mut().get_inner_mut().set_content(value);
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("Enter", "browse (in tree: pick item)"),
+            ("Right / Left", "in tree: expand / collapse dir"),
+        ]
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        let mut edit = FilePickerEdit::new()
+            .extensions(self.extensions.clone())
+            .placeholder(self.placeholder.as_str())
+            .value(value);
+        if self.dirs_only {
+            edit = edit.dirs_only();
+        }
+        Box::new(edit)
+    }
+}
+
+impl fields::FormField for fields::Field<FilePickerManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        if let Some(e) = self.run_validators(data, context) {
+            return Err(e);
+        }
+        let extensions = &self.widget_manager.extensions;
+        if data.is_empty() || self.widget_manager.dirs_only || has_extension(data, extensions) {
+            Ok(Value::String(data.to_owned()))
+        } else {
+            Err(format!("File must have extension: {}", extensions.join(", ")))
+        }
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({"type": "string"}))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.clone())
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
+            .required(self.is_required())
+            .takes_value(true)
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        args.value_of(&self.label)
+            .map(|v| v.to_string())
+            .unwrap_or(self.get_initial())
+    }
+}
+
+impl fields::Field<FilePickerManager, String> {
+    /// Sets text shown (dimmed) while `field` is empty.
+    ///
+    /// Unlike [initial] it's never treated as `field`'s value.
+    ///
+    /// [initial]: struct.Field.html#method.initial
+    pub fn placeholder<IS: Into<String>>(mut self, placeholder: IS) -> Self {
+        self.widget_manager.placeholder = placeholder.into();
+        self
+    }
+    /// Limits files to ones with `extensions` (like `txt` or `tar.gz`).
+    pub fn extensions<IS: Into<String>>(mut self, extensions: Vec<IS>) -> Self {
+        self.widget_manager.extensions = extensions.into_iter().map(|e| e.into()).collect();
+        self
+    }
+}
@@ -0,0 +1,93 @@
+use clap;
+use cursive::theme::Style;
+use cursive::view::AnyView;
+use cursive::views::DummyView;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+
+/// Convienient wrapper around `Field<HiddenManager, Value>`.
+///
+/// It has no widget (nor CLI argument), it only adds its fixed value to form's data, e.g.
+/// action's id or API version.
+pub struct Hidden;
+
+impl Hidden {
+    /// Creates a new `Field<HiddenManager, Value>` contributing `value` to form's data.
+    pub fn new<IS, V>(label: IS, value: V) -> fields::Field<HiddenManager, Value>
+    where
+        IS: Into<String>,
+        V: Into<Value>,
+    {
+        fields::Field::new(label, HiddenManager, value.into())
+    }
+}
+
+#[derive(Clone)]
+pub struct HiddenManager;
+
+impl WidgetManager for HiddenManager {
+    fn build_widget(&self, _label: &str, _help: &str, initial: &str) -> Box<AnyView> {
+        self.build_value_view(initial)
+    }
+    fn get_value(&self, _view: &AnyView) -> String {
+        // value is kept by field
+        "".to_string()
+    }
+    fn set_error(&self, _view: &mut AnyView, _error: &str) {}
+    fn set_value(&self, _view: &mut AnyView, _value: &str) {}
+    fn set_label_style(&self, _view: &mut AnyView, _style: Style) {}
+    fn set_error_style(&self, _view: &mut AnyView, _style: Style) {}
+    fn set_required(&self, _view: &mut AnyView) {}
+    fn build_value_view(&self, _value: &str) -> Box<AnyView> {
+        Box::new(DummyView)
+    }
+}
+
+impl fields::FormField for fields::Field<HiddenManager, Value> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, _data: &str) -> Result<Value, String> {
+        Ok(self.initial.clone())
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({ "const": self.initial }))
+    }
+
+    fn get_initial(&self) -> String {
+        self.initial.to_string()
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg().hidden(true)
+    }
+
+    fn clap_args(&self) -> Vec<clap::Arg<'_, '_>> {
+        Vec::new()
+    }
+
+    fn clap_args2str(&self, _args: &clap::ArgMatches) -> String {
+        self.get_initial()
+    }
+}
@@ -0,0 +1,171 @@
+use clap;
+use cursive::view::AnyView;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use validators::Context;
+use views::KeyValueEdit;
+
+const PAIR_SEP: &str = "\n";
+const KEY_SEP: char = '=';
+
+/// Convienient wrapper around `Field<KeyValueManager, Vec<(String, String)>>`.
+///
+/// Pairs are serialized as object (e.g. `{ "HOME": "/root", "LANG": "C" }`), in CLI they are
+/// passed as `KEY=VALUE`.
+pub struct KeyValue;
+
+impl KeyValue {
+    /// Creates a new `Field<KeyValueManager, Vec<(String, String)>>`.
+    pub fn new<IS: Into<String>>(
+        label: IS,
+    ) -> fields::Field<KeyValueManager, Vec<(String, String)>> {
+        fields::Field::new(label, KeyValueManager, Vec::new())
+    }
+}
+
+fn join(pairs: &[(String, String)]) -> String {
+    pairs
+        .iter()
+        .map(|(k, v)| format!("{}{}{}", k, KEY_SEP, v))
+        .collect::<Vec<String>>()
+        .join(PAIR_SEP)
+}
+
+/// Splits `value` into pairs, `Err` holds pair without `=` (or key).
+fn split(value: &str) -> Result<Vec<(String, String)>, String> {
+    let mut pairs = Vec::new();
+    for pair in value.split(PAIR_SEP).filter(|p| !p.trim().is_empty()) {
+        let mut parts = pair.splitn(2, KEY_SEP);
+        let key = parts.next().unwrap_or("").trim();
+        match parts.next() {
+            Some(v) if !key.is_empty() => pairs.push((key.to_string(), v.to_string())),
+            _ => return Err(pair.to_string()),
+        }
+    }
+    Ok(pairs)
+}
+
+#[derive(Clone)]
+pub struct KeyValueManager;
+
+impl WidgetManager for KeyValueManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let edit = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<KeyValueEdit>()
+            .unwrap();
+        join(&edit.get_pairs())
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let edit = fields::get_value_view_mut(view)
+            .as_any_mut()
+            .downcast_mut::<KeyValueEdit>()
+            .unwrap();
+        edit.set_pairs(split(value).unwrap_or_default());
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![("Enter", "add pair"), ("Delete", "remove selected pair")]
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        Box::new(KeyValueEdit::new().pairs(split(value).unwrap_or_default()))
+    }
+}
+
+impl fields::FormField for fields::Field<KeyValueManager, Vec<(String, String)>> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        let pairs = split(data).map_err(|p| format!("Value must be KEY{}VALUE: {}", KEY_SEP, p))?;
+        if pairs.is_empty() {
+            if let Some(e) = self.run_validators("", context) {
+                return Err(e);
+            }
+        }
+        let mut map = Map::new();
+        for (key, value) in pairs {
+            if let Some(e) = self.run_validators(&value, context) {
+                return Err(format!("{}: {}", key, e));
+            }
+            map.insert(key, Value::String(value));
+        }
+        Ok(Value::Object(map))
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({
+            "type": "object",
+            "additionalProperties": {"type": "string"},
+        }))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| join(&self.initial))
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
+            .required(self.is_required())
+            .multiple(true)
+            .takes_value(true)
+            .value_name("KEY=VALUE")
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        match args.values_of(&self.label) {
+            Some(values) => values.collect::<Vec<&str>>().join(PAIR_SEP),
+            None => self.get_initial(),
+        }
+    }
+
+    fn value2str(&self, value: &Value) -> String {
+        match *value {
+            Value::Object(ref map) => {
+                let pairs = map.iter()
+                    .map(|(k, v)| (k.clone(), fields::value2str(v)))
+                    .collect::<Vec<(String, String)>>();
+                join(&pairs)
+            }
+            _ => "".to_string(),
+        }
+    }
+}
+
+impl fields::Field<KeyValueManager, Vec<(String, String)>> {
+    /// Sets initial `pairs` of `field`.
+    pub fn initial<K: Into<String>, V: Into<String>>(mut self, pairs: Vec<(K, V)>) -> Self {
+        self.initial = pairs.into_iter().map(|(k, v)| (k.into(), v.into())).collect();
+        self
+    }
+}
@@ -1,20 +1,140 @@
 //! Includes `form's` building blocks, `fields`.
 use clap;
+use cursive::theme::{BaseColor, Color, Style};
+use cursive::utils::markup::StyledString;
 use cursive::view::AnyView;
 use cursive::views;
+use serde_json::map::Map;
 use serde_json::value::Value;
+use std::env;
 use std::rc::Rc;
-use validators::{Required, Validator};
+use validators::{Context, Required, Validator, WithMessage};
 
 mod autocomplete;
 mod checkbox;
+mod conditional;
+mod date;
+mod duration;
+mod file_picker;
+mod hidden;
+mod key_value;
+mod multiline;
 mod multiselect;
+mod number;
+mod password;
+mod radio;
+mod repeated;
+mod select;
+mod slider;
+mod static_text;
+mod tags;
 mod text;
+mod time;
 
 pub use self::autocomplete::Autocomplete;
 pub use self::checkbox::Checkbox;
+pub use self::conditional::Conditional;
+pub use self::date::DatePicker;
+pub use self::duration::Duration;
+pub use self::file_picker::FilePicker;
+pub use self::hidden::Hidden;
+pub use self::key_value::KeyValue;
+pub use self::multiline::Multiline;
 pub use self::multiselect::Multiselect;
+pub use self::number::Number;
+pub use self::password::Password;
+pub use self::radio::RadioGroup;
+pub use self::repeated::Repeated;
+pub use self::select::Select;
+pub use self::slider::Slider;
+pub use self::static_text::Static;
+pub use self::tags::Tags;
 pub use self::text::Text;
+pub use self::time::{DateTime, Time, Timezone};
+
+/// State of `field` resulting from its validation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldState {
+    /// Field wasn't validated yet.
+    Untouched,
+    /// Field passed validation.
+    Valid,
+    /// Field passed validation, but it has a warning.
+    Warning,
+    /// Field failed validation.
+    Invalid,
+}
+
+/// Styles of fields' labels (for each [FieldState] & focus) and errors.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::cursive::theme::{Effect, Style};
+/// # use fui::fields::FieldStyles;
+/// # use fui::form::FormView;
+/// # fn main() {
+/// let styles = FieldStyles {
+///     focused: Style::from(Effect::Bold),
+///     ..FieldStyles::default()
+/// };
+/// let form = FormView::new().field_styles(styles);
+/// # }
+/// ```
+///
+/// [FieldState]: enum.FieldState.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldStyles {
+    /// Label of field which wasn't validated yet (by default plain).
+    pub untouched: Style,
+    /// Label of valid field (by default green).
+    pub valid: Style,
+    /// Label of field with a warning (by default yellow).
+    pub warning: Style,
+    /// Label of invalid field (by default red).
+    pub invalid: Style,
+    /// Added to label of focused field (by default nothing).
+    pub focused: Style,
+    /// Error of invalid field (by default red), warnings use `warning`.
+    pub error: Style,
+}
+
+impl Default for FieldStyles {
+    fn default() -> Self {
+        FieldStyles {
+            untouched: Style::none(),
+            valid: Style::from(Color::Dark(BaseColor::Green)),
+            warning: Style::from(Color::Dark(BaseColor::Yellow)),
+            invalid: Style::from(Color::Dark(BaseColor::Red)),
+            focused: Style::none(),
+            error: Style::from(Color::Dark(BaseColor::Red)),
+        }
+    }
+}
+
+impl FieldStyles {
+    /// Gets style of label of field in `state`, `focused` or not.
+    pub fn label_style(&self, state: FieldState, focused: bool) -> Style {
+        let style = match state {
+            FieldState::Untouched => self.untouched,
+            FieldState::Valid => self.valid,
+            FieldState::Warning => self.warning,
+            FieldState::Invalid => self.invalid,
+        };
+        if focused {
+            style.combine(self.focused)
+        } else {
+            style
+        }
+    }
+    /// Gets style of error shown on field in `state`.
+    pub fn error_style(&self, state: FieldState) -> Style {
+        match state {
+            FieldState::Warning => self.warning,
+            FieldState::Invalid => self.error,
+            _ => Style::none(),
+        }
+    }
+}
 
 /// Covers communication from `Field` to `Widget`.
 pub trait WidgetManager {
@@ -24,6 +144,35 @@ pub trait WidgetManager {
     fn get_value(&self, view: &AnyView) -> String;
     /// Sets `error` on widget.
     fn set_error(&self, view: &mut AnyView, error: &str);
+    /// Sets `value` on widget.
+    fn set_value(&self, view: &mut AnyView, value: &str);
+    /// Describes key bindings handled by widget as pairs: (keys, action).
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        Vec::new()
+    }
+    /// Sets `style` of widget's label (see [FieldStyles]), by default it styles label of
+    /// [label_with_help_layout].
+    ///
+    /// [FieldStyles]: struct.FieldStyles.html
+    /// [label_with_help_layout]: fn.label_with_help_layout.html
+    fn set_label_style(&self, view: &mut AnyView, style: Style) {
+        set_label_style(view, style);
+    }
+    /// Sets `style` of widget's error (see [FieldStyles]), by default it styles error of
+    /// [label_with_help_layout].
+    ///
+    /// [FieldStyles]: struct.FieldStyles.html
+    /// [label_with_help_layout]: fn.label_with_help_layout.html
+    fn set_error_style(&self, view: &mut AnyView, style: Style) {
+        set_error_style(view, style);
+    }
+    /// Marks widget of required `field`, by default it adds `*` to label of
+    /// [label_with_help_layout].
+    ///
+    /// [label_with_help_layout]: fn.label_with_help_layout.html
+    fn set_required(&self, view: &mut AnyView) {
+        set_label_required(view);
+    }
     /// Builds a `value` view
     fn build_value_view(&self, value: &str) -> Box<AnyView>;
 }
@@ -34,6 +183,10 @@ pub struct Field<W: WidgetManager, T> {
     label: String,
     help: String,
     initial: T,
+    width: Option<usize>,
+    env: Option<String>,
+    positional: bool,
+    short: Option<char>,
     validators: Vec<Rc<Validator>>,
     widget_manager: W,
 }
@@ -48,9 +201,13 @@ impl<W: WidgetManager, T> Field<W, T> {
         Field {
             label: label.into(),
             help: "".into(),
-            initial: initial,
+            initial,
+            width: None,
+            env: None,
+            positional: false,
+            short: None,
             validators: vec![],
-            widget_manager: widget_manager,
+            widget_manager,
         }
     }
     /// Sets `help` message for `field`.
@@ -58,16 +215,149 @@ impl<W: WidgetManager, T> Field<W, T> {
         self.help = msg.into();
         self
     }
+    /// Sets `width` of `field`'s widget (by default it takes available width).
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+    /// Takes `field`'s initial value from environment variable `var` (if it's set).
+    ///
+    /// It's also used as default of `field`'s command line argument.
+    ///
+    /// ```
+    /// # extern crate fui;
+    /// # use fui::fields::Text;
+    /// # fn main() {
+    /// let field = Text::new("target").default_from_env("TAR_TARGET");
+    /// # }
+    /// ```
+    pub fn default_from_env<IS: Into<String>>(mut self, var: IS) -> Self {
+        self.env = Some(var.into());
+        self
+    }
+    /// Alias of [default_from_env] mirroring clap's `Arg::env`.
+    ///
+    /// [default_from_env]: #method.default_from_env
+    pub fn env<IS: Into<String>>(self, var: IS) -> Self {
+        self.default_from_env(var)
+    }
+    /// Makes `field` a positional CLI argument (e.g. `my_app extract ARCHIVE`) instead of an
+    /// option (`--label value`).
+    ///
+    /// Positional arguments keep order of fields, it's meant for fields taking values (so not
+    /// for `Checkbox`).
+    ///
+    /// ```
+    /// # extern crate fui;
+    /// # use fui::fields::Text;
+    /// # use fui::form::FormView;
+    /// # fn main() {
+    /// let form = FormView::new()
+    ///     .field(Text::new("archive").positional())
+    ///     .field(Text::new("dest").positional());
+    /// # }
+    /// ```
+    pub fn positional(mut self) -> Self {
+        self.positional = true;
+        self
+    }
+    /// Sets single-letter alias of `field`'s CLI option (e.g. `-t` for `--target`).
+    ///
+    /// ```
+    /// # extern crate fui;
+    /// # use fui::fields::Text;
+    /// # fn main() {
+    /// let field = Text::new("target").short('t');
+    /// # }
+    /// ```
+    pub fn short(mut self, short: char) -> Self {
+        self.short = Some(short);
+        self
+    }
     /// Append `validator`.
     pub fn validator<V: Validator + 'static>(mut self, validator: V) -> Self {
         self.validators.push(Rc::new(validator));
         self
     }
+    /// Shows `field` only when value of field labeled `label` passes `predicate`.
+    ///
+    /// Hidden `field` isn't validated, nor included in form's data.
+    ///
+    /// ```
+    /// # extern crate fui;
+    /// # use fui::fields::{Number, Select};
+    /// # use fui::form::FormView;
+    /// # fn main() {
+    /// let form = FormView::new()
+    ///     .field(Select::new("format", vec![("tar", "tar"), ("gzip", "gzip")]))
+    ///     .field(Number::integer("level").visible_when("format", |v| v == "gzip"));
+    /// # }
+    /// ```
+    pub fn visible_when<IS, F>(self, label: IS, predicate: F) -> Conditional<Self>
+    where
+        Self: FormField,
+        IS: Into<String>,
+        F: Fn(&str) -> bool + 'static,
+    {
+        Conditional::new(self, label, predicate)
+    }
     /// Checks if Field is required
     pub fn is_required(&self) -> bool {
         self.validators
             .iter()
-            .any(|&ref x| (**x).as_any().downcast_ref::<Required>().is_some())
+            .map(|x| (**x).as_any())
+            .any(|x| x.is::<Required>() || x.is::<WithMessage<Required>>())
+    }
+    /// Runs `validators` on `data` returning the first error.
+    fn run_validators(&self, data: &str, context: &Context) -> Option<String> {
+        self.validators
+            .iter()
+            .filter_map(|v| v.validate_with(data, context))
+            .next()
+    }
+    /// Builds `properties` of JSON Schema with `label` described by `schema` (an object), `help`
+    /// and constraints of `validators` (which check items of arrays).
+    fn build_schema(&self, mut schema: Value) -> Map<String, Value> {
+        {
+            let map = schema.as_object_mut().expect("schema must be an object");
+            if !self.help.is_empty() {
+                map.insert("description".to_string(), Value::from(self.help.as_str()));
+            }
+            let checked = match map.get_mut("items") {
+                Some(&mut Value::Object(ref mut items)) => items,
+                _ => map,
+            };
+            for validator in self.validators.iter() {
+                validator.schema(checked);
+            }
+        }
+        let mut properties = Map::with_capacity(1);
+        properties.insert(self.label.clone(), schema);
+        properties
+    }
+    /// Gets value of environment variable set by `default_from_env`.
+    fn get_env_value(&self) -> Option<String> {
+        self.env.as_ref().and_then(|var| env::var(var).ok())
+    }
+    /// Builds [clap::Arg] named by `label` and described by `help`.
+    ///
+    /// Multiline `help` starts in the next line after argument's name.
+    ///
+    /// [clap::Arg]: ../../clap/struct.Arg.html
+    fn base_clap_arg(&self) -> clap::Arg<'_, '_> {
+        let mut arg = clap::Arg::with_name(&self.label)
+            .help(&self.help)
+            .next_line_help(self.help.contains('\n'));
+        if !self.positional {
+            arg = arg.long(&self.label);
+        }
+        if let Some(short) = self.short {
+            arg = arg.short(short.to_string());
+        }
+        match self.env {
+            Some(ref var) => arg.env(var),
+            None => arg,
+        }
     }
 }
 
@@ -77,24 +367,142 @@ pub trait FormField {
     fn build_widget(&self) -> Box<AnyView>;
     /// Validates `data`.
     fn validate(&self, data: &str) -> Result<Value, String>;
+    /// Validates `data` knowing values of other `fields` (`context`), by default it's `validate`.
+    fn validate_with(&self, data: &str, _context: &Context) -> Result<Value, String> {
+        self.validate(data)
+    }
     /// Gets `field`'s label.
     fn get_label(&self) -> &str;
+    /// Gets `field`'s help, shown by form's help panel (see [KeyBindings::help]).
+    ///
+    /// [KeyBindings::help]: ../form/struct.KeyBindings.html#structfield.help
+    fn get_help(&self) -> &str {
+        ""
+    }
+    /// Gets `field`'s initial value converted to str.
+    fn get_initial(&self) -> String;
+    /// Gets `field`'s width, `None` means available width.
+    fn get_width(&self) -> Option<usize>;
     /// Gets manager which controlls `widget`.
     fn get_widget_manager(&self) -> &WidgetManager;
     /// Builds [clap::Arg] needed by automatically generated [clap::App].
     ///
     /// [clap::Arg]: ../../clap/struct.Arg.html
     /// [clap::App]: ../../clap/struct.App.html
-    fn clap_arg(&self) -> clap::Arg;
+    fn clap_arg(&self) -> clap::Arg<'_, '_>;
+    /// Builds all [clap::Arg]s representing this `field`, by default the one from `clap_arg`.
+    ///
+    /// [clap::Arg]: ../../clap/struct.Arg.html
+    fn clap_args(&self) -> Vec<clap::Arg<'_, '_>> {
+        vec![self.clap_arg()]
+    }
     /// Extracts field's data from [clap::ArgMatches] and converts it to str.
     ///
     /// [clap::App]: ../../clap/struct.ArgMatches.html
     fn clap_args2str(&self, args: &clap::ArgMatches) -> String;
+    /// Checks if `field` has to be filled, by default it's taken from `clap_arg`.
+    fn is_required(&self) -> bool {
+        self.clap_arg().is_set(clap::ArgSettings::Required)
+    }
+    /// Converts validated `value` to entries of form's `data`, by default it's `label: value`.
+    ///
+    /// Composite `field`s (like date range) can override it to produce several keys at once
+    /// (e.g. `value` being `{"from": .., "to": ..}` may become keys `from` & `to`).
+    fn contribute(&self, value: Value) -> Map<String, Value> {
+        let mut data = Map::with_capacity(1);
+        data.insert(self.get_label().to_owned(), value);
+        data
+    }
+    /// Describes entries contributed to form's `data` as `properties` of JSON Schema (see
+    /// [FormView::schema]), by default `label` is a string described by `help`.
+    ///
+    /// [FormView::schema]: ../form/struct.FormView.html#method.schema
+    fn schema(&self) -> Map<String, Value> {
+        let mut schema = Map::new();
+        schema.insert("type".to_string(), Value::from("string"));
+        if !self.get_help().is_empty() {
+            schema.insert("description".to_string(), Value::from(self.get_help()));
+        }
+        let mut properties = Map::with_capacity(1);
+        properties.insert(self.get_label().to_owned(), Value::Object(schema));
+        properties
+    }
+    /// Checks if `field` is shown (and so validated & included in data), `context` holds values of
+    /// all fields, by default it's always `true`.
+    fn is_visible(&self, _context: &Context) -> bool {
+        true
+    }
+    /// Tells if `field`'s value is secret, so it mustn't be kept (e.g. in history of values), by
+    /// default it's `false`.
+    fn is_secret(&self) -> bool {
+        false
+    }
+    /// Tells if `field` is a flag (like [Checkbox]) answering yes/no, by default it's `false`.
+    ///
+    /// [Checkbox]: struct.Checkbox.html
+    fn is_flag(&self) -> bool {
+        false
+    }
+    /// Converts `value` (as found in form's data) to str accepted by `set_value`.
+    ///
+    /// By default scalars are formatted and items of arrays are separated with comma.
+    fn value2str(&self, value: &Value) -> String {
+        value2str(value)
+    }
+    /// Converts `value` (as found in form's data) to CLI arguments giving it, e.g.
+    /// `["--target", "a.tar"]`, by default items of arrays & pairs of objects are repeated
+    /// arguments.
+    ///
+    /// Empty values give no arguments.
+    fn value2clap_args(&self, value: &Value) -> Vec<String> {
+        let args = self.clap_args();
+        let arg = match args.first() {
+            Some(arg) => arg,
+            None => return Vec::new(),
+        };
+        let values = match *value {
+            Value::Array(ref items) => items.iter().map(value2str).collect(),
+            Value::Object(ref map) => map.iter()
+                .map(|(k, v)| format!("{}={}", k, value2str(v)))
+                .collect(),
+            ref other => vec![self.value2str(other)],
+        };
+        let mut cli_args = Vec::with_capacity(2 * values.len());
+        for value in values.into_iter().filter(|v| !v.is_empty()) {
+            if let Some(long) = arg.s.long {
+                cli_args.push(format!("--{}", long));
+            }
+            cli_args.push(value);
+        }
+        cli_args
+    }
+    /// Restores `widget` to initial value and clears its error.
+    fn reset(&self, view: &mut AnyView) {
+        let widget_manager = self.get_widget_manager();
+        widget_manager.set_value(view, &self.get_initial());
+        widget_manager.set_error(view, "");
+    }
+}
+
+fn value2str(value: &Value) -> String {
+    match *value {
+        Value::Null => "".to_string(),
+        Value::String(ref s) => s.clone(),
+        Value::Array(ref items) => items
+            .iter()
+            .map(value2str)
+            .collect::<Vec<String>>()
+            .join(","),
+        ref other => other.to_string(),
+    }
 }
 
+/// Joins `label` with the first line of `help`, the rest of it is left for form's help panel.
 fn format_annotation(label: &str, help: &str) -> String {
-    if help.len() > 0 {
-        format!("{:20}: {}", label, help)
+    let mut lines = help.lines();
+    if let Some(first) = lines.next() {
+        let more = if lines.next().is_some() { " ..." } else { "" };
+        format!("{:20}: {}{}", label, first, more)
     } else {
         format!("{:20}", label)
     }
@@ -111,3 +519,117 @@ pub fn label_with_help_layout(view: Box<AnyView>, label: &str, help: &str) -> Bo
 
     Box::new(widget)
 }
+
+/// Gets `value` view of widget built with [label_with_help_layout].
+///
+/// [label_with_help_layout]: fn.label_with_help_layout.html
+fn get_value_view(view: &AnyView) -> &AnyView {
+    let boxed_widget = view.as_any().downcast_ref::<Box<AnyView>>().unwrap();
+    let layout = (**boxed_widget)
+        .as_any()
+        .downcast_ref::<views::LinearLayout>()
+        .unwrap();
+    let boxed_value = layout
+        .get_child(1)
+        .unwrap()
+        .as_any()
+        .downcast_ref::<Box<AnyView>>()
+        .unwrap();
+    &**boxed_value
+}
+
+/// Gets mutable `value` view of widget built with [label_with_help_layout].
+///
+/// [label_with_help_layout]: fn.label_with_help_layout.html
+fn get_value_view_mut(view: &mut AnyView) -> &mut AnyView {
+    let boxed_widget = view.as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+    let layout = (**boxed_widget)
+        .as_any_mut()
+        .downcast_mut::<views::LinearLayout>()
+        .unwrap();
+    let boxed_value = layout
+        .get_child_mut(1)
+        .unwrap()
+        .as_any_mut()
+        .downcast_mut::<Box<AnyView>>()
+        .unwrap();
+    &mut **boxed_value
+}
+
+/// Sets `error` of widget built with [label_with_help_layout].
+///
+/// [label_with_help_layout]: fn.label_with_help_layout.html
+fn set_error_text(view: &mut AnyView, error: &str) {
+    let boxed_widget = view.as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+    let layout = (**boxed_widget)
+        .as_any_mut()
+        .downcast_mut::<views::LinearLayout>()
+        .unwrap();
+    let text = layout
+        .get_child_mut(2)
+        .unwrap()
+        .as_any_mut()
+        .downcast_mut::<views::TextView>()
+        .unwrap();
+    text.set_content(error);
+}
+
+/// Adds `*` after label of widget built with [label_with_help_layout].
+///
+/// [label_with_help_layout]: fn.label_with_help_layout.html
+pub fn set_label_required(view: &mut AnyView) {
+    let boxed_widget = view.as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+    let layout = (**boxed_widget)
+        .as_any_mut()
+        .downcast_mut::<views::LinearLayout>()
+        .unwrap();
+    let label = layout
+        .get_child_mut(0)
+        .unwrap()
+        .as_any_mut()
+        .downcast_mut::<views::TextView>()
+        .unwrap();
+    let mut text = label.get_content().source().to_owned();
+    let end = text.find([' ', ':']).unwrap_or(text.len());
+    // marker takes place of one space padding label, so helps stay aligned
+    if text[end..].starts_with(' ') {
+        text.remove(end);
+    }
+    text.insert(end, '*');
+    label.set_content(text);
+}
+
+/// Restyles text of `idx` child of widget built with [label_with_help_layout].
+///
+/// [label_with_help_layout]: fn.label_with_help_layout.html
+fn set_text_style(view: &mut AnyView, idx: usize, style: Style) {
+    let boxed_widget = view.as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+    let layout = (**boxed_widget)
+        .as_any_mut()
+        .downcast_mut::<views::LinearLayout>()
+        .unwrap();
+    let text_view = layout
+        .get_child_mut(idx)
+        .unwrap()
+        .as_any_mut()
+        .downcast_mut::<views::TextView>()
+        .unwrap();
+    let text = text_view.get_content().source().to_owned();
+    text_view.set_content(StyledString::styled(text, style));
+}
+
+/// Styles label of widget built with [label_with_help_layout] (see [FieldStyles]).
+///
+/// [FieldStyles]: struct.FieldStyles.html
+/// [label_with_help_layout]: fn.label_with_help_layout.html
+pub fn set_label_style(view: &mut AnyView, style: Style) {
+    set_text_style(view, 0, style);
+}
+
+/// Styles error of widget built with [label_with_help_layout] (see [FieldStyles]).
+///
+/// [FieldStyles]: struct.FieldStyles.html
+/// [label_with_help_layout]: fn.label_with_help_layout.html
+pub fn set_error_style(view: &mut AnyView, style: Style) {
+    set_text_style(view, 2, style);
+}
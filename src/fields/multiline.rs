@@ -0,0 +1,109 @@
+use clap;
+use cursive::view::AnyView;
+use cursive::views::{BoxView, TextArea};
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use validators::Context;
+
+/// Number of lines shown even if the text is shorter.
+const MIN_HEIGHT: usize = 3;
+
+/// Convienient wrapper around `Field<MultilineManager, String>`.
+///
+/// Value is a single string with embedded newlines (Enter inserts a new line).
+pub struct Multiline;
+
+impl Multiline {
+    /// Creates a new `Field<MultilineManager, String>`.
+    pub fn new<IS: Into<String>>(label: IS) -> fields::Field<MultilineManager, String> {
+        fields::Field::new(label, MultilineManager, "".to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct MultilineManager;
+
+impl WidgetManager for MultilineManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let text_area = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<BoxView<TextArea>>()
+            .unwrap();
+        text_area.get_inner().get_content().to_string()
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let text_area = fields::get_value_view_mut(view)
+            .as_any_mut()
+            .downcast_mut::<BoxView<TextArea>>()
+            .unwrap();
+        text_area.get_inner_mut().set_content(value);
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![("Enter", "new line")]
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        let text_area = TextArea::new().content(value);
+        Box::new(BoxView::with_min_height(MIN_HEIGHT, text_area))
+    }
+}
+
+impl fields::FormField for fields::Field<MultilineManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        match self.run_validators(data, context) {
+            Some(e) => Err(e),
+            None => Ok(Value::String(data.to_owned())),
+        }
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({"type": "string"}))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.clone())
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
+            .required(self.is_required())
+            .takes_value(true)
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        args.value_of(&self.label).unwrap_or("").to_string()
+    }
+}
@@ -4,13 +4,15 @@ use std::rc::Rc;
 use clap;
 use cursive::view::AnyView;
 use cursive::views::{LinearLayout, TextView};
+use serde_json::map::Map;
 use serde_json::value::Value;
 
 use feeders::Feeder;
 use fields::{label_with_help_layout, Field, FormField, WidgetManager};
+use validators::Context;
 use views;
 
-const VALUE_SEP: &'static str = ",";
+const VALUE_SEP: &str = ",";
 
 /// Convienient wrapper around `Field<MultiselectManager, Vec<String>>`.
 pub struct Multiselect;
@@ -34,6 +36,15 @@ pub struct MultiselectManager {
 }
 
 impl WidgetManager for MultiselectManager {
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("Up / Ctrl+p", "previous item"),
+            ("Down / Ctrl+n", "next item"),
+            ("Ctrl+u", "clear value"),
+            ("Enter", "select / deselect item"),
+            ("Space", "deselect item"),
+        ]
+    }
     fn build_value_view(&self, initial: &str) -> Box<AnyView> {
         let mut widget = views::Multiselect::new(Rc::clone(&self.feeder));
         if initial.trim() != "" {
@@ -86,6 +97,31 @@ impl WidgetManager for MultiselectManager {
             .unwrap();
         error_field.set_content(error);
     }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let boxed_widget = (*view).as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap();
+        let boxed_field = (*widget)
+            .get_child_mut(1)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<Box<AnyView>>()
+            .unwrap();
+        let ms = (**boxed_field)
+            .as_any_mut()
+            .downcast_mut::<views::Multiselect>()
+            .unwrap();
+        ms.clear_selection();
+        if value.trim() != "" {
+            let items = value
+                .split(VALUE_SEP)
+                .map(|x| x.to_owned())
+                .collect::<Vec<String>>();
+            ms.select_items(items);
+        }
+    }
 }
 
 impl FormField for Field<MultiselectManager, Vec<String>> {
@@ -93,12 +129,13 @@ impl FormField for Field<MultiselectManager, Vec<String>> {
         &self.widget_manager
     }
     fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
         let items = data.split(VALUE_SEP).collect::<Vec<&str>>();
         for item in items.iter() {
-            for v in &self.validators {
-                if let Some(e) = v.validate(item) {
-                    return Err(e);
-                }
+            if let Some(e) = self.run_validators(item, context) {
+                return Err(e);
             }
         }
         let vec_str = items
@@ -111,16 +148,27 @@ impl FormField for Field<MultiselectManager, Vec<String>> {
     fn get_label(&self) -> &str {
         &self.label
     }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({"type": "array", "items": {"type": "string"}}))
+    }
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.join(VALUE_SEP))
+    }
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
     fn build_widget(&self) -> Box<AnyView> {
-        let initial = self.initial.join(VALUE_SEP);
+        let initial = self.get_initial();
         self.widget_manager
             .build_widget(&self.label, &self.help, &initial)
     }
 
-    fn clap_arg(&self) -> clap::Arg {
-        clap::Arg::with_name(&self.label)
-            .long(&self.label)
-            .help(&self.help)
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
             .required(self.is_required())
             .multiple(true)
             .takes_value(true)
@@ -128,7 +176,7 @@ impl FormField for Field<MultiselectManager, Vec<String>> {
 
     fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
         let values = args.values_of(&self.label)
-            .unwrap_or(clap::Values::default());
+            .unwrap_or_default();
         values.collect::<Vec<&str>>().join(VALUE_SEP)
     }
 }
@@ -0,0 +1,206 @@
+use clap;
+use cursive::view::AnyView;
+use serde_json::map::Map;
+use serde_json::value::{Number as JsonNumber, Value};
+
+use fields;
+use fields::WidgetManager;
+use validators::Context;
+use views::NumberEdit;
+
+/// Convienient wrapper around `Field<NumberManager, Option<f64>>`.
+pub struct Number;
+
+impl Number {
+    /// Creates a new `Field<NumberManager, Option<f64>>` accepting integers.
+    pub fn integer<IS: Into<String>>(label: IS) -> fields::Field<NumberManager, Option<f64>> {
+        fields::Field::new(label, NumberManager::new(true), None)
+    }
+
+    /// Creates a new `Field<NumberManager, Option<f64>>` accepting floats.
+    pub fn float<IS: Into<String>>(label: IS) -> fields::Field<NumberManager, Option<f64>> {
+        fields::Field::new(label, NumberManager::new(false), None)
+    }
+}
+
+#[derive(Clone)]
+pub struct NumberManager {
+    integer: bool,
+    min: Option<f64>,
+    max: Option<f64>,
+    step: f64,
+}
+
+impl NumberManager {
+    fn new(integer: bool) -> Self {
+        NumberManager {
+            integer,
+            min: None,
+            max: None,
+            step: 1.0,
+        }
+    }
+
+    fn format(&self, value: f64) -> String {
+        if self.integer {
+            format!("{}", value as i64)
+        } else {
+            format!("{}", value)
+        }
+    }
+
+    /// Converts `data` to JSON number, empty `data` is `Null`.
+    fn parse(&self, data: &str) -> Result<Value, String> {
+        if data.is_empty() {
+            return Ok(Value::Null);
+        }
+        let (number, value) = if self.integer {
+            let v = data.parse::<i64>()
+                .map_err(|_| "Value must be an integer".to_string())?;
+            (JsonNumber::from(v), v as f64)
+        } else {
+            let v = data.parse::<f64>()
+                .map_err(|_| "Value must be a number".to_string())?;
+            let number = JsonNumber::from_f64(v).ok_or("Value must be a number".to_string())?;
+            (number, v)
+        };
+        if let Some(min) = self.min {
+            if value < min {
+                return Err(format!("Value must be at least {}", self.format(min)));
+            }
+        }
+        if let Some(max) = self.max {
+            if value > max {
+                return Err(format!("Value must be at most {}", self.format(max)));
+            }
+        }
+        Ok(Value::Number(number))
+    }
+}
+
+impl WidgetManager for NumberManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let edit = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<NumberEdit>()
+            .unwrap();
+        (*edit.get_inner().get_content()).clone()
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let edit = fields::get_value_view_mut(view)
+            .as_any_mut()
+            .downcast_mut::<NumberEdit>()
+            .unwrap();
+        edit.get_inner_mut().set_content(value);
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![("Up / Down", "increment / decrement value")]
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        let mut edit = NumberEdit::new(self.integer).step(self.step).value(value);
+        if let Some(min) = self.min {
+            edit = edit.min(min);
+        }
+        if let Some(max) = self.max {
+            edit = edit.max(max);
+        }
+        Box::new(edit)
+    }
+}
+
+impl fields::FormField for fields::Field<NumberManager, Option<f64>> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        match self.run_validators(data, context) {
+            Some(e) => Err(e),
+            None => self.widget_manager.parse(data),
+        }
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        let manager = &self.widget_manager;
+        let kind = if manager.integer { "integer" } else { "number" };
+        let mut schema = json!({ "type": [kind, "null"] });
+        if let Some(min) = manager.min {
+            schema["minimum"] = Value::from(min);
+        }
+        if let Some(max) = manager.max {
+            schema["maximum"] = Value::from(max);
+        }
+        self.build_schema(schema)
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| {
+            self.initial
+                .map(|v| self.widget_manager.format(v))
+                .unwrap_or("".to_string())
+        })
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
+            .required(self.is_required())
+            .takes_value(true)
+            .allow_hyphen_values(true)
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        args.value_of(&self.label)
+            .map(|v| v.to_string())
+            .unwrap_or(self.get_initial())
+    }
+}
+
+impl fields::Field<NumberManager, Option<f64>> {
+    /// Sets initial `value` of `field`.
+    pub fn initial<N: Into<f64>>(mut self, initial: N) -> Self {
+        self.initial = Some(initial.into());
+        self
+    }
+    /// Sets the lowest allowed value.
+    pub fn min<N: Into<f64>>(mut self, min: N) -> Self {
+        self.widget_manager.min = Some(min.into());
+        self
+    }
+    /// Sets the highest allowed value.
+    pub fn max<N: Into<f64>>(mut self, max: N) -> Self {
+        self.widget_manager.max = Some(max.into());
+        self
+    }
+    /// Sets value added (subtracted) with Up (Down) key, by default it's 1.
+    pub fn step<N: Into<f64>>(mut self, step: N) -> Self {
+        self.widget_manager.step = step.into();
+        self
+    }
+}
@@ -0,0 +1,123 @@
+use atty;
+use clap;
+use cursive::view::AnyView;
+use rpassword;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use fields::text::TextManager;
+use validators::Context;
+
+/// Text shown in errors instead of password.
+const MASK: &str = "*****";
+
+/// Convienient wrapper around `Field<PasswordManager, String>`.
+///
+/// Typed text is masked and it's never shown in errors.
+///
+/// In CLI, when password is omitted and terminal is available, it's asked for (without echo).
+pub struct Password;
+
+impl Password {
+    /// Creates a new `Field<PasswordManager, String>`.
+    pub fn new<IS: Into<String>>(label: IS) -> fields::Field<PasswordManager, String> {
+        fields::Field::new(label, PasswordManager(TextManager::secret()), "".to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct PasswordManager(TextManager);
+
+impl WidgetManager for PasswordManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        self.0.build_widget(label, help, initial)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        self.0.get_value(view)
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        self.0.set_error(view, error)
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        self.0.set_value(view, value)
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        self.0.build_value_view(value)
+    }
+}
+
+impl fields::FormField for fields::Field<PasswordManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        match self.run_validators(data, context) {
+            Some(ref e) if !data.is_empty() => Err(e.replace(data, MASK)),
+            Some(e) => Err(e),
+            None => Ok(Value::String(data.to_owned())),
+        }
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({"type": "string"}))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.clone())
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn is_secret(&self) -> bool {
+        true
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        // not required, because missing value is asked for
+        self.base_clap_arg().takes_value(true)
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        match args.value_of(&self.label) {
+            Some(v) => v.to_string(),
+            None if atty::is(atty::Stream::Stdin) => {
+                let prompt = format!("{}: ", self.label);
+                rpassword::read_password_from_tty(Some(&prompt)).unwrap_or("".to_string())
+            }
+            None => "".to_string(),
+        }
+    }
+}
+
+impl fields::Field<PasswordManager, String> {
+    /// Sets text shown (dimmed) while `field` is empty.
+    ///
+    /// Unlike [initial] it's never treated as `field`'s value.
+    ///
+    /// [initial]: struct.Field.html#method.initial
+    pub fn placeholder<IS: Into<String>>(mut self, placeholder: IS) -> Self {
+        self.widget_manager.0.set_placeholder(placeholder);
+        self
+    }
+}
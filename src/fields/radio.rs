@@ -0,0 +1,190 @@
+use clap;
+use cursive::view::AnyView;
+use cursive::views::{self, LinearLayout, RadioButton, TextView};
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use validators::Context;
+
+/// Text separating buttons.
+const SPACER: &str = "  ";
+
+/// Convienient wrapper around `Field<RadioGroupManager, String>`.
+pub struct RadioGroup;
+
+impl RadioGroup {
+    /// Creates a new `Field<RadioGroupManager, String>` with `options` given as pairs: (shown
+    /// label, value).
+    ///
+    /// Options are shown in single line, by default the first one is selected.
+    pub fn new<IS, L, V>(
+        label: IS,
+        options: Vec<(L, V)>,
+    ) -> fields::Field<RadioGroupManager, String>
+    where
+        IS: Into<String>,
+        L: Into<String>,
+        V: Into<String>,
+    {
+        let options = options
+            .into_iter()
+            .map(|(l, v)| (l.into(), v.into()))
+            .collect();
+        fields::Field::new(label, RadioGroupManager { options }, "".to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct RadioGroupManager {
+    options: Vec<(String, String)>,
+}
+
+impl RadioGroupManager {
+    fn position(&self, value: &str) -> Option<usize> {
+        self.options.iter().position(|(_, v)| v == value)
+    }
+}
+
+/// Returns buttons of `layout` (skipping spacers).
+fn get_buttons(layout: &LinearLayout) -> Vec<&RadioButton<String>> {
+    let mut buttons = Vec::new();
+    let mut idx = 0;
+    while let Some(child) = layout.get_child(idx) {
+        idx += 1;
+        if let Some(button) = child.as_any().downcast_ref::<RadioButton<String>>() {
+            buttons.push(button);
+        }
+    }
+    buttons
+}
+
+impl WidgetManager for RadioGroupManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let layout = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        get_buttons(layout)
+            .iter()
+            .position(|b| b.is_selected())
+            .and_then(|idx| self.options.get(idx))
+            .map(|(_, v)| v.clone())
+            .unwrap_or("".to_string())
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let layout = fields::get_value_view_mut(view)
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap();
+        // buttons are separated by spacers
+        let idx = self.position(value).unwrap_or(0) * 2;
+        if let Some(button) = layout
+            .get_child_mut(idx)
+            .and_then(|c| c.as_any_mut().downcast_mut::<RadioButton<String>>())
+        {
+            button.select();
+        }
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![("Left/Right", "move between options"), ("Enter", "select option")]
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        let selected = self.position(value).unwrap_or(0);
+        let mut group = views::RadioGroup::new();
+        let mut layout = LinearLayout::horizontal();
+        for (idx, (l, v)) in self.options.iter().enumerate() {
+            if idx > 0 {
+                layout.add_child(TextView::new(SPACER));
+            }
+            let mut button = group.button(v.clone(), l.as_str());
+            if idx == selected {
+                button.select();
+            }
+            layout.add_child(button);
+        }
+        Box::new(layout)
+    }
+}
+
+impl fields::FormField for fields::Field<RadioGroupManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        if let Some(e) = self.run_validators(data, context) {
+            return Err(e);
+        }
+        if data.is_empty() || self.widget_manager.position(data).is_some() {
+            Ok(Value::String(data.to_owned()))
+        } else {
+            Err("Value must be one of options".to_string())
+        }
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        let options = self.widget_manager
+            .options
+            .iter()
+            .map(|option| Value::from(option.1.as_str()))
+            .collect::<Vec<Value>>();
+        self.build_schema(json!({"type": "string", "enum": options}))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.clone())
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        let values = self.widget_manager
+            .options
+            .iter()
+            .map(|(_, v)| v.as_str())
+            .collect::<Vec<&str>>();
+        self.base_clap_arg()
+            .required(self.is_required())
+            .takes_value(true)
+            .possible_values(&values)
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        match args.value_of(&self.label) {
+            Some(v) => v.to_string(),
+            None if self.get_initial().is_empty() => self.widget_manager
+                .options
+                .first()
+                .map(|(_, v)| v.clone())
+                .unwrap_or("".to_string()),
+            None => self.get_initial(),
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::rc::Rc;
+
+use clap;
+use cursive::direction::Direction;
+use cursive::event::{Event, EventResult, Key};
+use cursive::traits::View;
+use cursive::view::{AnyView, ViewWrapper};
+use cursive::views::LinearLayout;
+use serde_json;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::{FormField, WidgetManager};
+use validators::Context;
+
+/// Convienient wrapper around `Field<RepeatedManager, Vec<String>>`.
+///
+/// Repeats `field` in rows (Insert adds one, Ctrl+d removes focused one) producing array of
+/// their values, empty rows are skipped. In CLI argument can be passed many times, value of
+/// environment variable (see `default_from_env`) gives rows separated with comma.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::fields::{Repeated, Text};
+/// # use fui::form::FormView;
+/// # fn main() {
+/// let form = FormView::new().field(Repeated::new(Text::new("file")));
+/// # }
+/// ```
+pub struct Repeated;
+
+impl Repeated {
+    /// Creates a new `Field<RepeatedManager, Vec<String>>` repeating `field`.
+    pub fn new<F: FormField + 'static>(field: F) -> fields::Field<RepeatedManager, Vec<String>> {
+        let label = field.get_label().to_owned();
+        let manager = RepeatedManager {
+            inner: Rc::new(field),
+        };
+        fields::Field::new(label, manager, Vec::new())
+    }
+}
+
+/// Decodes values of rows, which are kept as JSON array.
+fn decode(value: &str) -> Vec<String> {
+    serde_json::from_str(value).unwrap_or(Vec::new())
+}
+
+fn encode(values: &[String]) -> String {
+    serde_json::to_string(values).unwrap()
+}
+
+/// Rows of repeated `field`'s widgets.
+struct RepeatedView {
+    view: LinearLayout,
+    inner: Rc<FormField>,
+}
+
+impl RepeatedView {
+    fn new(inner: Rc<FormField>, values: Vec<String>) -> Self {
+        let mut view = RepeatedView {
+            view: LinearLayout::vertical(),
+            inner,
+        };
+        view.set_values(values);
+        view
+    }
+
+    /// Rebuilds rows for `values`, there is always at least one (maybe empty) row.
+    fn set_values(&mut self, mut values: Vec<String>) {
+        if values.is_empty() {
+            values.push(self.inner.get_initial());
+        }
+        self.view = LinearLayout::vertical();
+        for value in values {
+            self.add_row(&value);
+        }
+    }
+
+    fn add_row(&mut self, value: &str) {
+        let label = format!("#{}", self.rows_count() + 1);
+        let row = self.inner
+            .get_widget_manager()
+            .build_widget(&label, "", value);
+        self.view.add_child(row);
+    }
+
+    fn rows_count(&self) -> usize {
+        let mut idx = 0;
+        while self.view.get_child(idx).is_some() {
+            idx += 1;
+        }
+        idx
+    }
+
+    fn get_values(&self) -> Vec<String> {
+        let manager = self.inner.get_widget_manager();
+        (0..self.rows_count())
+            .map(|idx| manager.get_value(self.view.get_child(idx).unwrap()))
+            .collect()
+    }
+
+    fn remove_focused(&mut self) {
+        let focused = self.view.get_focus_index();
+        let mut values = self.get_values();
+        values.remove(focused);
+        self.set_values(values);
+        self.view.take_focus(Direction::front());
+    }
+}
+
+impl ViewWrapper for RepeatedView {
+    wrap_impl!(self.view: LinearLayout);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Ins) => {
+                let initial = self.inner.get_initial();
+                self.add_row(&initial);
+                EventResult::Consumed(None)
+            }
+            Event::CtrlChar('d') => {
+                self.remove_focused();
+                EventResult::Consumed(None)
+            }
+            _ => self.view.on_event(event),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RepeatedManager {
+    inner: Rc<FormField>,
+}
+
+impl WidgetManager for RepeatedManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let rows = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<RepeatedView>()
+            .unwrap();
+        encode(&rows.get_values())
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let rows = fields::get_value_view_mut(view)
+            .as_any_mut()
+            .downcast_mut::<RepeatedView>()
+            .unwrap();
+        rows.set_values(decode(value));
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        let mut bindings = vec![("Insert", "add row"), ("Ctrl+d", "remove focused row")];
+        bindings.extend(self.inner.get_widget_manager().keybindings());
+        bindings
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        Box::new(RepeatedView::new(Rc::clone(&self.inner), decode(value)))
+    }
+}
+
+impl fields::FormField for fields::Field<RepeatedManager, Vec<String>> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        let values = decode(data)
+            .into_iter()
+            .filter(|v| !v.is_empty())
+            .collect::<Vec<String>>();
+        if values.is_empty() {
+            if let Some(e) = self.run_validators("", context) {
+                return Err(e);
+            }
+        }
+        let inner = &self.widget_manager.inner;
+        let mut items = Vec::with_capacity(values.len());
+        for (idx, value) in values.iter().enumerate() {
+            let item = match self.run_validators(value, context) {
+                Some(e) => Err(e),
+                None => inner.validate_with(value, context),
+            };
+            items.push(item.map_err(|e| format!("#{}: {}", idx + 1, e))?);
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        // items are described by repeated field (its schema is keyed by its label)
+        let inner = &self.widget_manager.inner;
+        let items = inner
+            .schema()
+            .remove(inner.get_label())
+            .unwrap_or_else(|| json!({"type": "string"}));
+        self.build_schema(json!({"type": "array", "items": items}))
+    }
+
+    fn get_initial(&self) -> String {
+        match self.get_env_value() {
+            // env value gives rows separated with comma
+            Some(value) => {
+                encode(&value.split(',').map(|v| v.trim().to_string()).collect::<Vec<String>>())
+            }
+            None => encode(&self.initial),
+        }
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        let arg = self.widget_manager
+            .inner
+            .clap_arg()
+            .required(self.is_required())
+            .multiple(true)
+            .takes_value(true);
+        if self.help.is_empty() {
+            arg
+        } else {
+            arg.help(&self.help)
+        }
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        match args.values_of(&self.label) {
+            Some(values) => encode(&values.map(|v| v.to_string()).collect::<Vec<String>>()),
+            None => self.get_initial(),
+        }
+    }
+
+    fn value2str(&self, value: &Value) -> String {
+        let items = value.as_array().map(|a| a.as_slice()).unwrap_or(&[]);
+        let inner = &self.widget_manager.inner;
+        encode(&items.iter().map(|v| inner.value2str(v)).collect::<Vec<String>>())
+    }
+}
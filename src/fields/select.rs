@@ -0,0 +1,152 @@
+use clap;
+use cursive::view::AnyView;
+use cursive::views::SelectView;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use validators::Context;
+
+/// Convienient wrapper around `Field<SelectManager, String>`.
+pub struct Select;
+
+impl Select {
+    /// Creates a new `Field<SelectManager, String>` with `options` given as pairs: (shown label,
+    /// value).
+    ///
+    /// Selected option's value lands in data, by default it's the first option.
+    pub fn new<IS, L, V>(label: IS, options: Vec<(L, V)>) -> fields::Field<SelectManager, String>
+    where
+        IS: Into<String>,
+        L: Into<String>,
+        V: Into<String>,
+    {
+        let options = options
+            .into_iter()
+            .map(|(l, v)| (l.into(), v.into()))
+            .collect();
+        fields::Field::new(label, SelectManager { options }, "".to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct SelectManager {
+    options: Vec<(String, String)>,
+}
+
+impl WidgetManager for SelectManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let select = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<SelectView<String>>()
+            .unwrap();
+        match select.selected_id() {
+            Some(_) => (*select.selection()).clone(),
+            None => "".to_string(),
+        }
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let select = fields::get_value_view_mut(view)
+            .as_any_mut()
+            .downcast_mut::<SelectView<String>>()
+            .unwrap();
+        let idx = self.options.iter().position(|(_, v)| v == value);
+        select.set_selection(idx.unwrap_or(0));
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![("Enter", "show options")]
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        let mut select = SelectView::<String>::new().popup();
+        for (l, v) in self.options.iter() {
+            select.add_item(l.as_str(), v.clone());
+        }
+        if let Some(idx) = self.options.iter().position(|(_, v)| v == value) {
+            select.set_selection(idx);
+        }
+        Box::new(select)
+    }
+}
+
+impl fields::FormField for fields::Field<SelectManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        if let Some(e) = self.run_validators(data, context) {
+            return Err(e);
+        }
+        let options = &self.widget_manager.options;
+        if data.is_empty() || options.iter().any(|(_, v)| v == data) {
+            Ok(Value::String(data.to_owned()))
+        } else {
+            Err("Value must be one of options".to_string())
+        }
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        let options = self.widget_manager
+            .options
+            .iter()
+            .map(|option| Value::from(option.1.as_str()))
+            .collect::<Vec<Value>>();
+        self.build_schema(json!({"type": "string", "enum": options}))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.clone())
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        let values = self.widget_manager
+            .options
+            .iter()
+            .map(|(_, v)| v.as_str())
+            .collect::<Vec<&str>>();
+        self.base_clap_arg()
+            .required(self.is_required())
+            .takes_value(true)
+            .possible_values(&values)
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        match args.value_of(&self.label) {
+            Some(v) => v.to_string(),
+            None if self.get_initial().is_empty() => self.widget_manager
+                .options
+                .first()
+                .map(|(_, v)| v.clone())
+                .unwrap_or("".to_string()),
+            None => self.get_initial(),
+        }
+    }
+}
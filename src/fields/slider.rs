@@ -0,0 +1,181 @@
+use clap;
+use cursive::view::AnyView;
+use serde_json::map::Map;
+use serde_json::value::{Number as JsonNumber, Value};
+
+use fields;
+use fields::WidgetManager;
+use validators::Context;
+use views::SliderEdit;
+
+/// Convienient wrapper around `Field<SliderManager, f64>`.
+pub struct Slider;
+
+impl Slider {
+    /// Creates a new `Field<SliderManager, f64>` picking values from `min` to `max` by `step`.
+    ///
+    /// Value is serialized as an integer if `min` and `step` are integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` isn't positive or `max` is less than `min`.
+    pub fn new<IS, N1, N2, N3>(
+        label: IS,
+        min: N1,
+        max: N2,
+        step: N3,
+    ) -> fields::Field<SliderManager, f64>
+    where
+        IS: Into<String>,
+        N1: Into<f64>,
+        N2: Into<f64>,
+        N3: Into<f64>,
+    {
+        let (min, max, step) = (min.into(), max.into(), step.into());
+        assert!(step > 0.0, "Slider's step must be positive, got: {}", step);
+        assert!(max >= min, "Slider's max ({}) is less than min ({})", max, min);
+        let manager = SliderManager { min, max, step };
+        fields::Field::new(label, manager, min)
+    }
+}
+
+#[derive(Clone)]
+pub struct SliderManager {
+    min: f64,
+    max: f64,
+    step: f64,
+}
+
+impl SliderManager {
+    fn is_integer(&self) -> bool {
+        self.min.fract() == 0.0 && self.step.fract() == 0.0
+    }
+
+    fn format(&self, value: f64) -> String {
+        if self.is_integer() {
+            format!("{}", value as i64)
+        } else {
+            format!("{}", value)
+        }
+    }
+
+    /// Converts `data` to JSON number.
+    fn parse(&self, data: &str) -> Result<Value, String> {
+        let value = data.parse::<f64>()
+            .map_err(|_| "Value must be a number".to_string())?;
+        if value < self.min || value > self.max {
+            return Err(format!(
+                "Value must be between {} and {}",
+                self.format(self.min),
+                self.format(self.max)
+            ));
+        }
+        let number = if self.is_integer() {
+            if value.fract() != 0.0 {
+                return Err("Value must be an integer".to_string());
+            }
+            JsonNumber::from(value as i64)
+        } else {
+            JsonNumber::from_f64(value).ok_or("Value must be a number".to_string())?
+        };
+        Ok(Value::Number(number))
+    }
+}
+
+impl WidgetManager for SliderManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let slider = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<SliderEdit>()
+            .unwrap();
+        self.format(slider.get_value())
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let slider = fields::get_value_view_mut(view)
+            .as_any_mut()
+            .downcast_mut::<SliderEdit>()
+            .unwrap();
+        slider.set_value(value.parse().unwrap_or(self.min));
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![("Left / Right", "decrement / increment value"), ("Home / End", "min / max value")]
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        let value = value.parse().unwrap_or(self.min);
+        Box::new(SliderEdit::new(self.min, self.max, self.step).value(value))
+    }
+}
+
+impl fields::FormField for fields::Field<SliderManager, f64> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        match self.run_validators(data, context) {
+            Some(e) => Err(e),
+            None => self.widget_manager.parse(data),
+        }
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        let manager = &self.widget_manager;
+        let kind = if manager.is_integer() { "integer" } else { "number" };
+        self.build_schema(json!({
+            "type": kind,
+            "minimum": manager.min,
+            "maximum": manager.max,
+        }))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.widget_manager.format(self.initial))
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
+            .takes_value(true)
+            .allow_hyphen_values(true)
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        args.value_of(&self.label)
+            .map(|v| v.to_string())
+            .unwrap_or(self.get_initial())
+    }
+}
+
+impl fields::Field<SliderManager, f64> {
+    /// Sets initial `value` of `field`, by default it's `min`.
+    pub fn initial<N: Into<f64>>(mut self, initial: N) -> Self {
+        self.initial = initial.into();
+        self
+    }
+}
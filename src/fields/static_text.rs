@@ -0,0 +1,108 @@
+use clap;
+use cursive::view::AnyView;
+use cursive::views::TextView;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+
+/// Convienient wrapper around `Field<StaticManager, String>`.
+///
+/// Shows read-only text (e.g. computed path or a warning), which isn't a part of form's data nor
+/// CLI. Text can be changed with [FormView::set_field_value].
+///
+/// [FormView::set_field_value]: ../form/struct.FormView.html#method.set_field_value
+pub struct Static;
+
+impl Static {
+    /// Creates a new `Field<StaticManager, String>` showing `text`.
+    pub fn new<IS1, IS2>(label: IS1, text: IS2) -> fields::Field<StaticManager, String>
+    where
+        IS1: Into<String>,
+        IS2: Into<String>,
+    {
+        fields::Field::new(label, StaticManager, text.into())
+    }
+}
+
+#[derive(Clone)]
+pub struct StaticManager;
+
+impl WidgetManager for StaticManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let text = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<TextView>()
+            .unwrap();
+        text.get_content().source().to_string()
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let text = fields::get_value_view_mut(view)
+            .as_any_mut()
+            .downcast_mut::<TextView>()
+            .unwrap();
+        text.set_content(value);
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        Box::new(TextView::new(value))
+    }
+}
+
+impl fields::FormField for fields::Field<StaticManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.initial)
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        Ok(Value::String(data.to_owned()))
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        // it's not included in form's data
+        Map::new()
+    }
+
+    fn get_initial(&self) -> String {
+        self.initial.clone()
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg().hidden(true)
+    }
+
+    fn clap_args(&self) -> Vec<clap::Arg<'_, '_>> {
+        Vec::new()
+    }
+
+    fn clap_args2str(&self, _args: &clap::ArgMatches) -> String {
+        self.initial.clone()
+    }
+
+    fn contribute(&self, _value: Value) -> Map<String, Value> {
+        Map::new()
+    }
+}
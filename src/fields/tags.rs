@@ -0,0 +1,132 @@
+use clap;
+use cursive::view::AnyView;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use validators::Context;
+use views::TagsEdit;
+
+const VALUE_SEP: &str = ",";
+
+/// Convienient wrapper around `Field<TagsManager, Vec<String>>`.
+///
+/// Typed values (separated with `,` or Enter) are collected into array.
+pub struct Tags;
+
+impl Tags {
+    /// Creates a new `Field<TagsManager, Vec<String>>`.
+    pub fn new<IS: Into<String>>(label: IS) -> fields::Field<TagsManager, Vec<String>> {
+        fields::Field::new(label, TagsManager, Vec::new())
+    }
+}
+
+/// Splits `value` into tags (skipping empty ones).
+fn split(value: &str) -> Vec<String> {
+    value
+        .split(VALUE_SEP)
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .map(|x| x.to_owned())
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct TagsManager;
+
+impl WidgetManager for TagsManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let edit = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<TagsEdit>()
+            .unwrap();
+        edit.get_tags().join(VALUE_SEP)
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let edit = fields::get_value_view_mut(view)
+            .as_any_mut()
+            .downcast_mut::<TagsEdit>()
+            .unwrap();
+        edit.set_tags(split(value));
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            (", / Enter", "add tag"),
+            ("Backspace", "remove last tag (when nothing is typed)"),
+        ]
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        Box::new(TagsEdit::new().tags(split(value)))
+    }
+}
+
+impl fields::FormField for fields::Field<TagsManager, Vec<String>> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        let tags = split(data);
+        if tags.is_empty() {
+            if let Some(e) = self.run_validators("", context) {
+                return Err(e);
+            }
+        }
+        for tag in tags.iter() {
+            if let Some(e) = self.run_validators(tag, context) {
+                return Err(e);
+            }
+        }
+        Ok(Value::Array(tags.into_iter().map(Value::String).collect()))
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({"type": "array", "items": {"type": "string"}}))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.join(VALUE_SEP))
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
+            .required(self.is_required())
+            .multiple(true)
+            .takes_value(true)
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        match args.values_of(&self.label) {
+            Some(values) => values.collect::<Vec<&str>>().join(VALUE_SEP),
+            None => self.get_initial(),
+        }
+    }
+}
@@ -1,10 +1,13 @@
 use clap;
 use cursive::view::AnyView;
 use cursive::views;
+use serde_json::map::Map;
 use serde_json::value::Value;
 
 use fields;
 use fields::WidgetManager;
+use validators::Context;
+use views::EditWithPlaceholder;
 
 /// Convienient wrapper around `Field<TextManager, String>`.
 pub struct Text;
@@ -12,12 +15,46 @@ pub struct Text;
 impl Text {
     /// Creates a new `Field<TextManager, String>`.
     pub fn new<IS: Into<String>>(label: IS) -> fields::Field<TextManager, String> {
-        fields::Field::new(label, TextManager, "".to_string())
+        fields::Field::new(label, TextManager::new(), "".to_string())
     }
 }
 
 #[derive(Clone)]
-pub struct TextManager;
+pub struct TextManager {
+    placeholder: String,
+    secret: bool,
+}
+
+impl TextManager {
+    /// Creates a new `TextManager` with plain text widget.
+    pub fn new() -> Self {
+        TextManager {
+            placeholder: "".to_string(),
+            secret: false,
+        }
+    }
+
+    /// Creates a new `TextManager` with plain text widget showing `placeholder`.
+    pub fn with_placeholder<IS: Into<String>>(placeholder: IS) -> Self {
+        TextManager {
+            placeholder: placeholder.into(),
+            secret: false,
+        }
+    }
+
+    /// Sets text shown (dimmed) while widget is empty.
+    pub fn set_placeholder<IS: Into<String>>(&mut self, placeholder: IS) {
+        self.placeholder = placeholder.into();
+    }
+
+    /// Creates a new `TextManager` which widget masks typed text.
+    pub fn secret() -> Self {
+        TextManager {
+            placeholder: "".to_string(),
+            secret: true,
+        }
+    }
+}
 
 impl WidgetManager for TextManager {
     fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
@@ -26,22 +63,22 @@ impl WidgetManager for TextManager {
     }
     fn get_value(&self, view: &AnyView) -> String {
         // fuck yea!
-        let boxed_widget: &Box<AnyView> = (*view.as_any()).downcast_ref::<Box<AnyView>>().unwrap();
+        let boxed_widget = (*view.as_any()).downcast_ref::<Box<AnyView>>().unwrap();
         let widget: &views::LinearLayout = (**boxed_widget)
             .as_any()
             .downcast_ref::<views::LinearLayout>()
             .unwrap();
-        let boxed_widget: &Box<AnyView> = widget
+        let boxed_widget = widget
             .get_child(1)
             .unwrap()
             .as_any()
             .downcast_ref::<Box<AnyView>>()
             .unwrap();
-        let edit: &views::EditView = (**boxed_widget)
+        let edit: &EditWithPlaceholder = (**boxed_widget)
             .as_any()
-            .downcast_ref::<views::EditView>()
+            .downcast_ref::<EditWithPlaceholder>()
             .unwrap();
-        let value: String = (&*edit.get_content()).clone();
+        let value: String = (*edit.get_inner().get_content()).clone();
         value
     }
     fn set_error(&self, view: &mut AnyView, error: &str) {
@@ -58,8 +95,28 @@ impl WidgetManager for TextManager {
             .unwrap();
         text.set_content(error);
     }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let view: &mut Box<AnyView> = (*view.as_any_mut()).downcast_mut::<Box<AnyView>>().unwrap();
+        let layout: &mut views::LinearLayout = (**view)
+            .as_any_mut()
+            .downcast_mut::<views::LinearLayout>()
+            .unwrap();
+        let boxed_widget: &mut Box<AnyView> = layout
+            .get_child_mut(1)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<Box<AnyView>>()
+            .unwrap();
+        let edit: &mut EditWithPlaceholder = (**boxed_widget)
+            .as_any_mut()
+            .downcast_mut::<EditWithPlaceholder>()
+            .unwrap();
+        edit.get_inner_mut().set_content(value);
+    }
     fn build_value_view(&self, value: &str) -> Box<AnyView> {
-        Box::new(views::EditView::new().content(value))
+        let mut edit = views::EditView::new().content(value);
+        edit.set_secret(self.secret);
+        Box::new(EditWithPlaceholder::new(edit).placeholder(self.placeholder.as_str()))
     }
 }
 
@@ -69,16 +126,18 @@ impl fields::FormField for fields::Field<TextManager, String> {
     }
     fn build_widget(&self) -> Box<AnyView> {
         self.widget_manager
-            .build_widget(&self.label, &self.help, &self.initial)
+            .build_widget(&self.label, &self.help, &self.get_initial())
     }
 
     fn validate(&self, data: &str) -> Result<Value, String> {
-        for v in &self.validators {
-            if let Some(e) = v.validate(data) {
-                return Err(e);
-            }
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        match self.run_validators(data, context) {
+            Some(e) => Err(e),
+            None => Ok(Value::String(data.to_owned())),
         }
-        Ok(Value::String(data.to_owned()))
     }
 
     /// Gets label of the field
@@ -86,10 +145,24 @@ impl fields::FormField for fields::Field<TextManager, String> {
         &self.label
     }
 
-    fn clap_arg(&self) -> clap::Arg {
-        clap::Arg::with_name(&self.label)
-            .long(&self.label)
-            .help(&self.help)
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({"type": "string"}))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.clone())
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
             .required(self.is_required())
             .takes_value(true)
     }
@@ -99,6 +172,18 @@ impl fields::FormField for fields::Field<TextManager, String> {
     }
 }
 
+impl fields::Field<TextManager, String> {
+    /// Sets text shown (dimmed) while `field` is empty.
+    ///
+    /// Unlike [initial] it's never treated as `field`'s value.
+    ///
+    /// [initial]: struct.Field.html#method.initial
+    pub fn placeholder<IS: Into<String>>(mut self, placeholder: IS) -> Self {
+        self.widget_manager.set_placeholder(placeholder);
+        self
+    }
+}
+
 impl<W: WidgetManager> fields::Field<W, String> {
     /// Sets initial `value` of `field`.
     pub fn initial<IS: Into<String>>(mut self, initial: IS) -> Self {
@@ -0,0 +1,271 @@
+use clap;
+use cursive::traits::Boxable;
+use cursive::view::AnyView;
+use cursive::views::{BoxView, DummyView, LinearLayout};
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use utils::{parse_date, parse_time};
+use validators::Context;
+use views::{DateEdit, TimeEdit};
+
+/// Width of date part of `DateTime`'s widget.
+const DATE_WIDTH: usize = 11;
+
+/// Describes how timezone is added to `Time` & `DateTime` values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Timezone {
+    /// Local time without timezone designator (e.g. `12:30`).
+    Naive,
+    /// UTC time marked with `Z` (e.g. `12:30Z`).
+    Utc,
+    /// Time with fixed offset in minutes (e.g. `Offset(90)` gives `12:30+01:30`).
+    Offset(i32),
+}
+
+impl Timezone {
+    /// Returns timezone designator appended to values.
+    fn suffix(&self) -> String {
+        match *self {
+            Timezone::Naive => "".to_string(),
+            Timezone::Utc => "Z".to_string(),
+            Timezone::Offset(minutes) => {
+                let sign = if minutes < 0 { '-' } else { '+' };
+                let minutes = minutes.abs();
+                format!("{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+            }
+        }
+    }
+}
+
+/// Convienient wrapper around `Field<TimeManager, String>`.
+///
+/// Value is a time in ISO-8601 format (`HH:MM`), e.g. `12:30`.
+pub struct Time;
+
+impl Time {
+    /// Creates a new `Field<TimeManager, String>`.
+    pub fn new<IS: Into<String>>(label: IS) -> fields::Field<TimeManager, String> {
+        fields::Field::new(label, TimeManager::new(false), "".to_string())
+    }
+}
+
+/// Convienient wrapper around `Field<TimeManager, String>`.
+///
+/// Value is a date & time in ISO-8601 format (`YYYY-MM-DDTHH:MM`), e.g. `2018-03-21T12:30`.
+pub struct DateTime;
+
+impl DateTime {
+    /// Creates a new `Field<TimeManager, String>` with date.
+    pub fn new<IS: Into<String>>(label: IS) -> fields::Field<TimeManager, String> {
+        fields::Field::new(label, TimeManager::new(true), "".to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct TimeManager {
+    with_date: bool,
+    timezone: Timezone,
+}
+
+impl TimeManager {
+    fn new(with_date: bool) -> Self {
+        TimeManager {
+            with_date,
+            timezone: Timezone::Naive,
+        }
+    }
+
+    fn format(&self) -> &'static str {
+        if self.with_date {
+            "YYYY-MM-DDTHH:MM"
+        } else {
+            "HH:MM"
+        }
+    }
+
+    /// Checks `data` (with or without timezone designator) and returns it with designator.
+    fn parse(&self, data: &str) -> Result<Value, String> {
+        if data.is_empty() {
+            return Ok(Value::String("".to_string()));
+        }
+        let suffix = self.timezone.suffix();
+        let value = if !suffix.is_empty() && data.ends_with(&suffix) {
+            &data[..data.len() - suffix.len()]
+        } else {
+            data
+        };
+        let is_valid = if self.with_date {
+            let mut parts = value.splitn(2, 'T');
+            let date = parts.next().unwrap_or("");
+            let time = parts.next().unwrap_or("");
+            parse_date(date).is_some() && parse_time(time).is_some()
+        } else {
+            parse_time(value).is_some()
+        };
+        if is_valid {
+            Ok(Value::String(format!("{}{}", value, suffix)))
+        } else {
+            Err(format!("Value must be in {} format", self.format()))
+        }
+    }
+
+    fn get_time_edit<'a>(&self, view: &'a AnyView) -> &'a TimeEdit {
+        let view = fields::get_value_view(view);
+        if self.with_date {
+            let layout = view.as_any().downcast_ref::<LinearLayout>().unwrap();
+            layout
+                .get_child(2)
+                .unwrap()
+                .as_any()
+                .downcast_ref::<TimeEdit>()
+                .unwrap()
+        } else {
+            view.as_any().downcast_ref::<TimeEdit>().unwrap()
+        }
+    }
+}
+
+impl WidgetManager for TimeManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let time = self.get_time_edit(view).get_value();
+        if !self.with_date {
+            return time;
+        }
+        let layout = fields::get_value_view(view)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        let date = layout
+            .get_child(0)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<BoxView<DateEdit>>()
+            .unwrap()
+            .get_inner()
+            .get_inner()
+            .get_inner()
+            .get_content();
+        if date.is_empty() && time.is_empty() {
+            "".to_string()
+        } else {
+            format!("{}T{}", date, time)
+        }
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_error_text(view, error);
+    }
+    fn set_value(&self, view: &mut AnyView, value: &str) {
+        let view = fields::get_value_view_mut(view);
+        if !self.with_date {
+            let edit = view.as_any_mut().downcast_mut::<TimeEdit>().unwrap();
+            edit.set_value(value);
+            return;
+        }
+        let mut parts = value.splitn(2, 'T');
+        let date = parts.next().unwrap_or("");
+        let time = parts.next().unwrap_or("");
+        let layout = view.as_any_mut().downcast_mut::<LinearLayout>().unwrap();
+        layout
+            .get_child_mut(0)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<BoxView<DateEdit>>()
+            .unwrap()
+            .get_inner_mut()
+            .get_inner_mut()
+            .get_inner_mut()
+            .set_content(date);
+        layout
+            .get_child_mut(2)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<TimeEdit>()
+            .unwrap()
+            .set_value(time);
+    }
+    fn keybindings(&self) -> Vec<(&'static str, &'static str)> {
+        vec![("Up / Down", "increment / decrement value")]
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        if !self.with_date {
+            return Box::new(TimeEdit::new().value(value));
+        }
+        let mut parts = value.splitn(2, 'T');
+        let date = parts.next().unwrap_or("");
+        let time = parts.next().unwrap_or("");
+        let layout = LinearLayout::horizontal()
+            .child(DateEdit::new().value(date).fixed_width(DATE_WIDTH))
+            .child(DummyView)
+            .child(TimeEdit::new().value(time));
+        Box::new(layout)
+    }
+}
+
+impl fields::FormField for fields::Field<TimeManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.get_initial())
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        self.validate_with(data, &Context::new())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Result<Value, String> {
+        match self.run_validators(data, context) {
+            Some(e) => Err(e),
+            None => self.widget_manager.parse(data),
+        }
+    }
+
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+
+    fn get_help(&self) -> &str {
+        &self.help
+    }
+
+    fn schema(&self) -> Map<String, Value> {
+        self.build_schema(json!({"type": "string"}))
+    }
+
+    fn get_initial(&self) -> String {
+        self.get_env_value().unwrap_or_else(|| self.initial.clone())
+    }
+
+    fn get_width(&self) -> Option<usize> {
+        self.width
+    }
+
+    fn clap_arg(&self) -> clap::Arg<'_, '_> {
+        self.base_clap_arg()
+            .required(self.is_required())
+            .takes_value(true)
+            .value_name(self.widget_manager.format())
+    }
+
+    fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+        args.value_of(&self.label)
+            .map(|v| v.to_string())
+            .unwrap_or(self.get_initial())
+    }
+}
+
+impl fields::Field<TimeManager, String> {
+    /// Sets `timezone` added to value, by default it's `Timezone::Naive`.
+    pub fn timezone(mut self, timezone: Timezone) -> Self {
+        self.widget_manager.timezone = timezone;
+        self
+    }
+}
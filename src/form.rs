@@ -1,242 +1,4338 @@
 //! Contains `form` related concetps like `FormView`.
 use std::rc::Rc;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
 
 use clap;
 use cursive::Cursive;
+use cursive::direction::Direction;
 use cursive::event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent};
-use cursive::view::{View, ViewWrapper};
-use cursive::views::{Dialog, DialogFocus, LinearLayout};
+use cursive::theme::{ColorStyle, Effect, Style};
+use cursive::traits::Boxable;
+use cursive::utils::markup::StyledString;
+use cursive::Printer;
+use cursive::vec::Vec2;
+use cursive::view::{AnyView, Selector, SizeConstraint, View, ViewWrapper};
+use cursive::views::{BoxView, Dialog, DialogFocus, DummyView, LinearLayout, SelectView,
+                     TextView};
 use serde_json::map::Map;
 use serde_json::value::Value;
 
-use fields::FormField;
+use fields::{FieldState, FieldStyles, FormField};
+use validators::{AsyncValidator, Context, Validator};
 
-type OnSubmit = Option<Rc<Fn(&mut Cursive, Value)>>;
+type OnSubmit = Option<Rc<Fn(&mut Cursive, Value) -> SubmitOutcome>>;
 type OnCancel = Option<Rc<Fn(&mut Cursive)>>;
+type OnButton = Rc<Fn(&mut Cursive, Value)>;
+type FormValidator = Rc<Fn(&Value) -> Result<(), FormError>>;
+/// Result of async validator: (validator's index, checked value, error).
+type AsyncResult = (usize, String, Option<String>);
+/// Indexes of children (in nested `LinearLayout`s) leading to field's widget.
+type Position = Vec<usize>;
 
-/// Aggregates `fields` and handles process of `submitting` (or `canceling`).
-pub struct FormView {
-    view: Dialog,
+/// Replaces `{label}` in `template` with value of `label` from `data`.
+fn fill_template(template: &str, data: &Value) -> String {
+    let mut text = template.to_owned();
+    if let Some(map) = data.as_object() {
+        for (label, value) in map {
+            let value = match *value {
+                Value::Null => "".to_string(),
+                Value::String(ref s) => s.clone(),
+                ref other => other.to_string(),
+            };
+            text = text.replace(&format!("{{{}}}", label), &value);
+        }
+    }
+    text
+}
 
-    fields: Vec<Box<FormField>>,
-    on_submit: OnSubmit,
-    on_cancel: OnCancel,
+/// Returns count of `layout`'s children.
+fn children_count(layout: &LinearLayout) -> usize {
+    let mut idx = 0;
+    while layout.get_child(idx).is_some() {
+        idx += 1;
+    }
+    idx
 }
-impl FormView {
-    /// Creates a new `FormView` with two buttons `submit` and `cancel`.
-    pub fn new() -> Self {
-        let layout = Dialog::new()
-            .content(LinearLayout::vertical())
-            .button("Cancel", |_| {})
-            .button("Submit (Ctrl+f)", |_| {});
-        FormView {
-            view: layout,
-            fields: Vec::new(),
-            on_submit: None,
-            on_cancel: None,
+
+// Dialog's content consists of layouts of: errors' summary, description, fields and form error.
+const SUMMARY_IDX: usize = 0;
+const DESCRIPTION_IDX: usize = 1;
+const FIELDS_IDX: usize = 2;
+const FORM_ERROR_IDX: usize = 3;
+const HELP_PANEL_IDX: usize = 4;
+
+fn get_content_child_mut(dialog: &mut Dialog, idx: usize) -> &mut LinearLayout {
+    dialog
+        .get_content_mut()
+        .as_any_mut()
+        .downcast_mut::<LinearLayout>()
+        .unwrap()
+        .get_child_mut(idx)
+        .unwrap()
+        .as_any_mut()
+        .downcast_mut::<LinearLayout>()
+        .unwrap()
+}
+
+fn get_fields_scroll(dialog: &Dialog) -> &ScrollLayout {
+    dialog
+        .get_content()
+        .as_any()
+        .downcast_ref::<LinearLayout>()
+        .unwrap()
+        .get_child(FIELDS_IDX)
+        .unwrap()
+        .as_any()
+        .downcast_ref::<ScrollLayout>()
+        .unwrap()
+}
+
+fn get_fields_layout(dialog: &Dialog) -> &LinearLayout {
+    &get_fields_scroll(dialog).layout
+}
+
+fn get_fields_layout_mut(dialog: &mut Dialog) -> &mut LinearLayout {
+    let scroll = dialog
+        .get_content_mut()
+        .as_any_mut()
+        .downcast_mut::<LinearLayout>()
+        .unwrap()
+        .get_child_mut(FIELDS_IDX)
+        .unwrap()
+        .as_any_mut()
+        .downcast_mut::<ScrollLayout>()
+        .unwrap();
+    &mut scroll.layout
+}
+
+/// Index of `Cancel` button in dialog.
+const CANCEL_BTN: usize = 0;
+/// Index of `Reset` button in dialog.
+const RESET_BTN: usize = 1;
+/// Index of `Submit` button in dialog.
+const SUBMIT_BTN: usize = 2;
+
+/// Refresh rate while async validators are running (their results are collected on refresh).
+const ASYNC_FPS: u32 = 10;
+
+/// Wraps field's widget making it focusable by field's label.
+///
+/// Hidden container takes no space and ignores events (see [FormField::is_visible]).
+///
+/// [FormField::is_visible]: ../fields/trait.FormField.html#method.is_visible
+struct FieldContainer {
+    view: BoxView<Box<AnyView>>,
+    label: String,
+    visible: bool,
+}
+
+impl ViewWrapper for FieldContainer {
+    wrap_impl!(self.view: BoxView<Box<AnyView>>);
+
+    fn wrap_draw(&self, printer: &Printer) {
+        if self.visible {
+            self.view.draw(printer);
         }
     }
 
-    /// Appends `field` to field list.
-    pub fn field<V: FormField + 'static>(mut self, field: V) -> Self {
-        let widget = field.build_widget();
-        self.view
-            .get_content_mut()
-            .as_any_mut()
-            .downcast_mut::<LinearLayout>()
-            .unwrap()
-            .add_child(widget);
-        self.fields.push(Box::new(field));
-        self
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        if self.visible {
+            self.view.required_size(req)
+        } else {
+            Vec2::zero()
+        }
     }
 
-    /// Sets the function to be called when submit is triggered.
-    pub fn set_on_submit<F>(&mut self, callback: F)
-    where
-        F: Fn(&mut Cursive, Value) + 'static,
-    {
-        self.on_submit = Some(Rc::new(callback));
+    fn wrap_layout(&mut self, size: Vec2) {
+        if self.visible {
+            self.view.layout(size);
+        }
     }
 
-    /// Sets the function to be called when submit is triggered.
-    ///
-    /// Chainable variant.
-    pub fn on_submit<F>(mut self, callback: F) -> Self
-    where
-        F: Fn(&mut Cursive, Value) + 'static,
-    {
-        self.set_on_submit(callback);
-        self
+    fn wrap_take_focus(&mut self, source: Direction) -> bool {
+        self.visible && self.view.take_focus(source)
     }
 
-    /// Sets the function to be called when cancel is triggered.
-    pub fn set_on_cancel<F>(&mut self, callback: F)
-    where
-        F: Fn(&mut Cursive) + 'static,
-    {
-        self.on_cancel = Some(Rc::new(callback));
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        if self.visible {
+            self.view.on_event(event)
+        } else {
+            EventResult::Ignored
+        }
     }
 
-    /// Sets the function to be called when cancel is triggered.
-    ///
-    /// Chainable variant.
-    pub fn on_cancel<F>(mut self, callback: F) -> Self
-    where
-        F: Fn(&mut Cursive) + 'static,
-    {
-        self.set_on_cancel(callback);
-        self
+    fn wrap_focus_view(&mut self, selector: &Selector) -> Result<(), ()> {
+        if !self.visible {
+            return Err(());
+        }
+        match selector {
+            &Selector::Id(id) if id == self.label => if self.view.take_focus(Direction::none()) {
+                Ok(())
+            } else {
+                Err(())
+            },
+            _ => self.view.focus_view(selector),
+        }
     }
+}
 
-    /// Translates form's fields to [clap::Arg]
-    ///
-    /// [clap::Arg]: ../../clap/struct.Arg.html
-    pub fn fields2clap_args(&self) -> Vec<clap::Arg> {
-        let mut args = Vec::with_capacity(self.fields.len());
-        for field in &self.fields {
-            let arg = field.clap_arg();
-            args.push(arg);
+/// Height offered to children of `ScrollLayout`, any of them fits (with room for views adding to
+/// it).
+const UNBOUNDED: usize = usize::MAX / 2;
+/// Columns taken by scrollbar (with a space before it).
+const SCROLLBAR_WIDTH: usize = 2;
+
+/// Vertical `layout` taller than available space, it shows children from `first` (scrolling by
+/// whole children) and keeps the focused one visible.
+struct ScrollLayout {
+    layout: LinearLayout,
+    first: usize,
+    // heights of children & size given by the last layout
+    heights: Vec<usize>,
+    size: Vec2,
+}
+
+impl ScrollLayout {
+    fn new(layout: LinearLayout) -> Self {
+        ScrollLayout {
+            layout,
+            first: 0,
+            heights: Vec::new(),
+            size: Vec2::zero(),
         }
-        return args;
     }
 
-    /// Translates [clap::ArgMatches] to [serde_json::Value] based on fields.
-    ///
-    /// [clap::ArgMatches]: ../../clap/struct.ArgMatches.html
-    /// [serde_json::Value]: ../../serde_json/enum.Value.html
-    pub fn clap_arg_matches2value(&self, arg_matches: &clap::ArgMatches) -> Value {
-        let mut form_data = Map::with_capacity(self.fields.len());
-        for field in self.fields.iter() {
-            let data = field.clap_args2str(&arg_matches);
-            match field.validate(data.as_ref()) {
-                Ok(v) => {
-                    form_data.insert(field.get_label().to_string(), v);
-                }
-                Err(e) => {
-                    let msg = format!("ERROR: {:?}", e);
-                    eprintln!("{}", msg);
-                }
-            }
+    fn content_height(&self) -> usize {
+        self.heights.iter().sum()
+    }
+
+    fn is_scrollable(&self) -> bool {
+        self.content_height() > self.size.y
+    }
+
+    fn can_scroll_down(&self) -> bool {
+        self.heights[self.first..].iter().sum::<usize>() > self.size.y
+    }
+
+    /// Changes `first` so the focused child is shown (as much of it as fits).
+    fn scroll_to_focus(&mut self) {
+        let focus = self.layout.get_focus_index();
+        if focus >= self.heights.len() {
+            return;
+        }
+        if focus < self.first {
+            self.first = focus;
+        }
+        let mut first = self.first;
+        while first < focus && self.heights[first..focus + 1].iter().sum::<usize>() > self.size.y {
+            first += 1;
         }
-        Value::Object(form_data)
+        self.first = first;
     }
 
-    fn validate(&self) -> Result<Value, HashMap<String, String>> {
-        let mut data = Map::with_capacity(self.fields.len());
-        let mut errors = HashMap::with_capacity(self.fields.len());
+    fn draw_scrollbar(&self, printer: &Printer) {
+        let (height, content_height) = (self.size.y, self.content_height());
+        if self.size.x == 0 {
+            return;
+        }
+        let x = self.size.x - 1;
+        printer.print_vline((x, 0), height, "|");
+        let thumb_height = ::std::cmp::max(1, height * height / content_height);
+        let start_line: usize = self.heights[..self.first].iter().sum();
+        let thumb_y = ((height - thumb_height) * start_line / (content_height - height))
+            .min(height - thumb_height);
+        let color = if printer.focused {
+            ColorStyle::highlight()
+        } else {
+            ColorStyle::highlight_inactive()
+        };
+        printer.with_color(color, |printer| {
+            printer.print_vline((x, thumb_y), thumb_height, " ");
+        });
+    }
+}
 
-        for (idx, field) in self.fields.iter().enumerate() {
-            let view = self.view
-                .get_content()
-                .as_any()
-                .downcast_ref::<LinearLayout>()
-                .unwrap()
-                .get_child(idx)
-                .unwrap();
-            let value = field.get_widget_manager().get_value(view);
-            let label = field.get_label();
-            match field.validate(value.as_ref()) {
-                Ok(v) => {
-                    data.insert(label.to_owned(), v);
-                }
-                Err(e) => {
-                    errors.insert(label.to_owned(), e.to_owned());
-                }
+impl ViewWrapper for ScrollLayout {
+    wrap_impl!(self.layout: LinearLayout);
+
+    fn wrap_draw(&self, printer: &Printer) {
+        let width = if self.is_scrollable() {
+            self.draw_scrollbar(printer);
+            self.size.x.saturating_sub(SCROLLBAR_WIDTH)
+        } else {
+            self.size.x
+        };
+        let focus = self.layout.get_focus_index();
+        let mut y = 0;
+        for idx in self.first..self.heights.len() {
+            if y >= self.size.y {
+                break;
             }
+            let height = self.heights[idx].min(self.size.y - y);
+            let child_printer =
+                printer.sub_printer((0, y), (width, height), printer.focused && idx == focus);
+            self.layout.get_child(idx).unwrap().draw(&child_printer);
+            y += self.heights[idx];
         }
+    }
 
-        if errors.is_empty() {
-            Ok(Value::Object(data))
-        } else {
-            Err(errors)
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        let mut size = self.layout.required_size(Vec2::new(req.x, UNBOUNDED));
+        if size.y > req.y {
+            let width = req.x.saturating_sub(SCROLLBAR_WIDTH);
+            size = self.layout.required_size(Vec2::new(width, UNBOUNDED));
+            size.x += SCROLLBAR_WIDTH;
         }
+        Vec2::new(size.x, size.y.min(req.y))
     }
 
-    fn event_submit(&mut self) -> EventResult {
-        match self.validate() {
-            Ok(data_map) => {
-                let opt_cb = self.on_submit
-                    .clone()
-                    .map(|cb| Callback::from_fn(move |c| cb(c, data_map.clone())));
-                EventResult::Consumed(opt_cb)
-            }
-            Err(errors) => {
-                // TODO: the event focus next required/invalid field?
-                for (idx, field) in self.fields.iter().enumerate() {
-                    let label = field.get_label();
-                    let e = errors.get(label).map(|x| x.as_ref()).unwrap_or("");
-                    // can't call method which returns suitable view because of ownership
-                    //  * such method would get &mut self
-                    //  * self.field gets &self
-                    //  so this clash of &mut and &, illegal
-                    //  possible solution is to use clone on WidgetManager (needs implementation)
-                    //  or
-                    //  form should only call field.validate and rest would be handled by field
-                    //  which should solve this issue?
-                    let mut view = self.view
-                        .get_content_mut()
-                        .as_any_mut()
-                        .downcast_mut::<LinearLayout>()
-                        .unwrap()
-                        .get_child_mut(idx)
-                        .unwrap();
-                    field.get_widget_manager().set_error(view, e);
-                }
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.size = size;
+        let mut width = size.x;
+        let mut content = self.layout.required_size(Vec2::new(width, UNBOUNDED));
+        if content.y > size.y {
+            width = size.x.saturating_sub(SCROLLBAR_WIDTH);
+            content = self.layout.required_size(Vec2::new(width, UNBOUNDED));
+        }
+        self.layout.layout(Vec2::new(width, content.y));
+        self.heights = (0..children_count(&self.layout))
+            .map(|idx| {
+                let child = self.layout.get_child_mut(idx).unwrap();
+                child.required_size(Vec2::new(width, UNBOUNDED)).y
+            })
+            .collect();
+        // don't leave empty space below the last child if previous ones fit there
+        self.first = self.first.min(self.heights.len());
+        while self.first > 0 && self.heights[self.first - 1..].iter().sum::<usize>() <= size.y {
+            self.first -= 1;
+        }
+        self.scroll_to_focus();
+    }
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let start_line: usize = self.heights[..self.first].iter().sum();
+        let result = match event {
+            // position is moved to hidden children's coordinates
+            Event::Mouse {
+                offset,
+                position,
+                event,
+            } => self.layout.on_event(Event::Mouse {
+                offset,
+                position: position + (0, start_line),
+                event,
+            }),
+            Event::Refresh => return self.layout.on_event(event),
+            _ => {
+                let result = self.layout.on_event(event);
+                self.scroll_to_focus();
+                return result;
+            }
+        };
+        match (result, event) {
+            (EventResult::Ignored, Event::Mouse { event: MouseEvent::WheelUp, .. })
+                if self.first > 0 =>
+            {
+                self.first -= 1;
+                EventResult::Consumed(None)
+            }
+            (EventResult::Ignored, Event::Mouse { event: MouseEvent::WheelDown, .. })
+                if self.can_scroll_down() =>
+            {
+                self.first += 1;
                 EventResult::Consumed(None)
             }
+            (result, _) => result,
         }
     }
 
-    fn event_cancel(&mut self) -> EventResult {
-        let cb = self.on_cancel
-            .clone()
-            .map(|cb| Callback::from_fn(move |c| cb(c)));
-        EventResult::Consumed(cb)
+    fn wrap_take_focus(&mut self, source: Direction) -> bool {
+        let taken = self.layout.take_focus(source);
+        self.scroll_to_focus();
+        taken
     }
 
-    /// Sets `title` of the form on the top of it
-    pub fn title(mut self, title: &str) -> Self {
-        self.view.set_title(title);
-        self
+    fn wrap_focus_view(&mut self, selector: &Selector) -> Result<(), ()> {
+        let result = self.layout.focus_view(selector);
+        self.scroll_to_focus();
+        result
     }
 }
 
-impl ViewWrapper for FormView {
-    wrap_impl!(self.view: Dialog);
+/// Fields of one tab, shown only when the tab is `active` (otherwise it behaves like hidden
+/// `FieldContainer`).
+struct TabPage {
+    layout: LinearLayout,
+    active: bool,
+}
+
+impl ViewWrapper for TabPage {
+    wrap_impl!(self.layout: LinearLayout);
+
+    fn wrap_draw(&self, printer: &Printer) {
+        if self.active {
+            self.layout.draw(printer);
+        }
+    }
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        if self.active {
+            self.layout.required_size(req)
+        } else {
+            Vec2::zero()
+        }
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        if self.active {
+            self.layout.layout(size);
+        }
+    }
+
+    fn wrap_take_focus(&mut self, source: Direction) -> bool {
+        self.active && self.layout.take_focus(source)
+    }
 
     fn wrap_on_event(&mut self, event: Event) -> EventResult {
-        match event {
-            Event::Mouse {
-                offset: _,
-                position: _,
-                event: MouseEvent::Press(btn),
-            } => {
-                if btn == MouseButton::Left {
-                    self.with_view_mut(|v| v.on_event(event))
-                        .unwrap_or(EventResult::Ignored);
-                    match self.view.focus() {
-                        DialogFocus::Button(0) => self.event_cancel(),
-                        DialogFocus::Button(1) => self.event_submit(),
-                        _ => EventResult::Ignored,
-                    }
-                } else {
-                    EventResult::Ignored
-                }
-            }
-            Event::Key(Key::Enter) => match self.view.focus() {
-                DialogFocus::Button(0) => self.event_cancel(),
-                DialogFocus::Button(1) => self.event_submit(),
-                _ => self.with_view_mut(|v| v.on_event(event))
-                    .unwrap_or(EventResult::Ignored),
-            },
-            // TODO: ctlr+enter binding?
-            Event::CtrlChar('f') => self.event_submit(),
-            _ => {
-                // default behaviour from ViewWrapper
-                self.with_view_mut(|v| v.on_event(event))
-                    .unwrap_or(EventResult::Ignored)
+        if self.active {
+            self.layout.on_event(event)
+        } else {
+            EventResult::Ignored
+        }
+    }
+
+    fn wrap_focus_view(&mut self, selector: &Selector) -> Result<(), ()> {
+        if self.active {
+            self.layout.focus_view(selector)
+        } else {
+            Err(())
+        }
+    }
+}
+
+/// Gets `view` as `LinearLayout` (for tab's page it's page's layout).
+fn as_layout(view: &AnyView) -> Option<&LinearLayout> {
+    let any = view.as_any();
+    any.downcast_ref::<LinearLayout>()
+        .or_else(|| any.downcast_ref::<TabPage>().map(|page| &page.layout))
+}
+
+/// Gets `view` as mutable `LinearLayout` (for tab's page it's page's layout).
+fn as_layout_mut(view: &mut AnyView) -> Option<&mut LinearLayout> {
+    let any = view.as_any_mut();
+    if any.is::<TabPage>() {
+        any.downcast_mut::<TabPage>().map(|page| &mut page.layout)
+    } else {
+        any.downcast_mut::<LinearLayout>()
+    }
+}
+
+/// Lists errors (with indexes of invalid fields) shown above fields.
+struct ErrorSummary {
+    view: SelectView<usize>,
+}
+
+impl ViewWrapper for ErrorSummary {
+    wrap_impl!(self.view: SelectView<usize>);
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        // long errors are clipped, instead of widening the form
+        self.view
+            .required_size(req)
+            .zip_map(req, |size, available| size.min(available))
+    }
+}
+
+/// Sets again contents of all `TextView`s in `layout`, so they are re-wrapped on next layout.
+///
+/// `TextView` keeps rows wrapped for narrower width if they fit the wider one (so after terminal is
+/// widened, text would stay wrapped), setting content drops its cache.
+fn refresh_texts(layout: &mut LinearLayout) {
+    let mut idx = 0;
+    while let Some(child) = layout.get_child_mut(idx) {
+        idx += 1;
+        if let Some(text) = child.as_any_mut().downcast_mut::<TextView>() {
+            let content = text.get_content().clone();
+            text.set_content(content);
+            continue;
+        }
+        if let Some(container) = child.as_any_mut().downcast_mut::<FieldContainer>() {
+            let widget = container.view.get_inner_mut();
+            if let Some(nested) = (**widget).as_any_mut().downcast_mut::<LinearLayout>() {
+                refresh_texts(nested);
             }
+            continue;
+        }
+        if let Some(scroll) = child.as_any_mut().downcast_mut::<ScrollLayout>() {
+            refresh_texts(&mut scroll.layout);
+            continue;
+        }
+        if let Some(nested) = as_layout_mut(child) {
+            refresh_texts(nested);
+        }
+    }
+}
+
+/// Finds container placed at `position` in `layout`.
+fn get_container<'a>(layout: &'a LinearLayout, position: &[usize]) -> &'a FieldContainer {
+    let child = layout.get_child(position[0]).unwrap();
+    if position.len() > 1 {
+        let nested = as_layout(child).unwrap();
+        get_container(nested, &position[1..])
+    } else {
+        child.as_any().downcast_ref::<FieldContainer>().unwrap()
+    }
+}
+
+/// Finds container placed at `position` in `layout`.
+fn get_container_mut<'a>(
+    layout: &'a mut LinearLayout,
+    position: &[usize],
+) -> &'a mut FieldContainer {
+    let child = layout.get_child_mut(position[0]).unwrap();
+    if position.len() > 1 {
+        let nested = as_layout_mut(child).unwrap();
+        get_container_mut(nested, &position[1..])
+    } else {
+        child.as_any_mut().downcast_mut::<FieldContainer>().unwrap()
+    }
+}
+
+/// Finds widget placed at `position` in `layout`.
+fn get_widget<'a>(layout: &'a LinearLayout, position: &[usize]) -> &'a AnyView {
+    get_container(layout, position).view.get_inner()
+}
+
+/// Finds widget placed at `position` in `layout`.
+fn get_widget_mut<'a>(layout: &'a mut LinearLayout, position: &[usize]) -> &'a mut AnyView {
+    get_container_mut(layout, position).view.get_inner_mut()
+}
+
+/// Wraps `field`'s widget limiting its width to the one set on `field`.
+fn build_sized_widget(field: &FormField, default_width: SizeConstraint) -> FieldContainer {
+    let width = field
+        .get_width()
+        .map(SizeConstraint::Fixed)
+        .unwrap_or(default_width);
+    let mut widget = field.build_widget();
+    if field.is_required() {
+        field.get_widget_manager().set_required(&mut widget);
+    }
+    FieldContainer {
+        // squishable, so narrowed terminal wraps widget instead of clipping it
+        view: BoxView::new(width, SizeConstraint::Free, widget).squishable(),
+        label: field.get_label().to_owned(),
+        visible: true,
+    }
+}
+
+/// Tells what should happen after `on_submit` callback is done.
+///
+/// `FormView` itself leaves the screen untouched, it's interpreted by the owner of the form (like
+/// [Fui::action_with_outcome]).
+///
+/// [Fui::action_with_outcome]: ../struct.Fui.html#method.action_with_outcome
+#[derive(Clone, Debug, PartialEq)]
+pub enum SubmitOutcome {
+    /// Form is done, program may go on (default).
+    Close,
+    /// Form stays shown with submitted values, so user can correct them and submit again.
+    KeepOpen,
+    /// Form is closed and user gets back to the actions' picker.
+    ReturnToMenu,
+    /// Form is closed and form of another action (its name preceded by its group, e.g.
+    /// `"remote add"`) is opened, filled with given data (see [FormView::set_data]).
+    ///
+    /// If there is no such action or data isn't an object, the error is shown and form stays
+    /// open.
+    ///
+    /// [FormView::set_data]: struct.FormView.html#method.set_data
+    GoTo(String, Value),
+}
+
+/// Keys (events) triggering form's actions.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::cursive::event::{Event, Key};
+/// # use fui::form::{FormView, KeyBindings};
+/// # fn main() {
+/// let mut keybindings = KeyBindings::default();
+/// keybindings.submit.push(Event::Ctrl(Key::Enter));
+/// keybindings.enter_submits = true;
+/// let form = FormView::new().keybindings(keybindings);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyBindings {
+    /// Submits the form (by default `Ctrl+f`).
+    pub submit: Vec<Event>,
+    /// Restores initial values (by default `Ctrl+r`).
+    pub reset: Vec<Event>,
+    /// Shows the next tab (by default `Ctrl+PageDown`).
+    pub next_tab: Vec<Event>,
+    /// Shows the previous tab (by default `Ctrl+PageUp`).
+    pub previous_tab: Vec<Event>,
+    /// Toggles panel with help of the focused field, outside of fields shows help with key
+    /// bindings (by default `F1`, `?` works too in fields which don't use it).
+    pub help: Vec<Event>,
+    /// Fills focused field with its older value from history (by default `Alt+Up`), see
+    /// [FormView::set_history].
+    ///
+    /// [FormView::set_history]: struct.FormView.html#method.set_history
+    pub previous_value: Vec<Event>,
+    /// Fills focused field with its newer value from history (by default `Alt+Down`).
+    pub next_value: Vec<Event>,
+    /// Saves draft of the form (by default `Ctrl+s`), see [FormView::set_on_save_draft].
+    ///
+    /// [FormView::set_on_save_draft]: struct.FormView.html#method.set_on_save_draft
+    pub save_draft: Vec<Event>,
+    /// Enter pressed in field which doesn't use it submits the form (by default `false`).
+    pub enter_submits: bool,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings {
+            submit: vec![Event::CtrlChar('f')],
+            reset: vec![Event::CtrlChar('r')],
+            next_tab: vec![Event::Ctrl(Key::PageDown)],
+            previous_tab: vec![Event::Ctrl(Key::PageUp)],
+            help: vec![Event::Key(Key::F1)],
+            previous_value: vec![Event::Alt(Key::Up)],
+            next_value: vec![Event::Alt(Key::Down)],
+            save_draft: vec![Event::CtrlChar('s')],
+            enter_submits: false,
+        }
+    }
+}
+
+/// Form's action bound to a key.
+enum BoundAction {
+    Submit,
+    Reset,
+    NextTab,
+    PreviousTab,
+    Help,
+    PreviousValue,
+    NextValue,
+    SaveDraft,
+}
+
+impl KeyBindings {
+    fn find(&self, event: &Event) -> Option<BoundAction> {
+        if self.submit.contains(event) {
+            Some(BoundAction::Submit)
+        } else if self.reset.contains(event) {
+            Some(BoundAction::Reset)
+        } else if self.next_tab.contains(event) {
+            Some(BoundAction::NextTab)
+        } else if self.previous_tab.contains(event) {
+            Some(BoundAction::PreviousTab)
+        } else if self.help.contains(event) {
+            Some(BoundAction::Help)
+        } else if self.previous_value.contains(event) {
+            Some(BoundAction::PreviousValue)
+        } else if self.next_value.contains(event) {
+            Some(BoundAction::NextValue)
+        } else if self.save_draft.contains(event) {
+            Some(BoundAction::SaveDraft)
+        } else {
+            None
         }
     }
 }
+
+/// Describes `events` for user (e.g. `Ctrl+f / F1`).
+fn describe_events(events: &[Event]) -> String {
+    events
+        .iter()
+        .map(|event| match *event {
+            Event::Char(c) => c.to_string(),
+            Event::CtrlChar(c) => format!("Ctrl+{}", c),
+            Event::AltChar(c) => format!("Alt+{}", c),
+            Event::Key(key) => format!("{:?}", key),
+            Event::Ctrl(key) => format!("Ctrl+{:?}", key),
+            Event::Alt(key) => format!("Alt+{:?}", key),
+            Event::Shift(key) => format!("Shift+{:?}", key),
+            ref other => format!("{:?}", other),
+        })
+        .collect::<Vec<String>>()
+        .join(" / ")
+}
+
+/// Built-in texts of forms, used to localize them.
+///
+/// Messages of validators are translated with [message].
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::form::{FormView, Strings};
+/// # fn main() {
+/// let strings = Strings {
+///     cancel: "Abbrechen".to_string(),
+///     submit: "Absenden".to_string(),
+///     ..Strings::default()
+/// };
+/// let strings = strings
+///     .message("Field is required", "Feld ist erforderlich")
+///     .message("Value must be at least {}", "Wert muss mindestens {} sein");
+/// assert_eq!(strings.translate("Value must be at least 3"), "Wert muss mindestens 3 sein");
+/// let form = FormView::new().strings(strings);
+/// # }
+/// ```
+///
+/// [message]: #method.message
+#[derive(Clone, Debug, PartialEq)]
+pub struct Strings {
+    /// Label of `Cancel` button.
+    pub cancel: String,
+    /// Label of `Reset` button (followed by its key).
+    pub reset: String,
+    /// Label of `Submit` button (followed by its key).
+    pub submit: String,
+    /// Button going back from confirmation.
+    pub back: String,
+    /// Button accepting confirmation.
+    pub yes: String,
+    /// Button rejecting confirmation.
+    pub no: String,
+    /// Title of confirmation.
+    pub confirm: String,
+    /// Title of warnings shown before submitting.
+    pub warnings: String,
+    /// Question asked after warnings.
+    pub submit_anyway: String,
+    /// Header of errors' summary.
+    pub errors: String,
+    /// Shown on fields while async validators are running.
+    pub checking: String,
+    /// Title of help with key bindings.
+    pub shortcuts: String,
+    /// Prefix of help panel's header.
+    pub help: String,
+    /// Shown in help panel of field without help.
+    pub no_help: String,
+    /// Help of actions' picker field.
+    pub pick_action: String,
+    /// Prefix of actions' picker entry repeating the last action.
+    pub repeat_last: String,
+    /// Shown when draft of the form is saved.
+    pub draft_saved: String,
+    /// Asks if saved draft of the form should be restored.
+    pub restore_draft: String,
+    /// Title of error shown when submitted data doesn't fit typed action.
+    pub invalid_data: String,
+    /// Title of error returned by action's handler.
+    pub error: String,
+    /// Button closing handler's error, submitted values stay in the form.
+    pub back_to_form: String,
+    /// Shown while action's handler is running in background.
+    pub working: String,
+    /// Title of output returned by action's handler.
+    pub output: String,
+    /// Button going from handler's output back to actions' picker.
+    pub run_another: String,
+    /// Button closing handler's output.
+    pub quit: String,
+    /// Help with key bindings: submitting form.
+    pub key_submit: String,
+    /// Help with key bindings: resetting form.
+    pub key_reset: String,
+    /// Help with key bindings: moving between fields.
+    pub key_next_field: String,
+    /// Help with key bindings: pressing focused button.
+    pub key_press_button: String,
+    /// Help with key bindings: showing the help.
+    pub key_help: String,
+    /// Help with key bindings: submitting form from a field (see `KeyBindings::enter_submits`).
+    pub key_enter_submits: String,
+    /// Help with key bindings: going to the next tab.
+    pub key_next_tab: String,
+    /// Help with key bindings: going to the previous tab.
+    pub key_previous_tab: String,
+    /// Help with key bindings: saving draft of the form.
+    pub key_save_draft: String,
+    /// Help with key bindings: filling field with older value from its history.
+    pub key_previous_value: String,
+    /// Help with key bindings: filling field with newer value from its history.
+    pub key_next_value: String,
+    /// Translations of messages: (original, translated), see [message].
+    ///
+    /// [message]: #method.message
+    pub messages: Vec<(String, String)>,
+}
+
+impl Default for Strings {
+    fn default() -> Self {
+        Strings {
+            cancel: "Cancel".to_string(),
+            reset: "Reset".to_string(),
+            submit: "Submit".to_string(),
+            back: "Back".to_string(),
+            yes: "Yes".to_string(),
+            no: "No".to_string(),
+            confirm: "Confirm".to_string(),
+            warnings: "Warnings".to_string(),
+            submit_anyway: "Submit anyway?".to_string(),
+            errors: "Errors (Enter jumps to field):".to_string(),
+            checking: "Checking...".to_string(),
+            shortcuts: "Keyboard shortcuts".to_string(),
+            help: "Help".to_string(),
+            no_help: "No help for this field.".to_string(),
+            pick_action: "Pick action".to_string(),
+            repeat_last: "Repeat last".to_string(),
+            draft_saved: "Draft saved".to_string(),
+            restore_draft: "Restore saved draft?".to_string(),
+            invalid_data: "Invalid data".to_string(),
+            error: "Error".to_string(),
+            back_to_form: "Back to form".to_string(),
+            working: "Working...".to_string(),
+            output: "Output".to_string(),
+            run_another: "Run another action".to_string(),
+            quit: "Quit".to_string(),
+            key_submit: "submit form".to_string(),
+            key_reset: "reset form".to_string(),
+            key_next_field: "next / previous field".to_string(),
+            key_press_button: "press focused button".to_string(),
+            key_help: "show this help (field's help in field)".to_string(),
+            key_enter_submits: "submit form (in field)".to_string(),
+            key_next_tab: "next tab".to_string(),
+            key_previous_tab: "previous tab".to_string(),
+            key_save_draft: "save draft".to_string(),
+            key_previous_value: "older value of field".to_string(),
+            key_next_value: "newer value of field".to_string(),
+            messages: Vec::new(),
+        }
+    }
+}
+
+impl Strings {
+    /// Adds translation of (validator's) message `original`.
+    ///
+    /// Each `{}` in `original` matches any text, which is put in place of the next `{}` in
+    /// `translated`.
+    pub fn message<IS: Into<String>>(mut self, original: IS, translated: IS) -> Self {
+        self.messages.push((original.into(), translated.into()));
+        self
+    }
+
+    /// Translates `message`, message without translation is returned as it is.
+    pub fn translate(&self, message: &str) -> String {
+        for (original, translated) in self.messages.iter() {
+            if let Some(values) = match_template(original, message) {
+                let mut parts = translated.split("{}");
+                let mut text = parts.next().unwrap_or("").to_string();
+                for (idx, part) in parts.enumerate() {
+                    text.push_str(values.get(idx).map(|v| v.as_str()).unwrap_or(""));
+                    text.push_str(part);
+                }
+                return text;
+            }
+        }
+        message.to_string()
+    }
+}
+
+/// Matches `text` against `template`, returning texts matched by its `{}`.
+fn match_template(template: &str, text: &str) -> Option<Vec<String>> {
+    let parts = template.split("{}").collect::<Vec<&str>>();
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if parts.len() == 1 {
+        return if template == text { Some(Vec::new()) } else { None };
+    }
+    if !text.starts_with(first) || text.len() < first.len() + last.len() {
+        return None;
+    }
+    let mut rest = &text[first.len()..];
+    let mut values = Vec::new();
+    for part in parts[1..parts.len() - 1].iter() {
+        let found = rest.find(part)?;
+        values.push(rest[..found].to_string());
+        rest = &rest[found + part.len()..];
+    }
+    if !rest.ends_with(last) {
+        return None;
+    }
+    values.push(rest[..rest.len() - last.len()].to_string());
+    Some(values)
+}
+
+/// Joins `name` of button with the first of its `events` (e.g. `Submit (Ctrl+f)`).
+fn label_with_key(name: &str, events: &[Event]) -> String {
+    match events.first() {
+        Some(event) => format!("{} ({})", name, describe_events(::std::slice::from_ref(event))),
+        None => name.to_string(),
+    }
+}
+
+/// Error found by validator of the whole form (see [FormView::form_validator]).
+///
+/// [FormView::form_validator]: struct.FormView.html#method.form_validator
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormError {
+    /// Error shown on field with given label.
+    Field(String, String),
+    /// Error shown below all fields.
+    Global(String),
+}
+
+/// Label under which errors of the whole form ([FormError::Global]) are reported.
+///
+/// [FormError::Global]: enum.FormError.html#variant.Global
+pub const FORM_ERROR_LABEL: &str = "";
+
+/// Aggregates `fields` and handles process of `submitting` (or `canceling`).
+pub struct FormView {
+    view: Dialog,
+
+    fields: Vec<Box<FormField>>,
+    positions: Vec<Position>,
+    // name of section for each field
+    sections: Vec<Option<String>>,
+    nest_sections: bool,
+    columns: usize,
+    // last row of columns & count of fields already placed in it
+    open_row: Option<(usize, usize)>,
+    // index of tabs' bar in fields' layout, tabs' names & indexes of their pages
+    tab_bar: Option<usize>,
+    tabs: Vec<(String, usize)>,
+    active_tab: usize,
+    form_validators: Vec<FormValidator>,
+    async_validators: Vec<(String, Arc<AsyncValidator>)>,
+    warnings: Vec<(String, Rc<Validator>)>,
+    // last result of each async validator: (checked value, error)
+    async_results: Vec<Option<(String, Option<String>)>>,
+    // receiver of running async validators & their count
+    async_pending: Option<(Receiver<AsyncResult>, usize)>,
+    state_colors: bool,
+    field_styles: FieldStyles,
+    // last state of each field & field styled as focused
+    states: Vec<FieldState>,
+    styled_focus: Option<usize>,
+    on_submit: OnSubmit,
+    on_cancel: OnCancel,
+    on_save_draft: Option<OnButton>,
+    // custom buttons: index in dialog & callback
+    buttons: Vec<(usize, OnButton)>,
+    // custom button waiting for async validators, `None` means `Submit`
+    pending_button: Option<usize>,
+    confirm: Option<String>,
+    keybindings: KeyBindings,
+    strings: Strings,
+    help_panel: bool,
+    // previous values of fields by labels (the latest first) & shown one: (field, value) indexes
+    history: HashMap<String, Vec<Value>>,
+    history_pos: Option<(usize, usize)>,
+    // values replacing initial values of fields (e.g. from config file)
+    defaults: Map<String, Value>,
+}
+impl Default for FormView {
+    fn default() -> Self {
+        FormView::new()
+    }
+}
+
+impl FormView {
+    /// Creates a new `FormView` with two buttons `submit` and `cancel`.
+    pub fn new() -> Self {
+        let summary = LinearLayout::vertical()
+            .child(TextView::new(""))
+            .child(ErrorSummary {
+                view: SelectView::new(),
+            })
+            .child(TextView::new(""));
+        let content = LinearLayout::vertical()
+            .child(summary)
+            .child(LinearLayout::vertical())
+            .child(ScrollLayout::new(LinearLayout::vertical()))
+            .child(LinearLayout::vertical().child(TextView::new("")))
+            .child(LinearLayout::vertical().child(TextView::new("")));
+        let layout = Dialog::new()
+            .content(content)
+            .button("Cancel", |_| {})
+            .button("Reset (Ctrl+r)", |_| {})
+            .button("Submit (Ctrl+f)", |_| {});
+        FormView {
+            view: layout,
+            fields: Vec::new(),
+            positions: Vec::new(),
+            sections: Vec::new(),
+            nest_sections: false,
+            columns: 1,
+            open_row: None,
+            tab_bar: None,
+            tabs: Vec::new(),
+            active_tab: 0,
+            form_validators: Vec::new(),
+            async_validators: Vec::new(),
+            warnings: Vec::new(),
+            async_results: Vec::new(),
+            async_pending: None,
+            state_colors: true,
+            field_styles: FieldStyles::default(),
+            states: Vec::new(),
+            styled_focus: None,
+            on_submit: None,
+            on_cancel: None,
+            on_save_draft: None,
+            buttons: Vec::new(),
+            pending_button: None,
+            confirm: None,
+            keybindings: KeyBindings::default(),
+            strings: Strings::default(),
+            help_panel: false,
+            history: HashMap::new(),
+            history_pos: None,
+            defaults: Map::new(),
+        }
+    }
+
+    /// Appends `field` to field list.
+    pub fn field<V: FormField + 'static>(mut self, field: V) -> Self {
+        self.add_field(field);
+        self
+    }
+
+    /// Appends `field` to field list.
+    ///
+    /// Non-chainable variant.
+    pub fn add_field<V: FormField + 'static>(&mut self, field: V) {
+        self.add_boxed_field(Box::new(field));
+    }
+
+    /// Appends boxed `field` (e.g. built at runtime, see [loader]) to field list.
+    ///
+    /// [loader]: ../loader/index.html
+    pub fn add_boxed_field(&mut self, field: Box<FormField>) {
+        let position = if self.columns > 1 {
+            self.add_to_columns(&*field)
+        } else {
+            let widget = build_sized_widget(&*field, SizeConstraint::Free);
+            let layout = get_fields_layout_mut(&mut self.view);
+            let idx = children_count(layout);
+            layout.add_child(widget);
+            vec![idx]
+        };
+        self.fields.push(field);
+        self.positions.push(position);
+        self.states.push(FieldState::Untouched);
+        self.sections.push(None);
+        self.refresh_visibility();
+    }
+
+    /// Places `field`'s widget in the first free cell of the last row of columns (a new row is
+    /// started when there is no such cell), returning its position.
+    fn add_to_columns(&mut self, field: &FormField) -> Position {
+        let columns = self.columns;
+        let layout = get_fields_layout_mut(&mut self.view);
+        let count = children_count(layout);
+        let (row_idx, cell) = match self.open_row {
+            // row is open only until anything else is appended
+            Some((row_idx, filled)) if row_idx + 1 == count && filled < columns => {
+                (row_idx, filled)
+            }
+            _ => {
+                let mut row = LinearLayout::horizontal();
+                for idx in 0..columns {
+                    if idx > 0 {
+                        row.add_child(DummyView.fixed_width(1));
+                    }
+                    // empty cell keeps widths of columns the same in incomplete row
+                    row.add_child(FieldContainer {
+                        view: BoxView::new(
+                            SizeConstraint::Full,
+                            SizeConstraint::Free,
+                            Box::new(DummyView) as Box<AnyView>,
+                        ),
+                        label: "".to_owned(),
+                        visible: true,
+                    });
+                }
+                layout.add_child(row);
+                (count, 0)
+            }
+        };
+        let position = vec![row_idx, cell * 2];
+        *get_container_mut(layout, &position) = build_sized_widget(field, SizeConstraint::Full);
+        self.open_row = Some((row_idx, cell + 1));
+        position
+    }
+
+    /// Places fields appended later with [field] in `count` columns (side by side, filling rows
+    /// from left to right).
+    ///
+    /// Examples
+    ///
+    /// ```
+    /// use fui::fields::{Number, Text};
+    /// use fui::form::FormView;
+    ///
+    /// let form = FormView::new()
+    ///     .columns(2)
+    ///     .field(Text::new("host"))
+    ///     .field(Number::integer("port"))
+    ///     .field(Text::new("user"));
+    /// ```
+    ///
+    /// [field]: #method.field
+    pub fn columns(mut self, count: usize) -> Self {
+        self.columns = count;
+        self.open_row = None;
+        self
+    }
+
+    /// Appends `fields` placed side by side in a single row.
+    ///
+    /// Fields without width share row's width equally.
+    ///
+    /// Examples
+    ///
+    /// ```
+    /// use fui::fields::Text;
+    /// use fui::form::FormView;
+    ///
+    /// let form = FormView::new()
+    ///     .row(vec![
+    ///         Box::new(Text::new("first-name")),
+    ///         Box::new(Text::new("last-name").width(20)),
+    ///     ])
+    ///     .field(Text::new("address"));
+    /// ```
+    pub fn row(mut self, fields: Vec<Box<FormField>>) -> Self {
+        let row_idx = children_count(get_fields_layout(&self.view));
+        let mut row = LinearLayout::horizontal();
+        for (idx, field) in fields.into_iter().enumerate() {
+            if idx > 0 {
+                row.add_child(DummyView.fixed_width(1));
+            }
+            row.add_child(build_sized_widget(&*field, SizeConstraint::Full));
+            self.fields.push(field);
+            self.positions.push(vec![row_idx, idx * 2]);
+            self.states.push(FieldState::Untouched);
+            self.sections.push(None);
+        }
+        get_fields_layout_mut(&mut self.view).add_child(row);
+        self.refresh_visibility();
+        self
+    }
+
+    /// Appends `fields` grouped under header `name`.
+    ///
+    /// By default section doesn't change form's data, see [nest_sections].
+    ///
+    /// Examples
+    ///
+    /// ```
+    /// use fui::fields::{Number, Text};
+    /// use fui::form::FormView;
+    ///
+    /// let form = FormView::new()
+    ///     .section("connection", vec![
+    ///         Box::new(Text::new("host")),
+    ///         Box::new(Number::integer("port")),
+    ///     ])
+    ///     .field(Text::new("query"));
+    /// ```
+    ///
+    /// [nest_sections]: #method.nest_sections
+    pub fn section(mut self, name: &str, fields: Vec<Box<FormField>>) -> Self {
+        let section_idx = children_count(get_fields_layout(&self.view));
+        let mut section = LinearLayout::vertical();
+        if section_idx > 0 {
+            section.add_child(DummyView);
+        }
+        let style = Style::from(Effect::Bold).combine(Effect::Underline);
+        section.add_child(TextView::new(StyledString::styled(name, style)));
+        for field in fields.into_iter() {
+            let idx = children_count(&section);
+            section.add_child(build_sized_widget(&*field, SizeConstraint::Free));
+            self.fields.push(field);
+            self.positions.push(vec![section_idx, idx]);
+            self.states.push(FieldState::Untouched);
+            self.sections.push(Some(name.to_owned()));
+        }
+        get_fields_layout_mut(&mut self.view).add_child(section);
+        self.refresh_visibility();
+        self
+    }
+
+    /// Appends `fields` shown on tab `name`, user switches tabs with `Ctrl+PgUp` / `Ctrl+PgDn`.
+    ///
+    /// Fields of all tabs are validated on submit and tab with the first invalid field gets
+    /// shown. Tabs don't change form's data.
+    ///
+    /// Examples
+    ///
+    /// ```
+    /// use fui::fields::{Checkbox, Text};
+    /// use fui::form::FormView;
+    ///
+    /// let form = FormView::new()
+    ///     .tab("Basic", vec![Box::new(Text::new("archive"))])
+    ///     .tab("Advanced", vec![Box::new(Checkbox::new("verbose"))]);
+    /// ```
+    pub fn tab(mut self, name: &str, fields: Vec<Box<FormField>>) -> Self {
+        if self.tab_bar.is_none() {
+            let layout = get_fields_layout_mut(&mut self.view);
+            self.tab_bar = Some(children_count(layout));
+            layout.add_child(TextView::new(""));
+        }
+        let page_idx = children_count(get_fields_layout(&self.view));
+        let mut page = LinearLayout::vertical().child(DummyView);
+        for field in fields.into_iter() {
+            let idx = children_count(&page);
+            page.add_child(build_sized_widget(&*field, SizeConstraint::Free));
+            self.fields.push(field);
+            self.positions.push(vec![page_idx, idx]);
+            self.states.push(FieldState::Untouched);
+            self.sections.push(None);
+        }
+        get_fields_layout_mut(&mut self.view).add_child(TabPage {
+            layout: page,
+            active: self.tabs.is_empty(),
+        });
+        self.tabs.push((name.to_owned(), page_idx));
+        self.refresh_tab_bar();
+        self.refresh_visibility();
+        self
+    }
+
+    /// Shows names of tabs, the active one highlighted.
+    fn refresh_tab_bar(&mut self) {
+        let bar_idx = match self.tab_bar {
+            Some(idx) => idx,
+            None => return,
+        };
+        let mut text = StyledString::new();
+        for (idx, (name, _)) in self.tabs.iter().enumerate() {
+            if idx > 0 {
+                text.append_plain(" ");
+            }
+            let name = format!(" {} ", name);
+            if idx == self.active_tab {
+                text.append_styled(name, Effect::Reverse);
+            } else {
+                text.append_plain(name);
+            }
+        }
+        let layout = get_fields_layout_mut(&mut self.view);
+        if let Some(bar) = layout
+            .get_child_mut(bar_idx)
+            .and_then(|c| c.as_any_mut().downcast_mut::<TextView>())
+        {
+            bar.set_content(text);
+        }
+    }
+
+    /// Shows tab with index `tab` hiding the others.
+    fn select_tab(&mut self, tab: usize) {
+        self.active_tab = tab;
+        for (idx, &(_, page_idx)) in self.tabs.iter().enumerate() {
+            let layout = get_fields_layout_mut(&mut self.view);
+            if let Some(page) = layout
+                .get_child_mut(page_idx)
+                .and_then(|c| c.as_any_mut().downcast_mut::<TabPage>())
+            {
+                page.active = idx == tab;
+            }
+        }
+        self.refresh_tab_bar();
+    }
+
+    /// Switches to the next (or previous if `forward` is `false`) tab focusing its first field.
+    fn event_switch_tab(&mut self, forward: bool) -> EventResult {
+        if self.tabs.is_empty() {
+            return EventResult::Ignored;
+        }
+        let count = self.tabs.len();
+        let tab = if forward {
+            (self.active_tab + 1) % count
+        } else {
+            (self.active_tab + count - 1) % count
+        };
+        self.select_tab(tab);
+        let page_idx = self.tabs[tab].1;
+        let context = self.get_values();
+        let first = (0..self.fields.len()).find(|&idx| {
+            self.positions[idx][0] == page_idx && self.fields[idx].is_visible(&context)
+        });
+        if let Some(idx) = first {
+            self.focus_field(idx);
+        }
+        EventResult::Consumed(None)
+    }
+
+    /// Puts data of fields from [section] in objects named by sections (e.g. `{"connection":
+    /// {"host": .., "port": ..}}`).
+    ///
+    /// [section]: #method.section
+    pub fn nest_sections(mut self) -> Self {
+        self.nest_sections = true;
+        self
+    }
+
+    /// Appends `validator` of the whole form's data, it's run when all fields are valid.
+    ///
+    /// Examples
+    ///
+    /// ```
+    /// use fui::fields::Text;
+    /// use fui::form::{FormError, FormView};
+    ///
+    /// let form = FormView::new()
+    ///     .field(Text::new("src-dir"))
+    ///     .field(Text::new("dst-dir"))
+    ///     .form_validator(|data| {
+    ///         if data["src-dir"] == data["dst-dir"] {
+    ///             let msg = "Value must differ from src-dir".to_string();
+    ///             Err(FormError::Field("dst-dir".to_string(), msg))
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     });
+    /// ```
+    pub fn form_validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&Value) -> Result<(), FormError> + 'static,
+    {
+        self.form_validators.push(Rc::new(validator));
+        self
+    }
+
+    /// Appends `validator` of field labeled `label` which is run in background thread on submit.
+    ///
+    /// It's meant for slow checks (e.g. resolving host), while it runs the field shows pending
+    /// indicator and the form stays responsive. It's run only when all fields are valid and its
+    /// result is reused until field's value changes.
+    ///
+    /// Examples
+    ///
+    /// ```
+    /// use std::net::ToSocketAddrs;
+    /// use fui::fields::Text;
+    /// use fui::form::FormView;
+    ///
+    /// let form = FormView::new()
+    ///     .field(Text::new("host"))
+    ///     .async_validator("host", |host: &str| match (host, 80).to_socket_addrs() {
+    ///         Ok(_) => None,
+    ///         Err(_) => Some("Host can't be resolved".to_string()),
+    ///     });
+    /// ```
+    pub fn async_validator<V>(mut self, label: &str, validator: V) -> Self
+    where
+        V: AsyncValidator + 'static,
+    {
+        self.async_validators.push((label.to_owned(), Arc::new(validator)));
+        self.async_results.push(None);
+        self
+    }
+
+    /// Appends `validator` of field labeled `label` whose errors are only warnings.
+    ///
+    /// Warnings are shown on fields but don't block submitting, user is asked to confirm it
+    /// instead.
+    ///
+    /// Examples
+    ///
+    /// ```
+    /// use fui::fields::Text;
+    /// use fui::form::FormView;
+    /// use fui::validators::{PathFree, Validator};
+    ///
+    /// let form = FormView::new()
+    ///     .field(Text::new("output"))
+    ///     .warning("output", PathFree.message("File exists, it will be overwritten"));
+    /// ```
+    pub fn warning<V: Validator + 'static>(mut self, label: &str, validator: V) -> Self {
+        self.warnings.push((label.to_owned(), Rc::new(validator)));
+        self
+    }
+
+    /// Collects warnings of visible fields (field's index, warning).
+    fn collect_warnings(&self) -> Vec<(usize, String)> {
+        let context = self.get_values();
+        let mut warnings: Vec<(usize, String)> = Vec::new();
+        for (label, validator) in &self.warnings {
+            let idx = match self.find_visible_field(label, &context) {
+                Some(idx) => idx,
+                None => continue,
+            };
+            if warnings.iter().any(|&(i, _)| i == idx) {
+                continue;
+            }
+            if let Some(w) = validator.validate_with(&context[label], &context) {
+                warnings.push((idx, w));
+            }
+        }
+        warnings
+    }
+
+    /// Gets index of visible field labeled `label`.
+    fn find_visible_field(&self, label: &str, context: &Context) -> Option<usize> {
+        self.fields
+            .iter()
+            .position(|f| f.get_label() == label)
+            .filter(|&idx| self.fields[idx].is_visible(context))
+    }
+
+    /// Runs async validators in background threads.
+    ///
+    /// Returns errors by labels if all results are known (for current values), otherwise `None`
+    /// and validators are running.
+    fn run_async_validators(&mut self) -> Option<HashMap<String, String>> {
+        let context = self.get_values();
+        let mut errors = HashMap::new();
+        let mut pending = Vec::new();
+        let (sender, receiver) = mpsc::channel();
+        for (idx, (label, validator)) in self.async_validators.iter().enumerate() {
+            if self.find_visible_field(label, &context).is_none() {
+                continue;
+            }
+            let value = context[label].clone();
+            if let Some((ref checked, ref error)) = self.async_results[idx] {
+                if *checked == value {
+                    if let Some(ref e) = *error {
+                        errors.entry(label.to_owned()).or_insert(e.to_owned());
+                    }
+                    continue;
+                }
+            }
+            pending.push(label.to_owned());
+            let validator = validator.clone();
+            let sender = sender.clone();
+            thread::spawn(move || {
+                let error = validator.validate(&value);
+                sender.send((idx, value, error)).ok();
+            });
+        }
+        if pending.is_empty() {
+            return Some(errors);
+        }
+        self.async_pending = Some((receiver, pending.len()));
+        for label in pending {
+            let idx = self.fields.iter().position(|f| f.get_label() == label).unwrap();
+            let checking = self.strings.checking.clone();
+            self.show_field_error(idx, &checking, FieldState::Untouched);
+        }
+        None
+    }
+
+    /// Collects results of running async validators, submits form when all are done.
+    fn event_refresh(&mut self) -> EventResult {
+        let mut results = Vec::new();
+        let done = match self.async_pending {
+            Some((ref receiver, ref mut count)) => {
+                while let Ok(result) = receiver.try_recv() {
+                    results.push(result);
+                    *count -= 1;
+                }
+                *count == 0
+            }
+            None => {
+                return self.with_view_mut(|v| v.on_event(Event::Refresh))
+                    .unwrap_or(EventResult::Ignored)
+            }
+        };
+        for (idx, value, error) in results {
+            let label = self.async_validators[idx].0.clone();
+            let field_idx = self.fields.iter().position(|f| f.get_label() == label).unwrap();
+            match error {
+                Some(ref e) => self.show_field_error(field_idx, e, FieldState::Invalid),
+                None => self.show_field_error(field_idx, "", FieldState::Valid),
+            }
+            self.async_results[idx] = Some((value, error));
+        }
+        if !done {
+            return EventResult::Consumed(None);
+        }
+        self.async_pending = None;
+        let button = self.pending_button.take();
+        let submitted = match self.event_validated(button) {
+            EventResult::Consumed(cb) => cb,
+            EventResult::Ignored => None,
+        };
+        let cb = Callback::from_fn(move |c| {
+            c.set_fps(0);
+            if let Some(ref cb) = submitted {
+                (**cb)(c);
+            }
+        });
+        EventResult::Consumed(Some(cb))
+    }
+
+    /// Runs validators of the whole form's `data` collecting errors by labels.
+    fn run_form_validators(&self, data: &Value) -> HashMap<String, String> {
+        let mut errors = HashMap::new();
+        for validator in &self.form_validators {
+            let (label, msg) = match validator(data) {
+                Ok(()) => continue,
+                Err(FormError::Field(label, msg)) => (label, msg),
+                Err(FormError::Global(msg)) => (FORM_ERROR_LABEL.to_owned(), msg),
+            };
+            errors.entry(label).or_insert(msg);
+        }
+        errors
+    }
+
+    /// Adds `value` of field with index `idx` to `data` (nested in section if needed).
+    fn add_data(&self, data: &mut Map<String, Value>, idx: usize, value: Value) {
+        let contributed = self.fields[idx].contribute(value);
+        let section = match self.sections[idx] {
+            Some(ref section) if self.nest_sections => section,
+            _ => {
+                data.extend(contributed);
+                return;
+            }
+        };
+        let entry = data.entry(section.to_owned())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(ref mut nested) = *entry {
+            nested.extend(contributed);
+        }
+    }
+
+    /// Sets the function to be called when submit is triggered.
+    ///
+    /// Outcome of such callback is always [SubmitOutcome::Close].
+    ///
+    /// [SubmitOutcome::Close]: enum.SubmitOutcome.html#variant.Close
+    pub fn set_on_submit<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, Value) + 'static,
+    {
+        self.set_on_submit_outcome(move |c, data| {
+            callback(c, data);
+            SubmitOutcome::Close
+        });
+    }
+
+    /// Sets the function to be called when submit is triggered, which decides what happens next.
+    pub fn set_on_submit_outcome<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, Value) -> SubmitOutcome + 'static,
+    {
+        self.on_submit = Some(Rc::new(callback));
+    }
+
+    /// Sets the function to be called when submit is triggered, which decides what happens next.
+    ///
+    /// Chainable variant.
+    pub fn on_submit_outcome<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, Value) -> SubmitOutcome + 'static,
+    {
+        self.set_on_submit_outcome(callback);
+        self
+    }
+
+    /// Sets the function to be called when submit is triggered.
+    ///
+    /// Chainable variant.
+    pub fn on_submit<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, Value) + 'static,
+    {
+        self.set_on_submit(callback);
+        self
+    }
+
+    /// Sets the function to be called when cancel is triggered.
+    pub fn set_on_cancel<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive) + 'static,
+    {
+        self.on_cancel = Some(Rc::new(callback));
+    }
+
+    /// Sets the function to be called when cancel is triggered.
+    ///
+    /// Chainable variant.
+    pub fn on_cancel<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive) + 'static,
+    {
+        self.set_on_cancel(callback);
+        self
+    }
+
+    /// Sets the function to be called with [draft] of the form when [KeyBindings::save_draft] is
+    /// pressed (e.g. saving it to a file, so it can be restored later with [set_draft]).
+    ///
+    /// [draft]: #method.draft
+    /// [set_draft]: #method.set_draft
+    /// [KeyBindings::save_draft]: struct.KeyBindings.html#structfield.save_draft
+    pub fn set_on_save_draft<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, Value) + 'static,
+    {
+        self.on_save_draft = Some(Rc::new(callback));
+    }
+
+    /// Sets the function to be called with draft of the form, see [set_on_save_draft].
+    ///
+    /// Chainable variant.
+    ///
+    /// [set_on_save_draft]: #method.set_on_save_draft
+    pub fn on_save_draft<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, Value) + 'static,
+    {
+        self.set_on_save_draft(callback);
+        self
+    }
+
+    /// Gets draft of the form: current (not validated) values of its fields by labels, secret
+    /// fields (see [FormField::is_secret]) are left out.
+    ///
+    /// [FormField::is_secret]: ../fields/trait.FormField.html#method.is_secret
+    pub fn draft(&self) -> Value {
+        let values = self.get_values();
+        let draft = self.fields
+            .iter()
+            .filter(|f| !f.is_secret())
+            .map(|f| (f.get_label().to_owned(), Value::from(values[f.get_label()].clone())))
+            .collect::<Map<String, Value>>();
+        Value::Object(draft)
+    }
+
+    /// Restores values of fields from `draft` (see [draft]), labels not matching any field are
+    /// ignored.
+    ///
+    /// [draft]: #method.draft
+    pub fn set_draft(&mut self, draft: &Value) {
+        let draft = match draft.as_object() {
+            Some(draft) => draft,
+            None => return,
+        };
+        for (label, value) in draft.iter() {
+            if let Some(value) = value.as_str() {
+                self.set_field_value(label, value).ok();
+            }
+        }
+    }
+
+    fn event_save_draft(&mut self) -> EventResult {
+        let draft = self.draft();
+        let cb = self.on_save_draft
+            .clone()
+            .map(|cb| Callback::from_fn(move |c| cb(c, draft.clone())));
+        EventResult::Consumed(cb)
+    }
+
+    /// Asks user `question` (Yes / No) before valid data is submitted.
+    ///
+    /// `question` may refer to field's value with `{label}`.
+    ///
+    /// ```
+    /// # extern crate fui;
+    /// # use fui::fields::Text;
+    /// # use fui::form::FormView;
+    /// # fn main() {
+    /// let form = FormView::new()
+    ///     .field(Text::new("target"))
+    ///     .confirm("Really create archive {target}?");
+    /// # }
+    /// ```
+    pub fn confirm<IS: Into<String>>(mut self, question: IS) -> Self {
+        self.confirm = Some(question.into());
+        self
+    }
+
+    /// Adds button `label` calling `callback` with form's data, e.g. to preview what would be
+    /// submitted.
+    ///
+    /// Like `Submit` it validates the form first and the `callback` is called only when data is
+    /// valid, but the form stays shown.
+    ///
+    /// ```
+    /// # extern crate fui;
+    /// # use fui::cursive::views::Dialog;
+    /// # use fui::fields::Text;
+    /// # use fui::form::FormView;
+    /// # fn main() {
+    /// let form = FormView::new()
+    ///     .field(Text::new("name"))
+    ///     .button("Preview", |c, data| c.add_layer(Dialog::info(format!("{}", data))));
+    /// # }
+    /// ```
+    pub fn button<IS, F>(mut self, label: IS, callback: F) -> Self
+    where
+        IS: Into<String>,
+        F: Fn(&mut Cursive, Value) + 'static,
+    {
+        let idx = self.view.buttons_mut().count();
+        let dialog = ::std::mem::replace(&mut self.view, Dialog::new());
+        self.view = dialog.button(label, |_| {});
+        self.buttons.push((idx, Rc::new(callback)));
+        self
+    }
+
+    /// Gets `label` of the only `field` if it's a flag (like [Checkbox]), which makes the form
+    /// a yes/no confirmation.
+    ///
+    /// [Checkbox]: ../fields/struct.Checkbox.html
+    pub fn confirm_label(&self) -> Option<&str> {
+        if self.fields.len() != 1 {
+            return None;
+        }
+        let field = &self.fields[0];
+        if field.is_flag() {
+            Some(field.get_label())
+        } else {
+            None
+        }
+    }
+
+    /// Translates form's fields to [clap::Arg]
+    ///
+    /// [clap::Arg]: ../../clap/struct.Arg.html
+    pub fn fields2clap_args(&self) -> Vec<clap::Arg<'_, '_>> {
+        let mut args = Vec::with_capacity(self.fields.len());
+        for field in &self.fields {
+            let has_default = self.defaults.contains_key(field.get_label());
+            // missing argument is taken from defaults
+            args.extend(field.clap_args().into_iter().map(|arg| {
+                if has_default {
+                    arg.required(false)
+                } else {
+                    arg
+                }
+            }));
+        }
+        args
+    }
+
+    /// Describes form's data with [JSON Schema] (types of fields, required ones, options of
+    /// [OneOf] and other validators' constraints), e.g. to document or check input of a tool.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate serde_json;
+    /// # extern crate fui;
+    /// # use fui::fields::{Checkbox, Text};
+    /// # use fui::form::FormView;
+    /// # use fui::validators::{OneOf, Required};
+    /// # fn main() {
+    /// let form = FormView::new()
+    ///     .field(Text::new("format").validator(Required).validator(OneOf(vec!["tar", "zip"])))
+    ///     .field(Checkbox::new("verbose").help("Lists files"));
+    /// assert_eq!(
+    ///     form.schema(),
+    ///     json!({
+    ///         "$schema": "http://json-schema.org/draft-07/schema#",
+    ///         "type": "object",
+    ///         "properties": {
+    ///             "format": {"type": "string", "enum": ["tar", "zip"]},
+    ///             "verbose": {"type": "boolean", "description": "Lists files"},
+    ///         },
+    ///         "required": ["format"],
+    ///         "additionalProperties": false,
+    ///     })
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [JSON Schema]: https://json-schema.org
+    /// [OneOf]: ../validators/struct.OneOf.html
+    pub fn schema(&self) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for field in self.fields.iter() {
+            let schema = field.schema();
+            // conditional fields may be hidden, so they are never required
+            if field.is_required() && field.clap_arg().is_set(clap::ArgSettings::Required) {
+                required.extend(schema.keys().map(|k| Value::from(k.as_str())));
+            }
+            properties.extend(schema);
+        }
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+
+    /// Translates [clap::ArgMatches] to [serde_json::Value] based on fields.
+    ///
+    /// Errors of validation are printed to stderr (invalid fields are skipped in data).
+    ///
+    /// [clap::ArgMatches]: ../../clap/struct.ArgMatches.html
+    /// [serde_json::Value]: ../../serde_json/enum.Value.html
+    pub fn clap_arg_matches2value(&self, arg_matches: &clap::ArgMatches) -> Value {
+        let (form_data, errors) = self.clap_arg_matches2data(arg_matches);
+        for e in errors {
+            eprintln!("ERROR: {:?}", e);
+        }
+        form_data
+    }
+
+    /// Translates [clap::ArgMatches] to data like [clap_arg_matches2value], but errors of
+    /// validation (of fields, form's and async validators) are returned instead of printed.
+    ///
+    /// [clap::ArgMatches]: ../../clap/struct.ArgMatches.html
+    /// [clap_arg_matches2value]: #method.clap_arg_matches2value
+    pub fn validate_clap_arg_matches(
+        &self,
+        arg_matches: &clap::ArgMatches,
+    ) -> Result<Value, Vec<String>> {
+        let (form_data, errors) = self.clap_arg_matches2data(arg_matches);
+        if errors.is_empty() {
+            Ok(form_data)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Collects data & errors of validation from [clap::ArgMatches], warnings are printed.
+    ///
+    /// [clap::ArgMatches]: ../../clap/struct.ArgMatches.html
+    fn clap_arg_matches2data(&self, arg_matches: &clap::ArgMatches) -> (Value, Vec<String>) {
+        let mut errors = Vec::new();
+        let mut form_data = Map::with_capacity(self.fields.len());
+        let context = self.fields
+            .iter()
+            .map(|field| {
+                let label = field.get_label();
+                let given = field
+                    .clap_args()
+                    .iter()
+                    .any(|arg| arg_matches.is_present(arg.b.name));
+                let value = match self.defaults.get(label) {
+                    Some(value) if !given => field.value2str(value),
+                    _ => field.clap_args2str(arg_matches),
+                };
+                (label.to_owned(), value)
+            })
+            .collect::<Context>();
+        for (idx, field) in self.fields.iter().enumerate() {
+            if !field.is_visible(&context) {
+                continue;
+            }
+            let data = &context[field.get_label()];
+            match field.validate_with(data, &context) {
+                Ok(v) => {
+                    self.add_data(&mut form_data, idx, v);
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+        let form_data = Value::Object(form_data);
+        // like in `validate_all`, form's validators need valid fields
+        if errors.is_empty() {
+            errors.extend(self.run_form_validators(&form_data).into_values());
+        }
+        for (label, validator) in &self.warnings {
+            if self.find_visible_field(label, &context).is_none() {
+                continue;
+            }
+            if let Some(w) = validator.validate_with(&context[label], &context) {
+                let msg = format!("WARNING: {:?}", w);
+                eprintln!("{}", msg);
+            }
+        }
+        // there is no UI to keep responsive, so async validators are simply awaited
+        for (label, validator) in &self.async_validators {
+            if self.find_visible_field(label, &context).is_none() {
+                continue;
+            }
+            if let Some(e) = validator.validate(&context[label]) {
+                errors.push(e);
+            }
+        }
+        (form_data, errors)
+    }
+
+    /// Converts form's `data` back to CLI arguments giving it (the opposite of
+    /// [clap_arg_matches2value]), fields missing in `data` are skipped.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate serde_json;
+    /// # extern crate fui;
+    /// # use fui::fields::{Checkbox, Text};
+    /// # use fui::form::FormView;
+    /// # fn main() {
+    /// let form = FormView::new()
+    ///     .field(Text::new("target"))
+    ///     .field(Checkbox::new("verbose"));
+    /// let args = form.value2clap_args(&json!({"target": "a.tar", "verbose": false}));
+    /// assert_eq!(args, vec!["--target", "a.tar", "--no-verbose"]);
+    /// # }
+    /// ```
+    ///
+    /// [clap_arg_matches2value]: #method.clap_arg_matches2value
+    pub fn value2clap_args(&self, data: &Value) -> Vec<String> {
+        let mut args = Vec::new();
+        for field in self.fields.iter() {
+            if let Some(value) = data.get(field.get_label()) {
+                args.extend(field.value2clap_args(value));
+            }
+        }
+        args
+    }
+
+    /// Sets `value` of field labeled `label`.
+    ///
+    /// Value is passed as `str` in the same form as it comes from CLI, so for [Checkbox] it's
+    /// `"true"` or `"false"` and for [Multiselect] items are separated with comma.
+    ///
+    /// [Checkbox]: ../fields/struct.Checkbox.html
+    /// [Multiselect]: ../fields/struct.Multiselect.html
+    pub fn set_field_value(&mut self, label: &str, value: &str) -> Result<(), String> {
+        let idx = match self.fields.iter().position(|f| f.get_label() == label) {
+            Some(idx) => idx,
+            None => return Err(format!("Field {:?} doesn't exist", label)),
+        };
+        let view = get_widget_mut(get_fields_layout_mut(&mut self.view), &self.positions[idx]);
+        self.fields[idx].get_widget_manager().set_value(view, value);
+        self.refresh_visibility();
+        Ok(())
+    }
+
+    /// Sets values of fields, see [set_field_value].
+    ///
+    /// Chainable variant, handy when form has to be filled without terminal (like in tests).
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of labels doesn't match a field.
+    ///
+    /// [set_field_value]: #method.set_field_value
+    ///
+    /// Examples
+    ///
+    /// ```
+    /// extern crate fui;
+    /// #[macro_use]
+    /// extern crate serde_json;
+    ///
+    /// use fui::fields::{Checkbox, Text};
+    /// use fui::form::FormView;
+    /// use fui::validators::Required;
+    ///
+    /// # fn main() {
+    /// let form = FormView::new()
+    ///     .field(Text::new("name").validator(Required))
+    ///     .field(Checkbox::new("verbose"));
+    ///
+    /// let result = form.with_values(&[("name", "John"), ("verbose", "true")])
+    ///     .validate_all();
+    /// assert_eq!(result, Ok(json!({"name": "John", "verbose": true})));
+    /// # }
+    /// ```
+    pub fn with_values(mut self, values: &[(&str, &str)]) -> Self {
+        for &(label, value) in values {
+            if let Err(e) = self.set_field_value(label, value) {
+                panic!("{}", e);
+            }
+        }
+        self
+    }
+
+    /// Fills fields with `data` (e.g. previously submitted one), keys are matched to fields'
+    /// labels.
+    ///
+    /// Fields missing in `data` keep their values and keys not matching any field are ignored,
+    /// `Err` is returned if `data` isn't an object.
+    ///
+    /// ```
+    /// extern crate fui;
+    /// #[macro_use]
+    /// extern crate serde_json;
+    ///
+    /// use fui::fields::{Checkbox, Text};
+    /// use fui::form::FormView;
+    ///
+    /// # fn main() {
+    /// let mut form = FormView::new()
+    ///     .field(Text::new("name"))
+    ///     .field(Checkbox::new("verbose"));
+    ///
+    /// let data = json!({"name": "John", "verbose": true});
+    /// form.set_data(&data).unwrap();
+    /// assert_eq!(form.validate_all(), Ok(data));
+    /// # }
+    /// ```
+    pub fn set_data(&mut self, data: &Value) -> Result<(), String> {
+        let data = match data.as_object() {
+            Some(map) => map,
+            None => return Err(format!("Data must be an object, got: {}", data)),
+        };
+        for (field, position) in self.fields.iter().zip(self.positions.iter()) {
+            if let Some(value) = data.get(field.get_label()) {
+                let view = get_widget_mut(get_fields_layout_mut(&mut self.view), position);
+                field.get_widget_manager().set_value(view, &field.value2str(value));
+            }
+        }
+        self.refresh_visibility();
+        Ok(())
+    }
+
+    /// Sets `defaults` of fields (e.g. read from config file), keys are matched to fields'
+    /// labels like in [set_data].
+    ///
+    /// Defaults take place of initial values: they fill fields (also when form is reset) and
+    /// CLI arguments which aren't given (so such arguments are never required).
+    ///
+    /// [set_data]: #method.set_data
+    pub fn set_defaults(&mut self, defaults: &Value) -> Result<(), String> {
+        self.set_data(defaults)?;
+        self.defaults = defaults.as_object().cloned().unwrap_or_default();
+        Ok(())
+    }
+
+    /// Collects current values of all fields (field's label -> value).
+    fn get_values(&self) -> Context {
+        self.fields
+            .iter()
+            .zip(self.positions.iter())
+            .map(|(field, position)| {
+                let view = get_widget(get_fields_layout(&self.view), position);
+                let value = field.get_widget_manager().get_value(view);
+                (field.get_label().to_owned(), value)
+            })
+            .collect::<Context>()
+    }
+
+    /// Shows or hides fields according to current values of fields they depend on.
+    fn refresh_visibility(&mut self) {
+        let context = self.get_values();
+        for (field, position) in self.fields.iter().zip(self.positions.iter()) {
+            let container = get_container_mut(get_fields_layout_mut(&mut self.view), position);
+            container.visible = field.is_visible(&context);
+        }
+    }
+
+    /// Validates all fields returning form's data or errors (field's label -> error message).
+    ///
+    /// Hidden fields are skipped. When fields are valid, validators of the whole form are run and
+    /// their global errors are under [FORM_ERROR_LABEL].
+    ///
+    /// [FORM_ERROR_LABEL]: constant.FORM_ERROR_LABEL.html
+    pub fn validate_all(&self) -> Result<Value, HashMap<String, String>> {
+        let mut data = Map::with_capacity(self.fields.len());
+        let mut errors = HashMap::with_capacity(self.fields.len());
+
+        let context = self.get_values();
+        for (idx, field) in self.fields.iter().enumerate() {
+            if !field.is_visible(&context) {
+                continue;
+            }
+            let label = field.get_label();
+            match field.validate_with(&context[label], &context) {
+                Ok(v) => {
+                    self.add_data(&mut data, idx, v);
+                }
+                Err(e) => {
+                    errors.insert(label.to_owned(), e.to_owned());
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        let data = Value::Object(data);
+        let errors = self.run_form_validators(&data);
+        if errors.is_empty() {
+            Ok(data)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Collects form's current data without submitting it (e.g. for live previews or auto-save).
+    ///
+    /// It's like [validate_all] but never fails, invalid fields hold entered text as is.
+    ///
+    /// ```
+    /// extern crate fui;
+    /// #[macro_use]
+    /// extern crate serde_json;
+    ///
+    /// use fui::fields::{Number, Text};
+    /// use fui::form::FormView;
+    ///
+    /// # fn main() {
+    /// let form = FormView::new()
+    ///     .field(Text::new("name"))
+    ///     .field(Number::integer("level"))
+    ///     .with_values(&[("name", "John"), ("level", "high")]);
+    ///
+    /// assert_eq!(form.data(), json!({"name": "John", "level": "high"}));
+    /// # }
+    /// ```
+    ///
+    /// [validate_all]: #method.validate_all
+    pub fn data(&self) -> Value {
+        let mut data = Map::with_capacity(self.fields.len());
+        let context = self.get_values();
+        for (idx, field) in self.fields.iter().enumerate() {
+            if !field.is_visible(&context) {
+                continue;
+            }
+            let label = field.get_label();
+            match field.validate_with(&context[label], &context) {
+                Ok(v) => self.add_data(&mut data, idx, v),
+                Err(_) => {
+                    data.insert(label.to_owned(), Value::String(context[label].clone()));
+                }
+            }
+        }
+        Value::Object(data)
+    }
+
+    /// Lists `errors` (field's index, error) above fields, so user can jump to invalid fields.
+    fn set_error_summary(&mut self, errors: &[(usize, String)]) {
+        let (header, spacer) = if errors.is_empty() {
+            ("", "")
+        } else {
+            (self.strings.errors.as_str(), " ")
+        };
+        let layout = get_content_child_mut(&mut self.view, SUMMARY_IDX);
+        let header_view = layout.get_child_mut(0);
+        if let Some(text) = header_view.and_then(|c| c.as_any_mut().downcast_mut::<TextView>()) {
+            text.set_content(StyledString::styled(header, Effect::Bold));
+        }
+        if let Some(list) = layout
+            .get_child_mut(1)
+            .and_then(|c| c.as_any_mut().downcast_mut::<ErrorSummary>())
+            .map(|summary| &mut summary.view)
+        {
+            list.clear();
+            for &(idx, ref error) in errors {
+                let error = self.strings.translate(error);
+                list.add_item(format!("{}: {}", self.fields[idx].get_label(), error), idx);
+            }
+        }
+        let spacer_view = layout.get_child_mut(2);
+        if let Some(text) = spacer_view.and_then(|c| c.as_any_mut().downcast_mut::<TextView>()) {
+            text.set_content(spacer);
+        }
+    }
+
+    /// Moves focus to field selected in errors' summary, when summary has focus.
+    fn event_jump_to_error(&mut self) -> Option<EventResult> {
+        if self.view.focus() != DialogFocus::Content {
+            return None;
+        }
+        let idx = {
+            let content = self.view
+                .get_content()
+                .as_any()
+                .downcast_ref::<LinearLayout>()
+                .unwrap();
+            if content.get_focus_index() != SUMMARY_IDX {
+                return None;
+            }
+            let list = content
+                .get_child(SUMMARY_IDX)
+                .and_then(|c| c.as_any().downcast_ref::<LinearLayout>())
+                .and_then(|l| l.get_child(1))
+                .and_then(|c| c.as_any().downcast_ref::<ErrorSummary>())
+                .map(|summary| &summary.view)
+                .unwrap();
+            match list.selected_id() {
+                Some(_) => *list.selection(),
+                None => return None,
+            }
+        };
+        self.focus_field(idx);
+        Some(EventResult::Consumed(None))
+    }
+
+    /// Shows `error` of the whole form below fields.
+    fn set_form_error(&mut self, error: &str) {
+        let error = self.strings.translate(error);
+        get_content_child_mut(&mut self.view, FORM_ERROR_IDX)
+            .get_child_mut(0)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<TextView>()
+            .unwrap()
+            .set_content(error);
+    }
+
+    /// Shows `error` and `state` on visible field with index `idx`.
+    fn show_field_error(&mut self, idx: usize, error: &str, state: FieldState) {
+        self.states[idx] = state;
+        {
+            let field = &self.fields[idx];
+            let container =
+                get_container_mut(get_fields_layout_mut(&mut self.view), &self.positions[idx]);
+            if !container.visible {
+                return;
+            }
+            field
+                .get_widget_manager()
+                .set_error(container.view.get_inner_mut(), &self.strings.translate(error));
+        }
+        self.apply_field_style(idx);
+    }
+
+    /// Styles label & error of field with index `idx` according to its state & focus.
+    fn apply_field_style(&mut self, idx: usize) {
+        if !self.state_colors {
+            return;
+        }
+        let state = self.states[idx];
+        let label_style = self.field_styles
+            .label_style(state, self.styled_focus == Some(idx));
+        let error_style = self.field_styles.error_style(state);
+        let widget_manager = self.fields[idx].get_widget_manager();
+        let view = get_widget_mut(get_fields_layout_mut(&mut self.view), &self.positions[idx]);
+        widget_manager.set_label_style(view, label_style);
+        widget_manager.set_error_style(view, error_style);
+    }
+
+    /// Moves focused style to the field which has focus now.
+    fn refresh_focus_style(&mut self) {
+        let focused = self.get_focused_field();
+        if focused == self.styled_focus {
+            return;
+        }
+        let previous = ::std::mem::replace(&mut self.styled_focus, focused);
+        for idx in previous.into_iter().chain(focused) {
+            self.apply_field_style(idx);
+        }
+    }
+
+    fn event_submit(&mut self) -> EventResult {
+        self.event_validated(None)
+    }
+
+    /// Validates the form and passes its data to `Submit` or to custom button `button` (index in
+    /// dialog).
+    fn event_validated(&mut self, button: Option<usize>) -> EventResult {
+        if self.async_pending.is_some() {
+            return EventResult::Consumed(None);
+        }
+        let errors = match self.validate_all() {
+            Ok(data_map) => match self.run_async_validators() {
+                Some(ref errors) if errors.is_empty() => {
+                    self.set_form_error("");
+                    return match button {
+                        Some(idx) => self.press_button(idx, data_map),
+                        None => self.submit(data_map),
+                    };
+                }
+                Some(errors) => errors,
+                None => {
+                    self.pending_button = button;
+                    let cb = Callback::from_fn(|c| c.set_fps(ASYNC_FPS));
+                    return EventResult::Consumed(Some(cb));
+                }
+            },
+            Err(errors) => errors,
+        };
+        let form_error = errors.get(FORM_ERROR_LABEL).cloned().unwrap_or_default();
+        self.set_form_error(&form_error);
+        // focus goes to the first invalid field, if there is no such field (only form's error)
+        // it stays where it was
+        let mut to_focus = self.get_focused_field();
+        let mut summary = Vec::new();
+        for idx in 0..self.fields.len() {
+            let e = errors
+                .get(self.fields[idx].get_label())
+                .map(|x| x.as_ref())
+                .unwrap_or("");
+            let state = if e.is_empty() {
+                FieldState::Valid
+            } else {
+                FieldState::Invalid
+            };
+            if state == FieldState::Invalid {
+                summary.push((idx, e.to_owned()));
+            }
+            self.show_field_error(idx, e, state);
+        }
+        if let Some(&(idx, _)) = summary.first() {
+            to_focus = Some(idx);
+        }
+        self.set_error_summary(&summary);
+        if let Some(idx) = to_focus {
+            self.focus_field(idx);
+        }
+        EventResult::Consumed(None)
+    }
+
+    /// Calls callback of custom button `idx` (index in dialog) with valid `data`.
+    fn press_button(&mut self, idx: usize, data: Value) -> EventResult {
+        self.set_error_summary(&[]);
+        for field_idx in 0..self.fields.len() {
+            self.show_field_error(field_idx, "", FieldState::Valid);
+        }
+        let callback = self.buttons
+            .iter()
+            .find(|&&(i, _)| i == idx)
+            .map(|(_, cb)| Rc::clone(cb))
+            .unwrap();
+        let cb = Callback::from_fn(move |c| callback(c, data.clone()));
+        EventResult::Consumed(Some(cb))
+    }
+
+    /// Checks if button `idx` (index in dialog) was added with [button].
+    ///
+    /// [button]: #method.button
+    fn is_custom_button(&self, idx: usize) -> bool {
+        self.buttons.iter().any(|&(i, _)| i == idx)
+    }
+
+    /// Calls `on_submit` with valid `data`, when there are warnings user has to confirm it first.
+    fn submit(&mut self, data: Value) -> EventResult {
+        self.set_error_summary(&[]);
+        let warnings = self.collect_warnings();
+        for idx in 0..self.fields.len() {
+            let warning = warnings
+                .iter()
+                .find(|&&(i, _)| i == idx)
+                .map(|(_, w)| w.as_ref())
+                .unwrap_or("");
+            let state = if warning.is_empty() {
+                FieldState::Valid
+            } else {
+                FieldState::Warning
+            };
+            self.show_field_error(idx, warning, state);
+        }
+        let on_submit = self.on_submit.clone();
+        let question = self.confirm.as_ref().map(|q| fill_template(q, &data));
+        let (title, msg, buttons) = if !warnings.is_empty() {
+            let msg = warnings
+                .iter()
+                .map(|&(idx, ref w)| {
+                    let warning = self.strings.translate(w);
+                    format!("{}: {}", self.fields[idx].get_label(), warning)
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            let question = question.unwrap_or(self.strings.submit_anyway.clone());
+            let strings = &self.strings;
+            (
+                strings.warnings.clone(),
+                format!("{}\n\n{}", msg, question),
+                (strings.back.clone(), strings.submit.clone()),
+            )
+        } else if let Some(question) = question {
+            let strings = &self.strings;
+            (
+                strings.confirm.clone(),
+                question,
+                (strings.no.clone(), strings.yes.clone()),
+            )
+        } else {
+            let opt_cb = on_submit.map(|cb| {
+                Callback::from_fn(move |c| {
+                    cb(c, data.clone());
+                })
+            });
+            return EventResult::Consumed(opt_cb);
+        };
+        let cb = Callback::from_fn(move |c| {
+            let on_submit = on_submit.clone();
+            let data = data.clone();
+            let confirm = Dialog::text(msg.clone())
+                .title(title.clone())
+                .button(buttons.0.clone(), |c| {
+                    c.pop_layer();
+                })
+                .button(buttons.1.clone(), move |c| {
+                    c.pop_layer();
+                    if let Some(ref cb) = on_submit {
+                        cb(c, data.clone());
+                    }
+                });
+            c.add_layer(confirm);
+        });
+        EventResult::Consumed(Some(cb))
+    }
+
+    /// Restores all fields to their initial values and clears errors.
+    pub fn reset(&mut self) {
+        let focused = self.get_focused_field();
+        for (field, position) in self.fields.iter().zip(self.positions.iter()) {
+            let view = get_widget_mut(get_fields_layout_mut(&mut self.view), position);
+            field.reset(view);
+        }
+        let defaults = Value::Object(self.defaults.clone());
+        self.set_data(&defaults).ok();
+        for idx in 0..self.fields.len() {
+            self.states[idx] = FieldState::Untouched;
+            self.apply_field_style(idx);
+        }
+        self.set_form_error("");
+        self.set_error_summary(&[]);
+        self.refresh_visibility();
+        if let Some(idx) = focused {
+            self.focus_field(idx);
+        }
+    }
+
+    /// Returns index of field which has focus.
+    fn get_focused_field(&self) -> Option<usize> {
+        if self.view.focus() != DialogFocus::Content {
+            return None;
+        }
+        let content = self.view
+            .get_content()
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        if content.get_focus_index() != FIELDS_IDX {
+            return None;
+        }
+        let mut focused = Vec::new();
+        let mut layout = get_fields_layout(&self.view);
+        loop {
+            let idx = layout.get_focus_index();
+            focused.push(idx);
+            match layout.get_child(idx).and_then(as_layout) {
+                Some(nested) => layout = nested,
+                None => break,
+            }
+        }
+        self.positions.iter().position(|p| *p == focused)
+    }
+
+    /// Moves focus to field with index `idx`.
+    fn focus_field(&mut self, idx: usize) {
+        let page_idx = self.positions[idx][0];
+        if let Some(tab) = self.tabs.iter().position(|&(_, p)| p == page_idx) {
+            if tab != self.active_tab {
+                self.select_tab(tab);
+            }
+        }
+        if self.view.take_focus(Direction::front()) {
+            let label = self.fields[idx].get_label();
+            self.view.focus_view(&Selector::Id(label)).ok();
+        }
+        self.refresh_focus_style();
+    }
+
+    /// Describes key bindings active in current context.
+    fn keybindings_help(&self) -> String {
+        let kb = &self.keybindings;
+        let strings = &self.strings;
+        let mut bindings = vec![
+            (describe_events(&kb.submit), strings.key_submit.as_str()),
+            (describe_events(&kb.reset), strings.key_reset.as_str()),
+            ("Tab / Shift+Tab".to_string(), strings.key_next_field.as_str()),
+            ("Enter".to_string(), strings.key_press_button.as_str()),
+            (describe_events(&kb.help), strings.key_help.as_str()),
+        ];
+        if kb.enter_submits {
+            bindings.push(("Enter".to_string(), strings.key_enter_submits.as_str()));
+        }
+        if !self.tabs.is_empty() {
+            bindings.push((describe_events(&kb.next_tab), strings.key_next_tab.as_str()));
+            bindings.push((describe_events(&kb.previous_tab), strings.key_previous_tab.as_str()));
+        }
+        if self.on_save_draft.is_some() {
+            bindings.push((describe_events(&kb.save_draft), strings.key_save_draft.as_str()));
+        }
+        if !self.history.is_empty() {
+            let older = strings.key_previous_value.as_str();
+            bindings.push((describe_events(&kb.previous_value), older));
+            bindings.push((describe_events(&kb.next_value), strings.key_next_value.as_str()));
+        }
+        // bindings of fields' widgets are translated like messages
+        let field_bindings = match self.get_focused_field() {
+            Some(idx) => self.fields[idx]
+                .get_widget_manager()
+                .keybindings()
+                .iter()
+                .map(|&(k, a)| (k.to_string(), strings.translate(a)))
+                .collect(),
+            None => Vec::new(),
+        };
+        bindings.extend(field_bindings.iter().map(|(k, a)| (k.clone(), a.as_str())));
+        bindings
+            .iter()
+            .filter(|(keys, _)| !keys.is_empty())
+            .map(|&(ref keys, action)| format!("{:20}{}", keys, action))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn event_resize(&mut self) -> EventResult {
+        let content = self.view
+            .get_content_mut()
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap();
+        refresh_texts(content);
+        // cursive lays out the whole screen after this event anyway
+        EventResult::Ignored
+    }
+
+    /// Shows `help` of field with index `idx` in the help panel, `None` clears the panel.
+    fn set_help_panel(&mut self, idx: Option<usize>) {
+        let strings = &self.strings;
+        let text = match idx.map(|idx| &self.fields[idx]) {
+            Some(field) if field.get_help().is_empty() => {
+                format!("{}: {}\n{}", strings.help, field.get_label(), strings.no_help)
+            }
+            Some(field) => format!("{}: {}\n{}", strings.help, field.get_label(), field.get_help()),
+            None => "".to_string(),
+        };
+        get_content_child_mut(&mut self.view, HELP_PANEL_IDX)
+            .get_child_mut(0)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<TextView>()
+            .unwrap()
+            .set_content(text);
+    }
+
+    /// Makes opened help panel follow focused field (it keeps the last one outside of fields).
+    fn refresh_help_panel(&mut self) {
+        if !self.help_panel {
+            return;
+        }
+        if let Some(idx) = self.get_focused_field() {
+            self.set_help_panel(Some(idx));
+        }
+    }
+
+    fn event_toggle_help_panel(&mut self, idx: usize) -> EventResult {
+        self.help_panel = !self.help_panel;
+        self.set_help_panel(if self.help_panel { Some(idx) } else { None });
+        EventResult::Consumed(None)
+    }
+
+    fn event_show_help(&mut self) -> EventResult {
+        if let Some(idx) = self.get_focused_field() {
+            return self.event_toggle_help_panel(idx);
+        }
+        let help = self.keybindings_help();
+        let title = self.strings.shortcuts.clone();
+        let cb = Callback::from_fn(move |c| {
+            c.add_layer(Dialog::info(help.clone()).title(title.clone()));
+        });
+        EventResult::Consumed(Some(cb))
+    }
+
+    fn event_cancel(&mut self) -> EventResult {
+        let cb = self.on_cancel
+            .clone()
+            .map(|cb| Callback::from_fn(move |c| cb(c)));
+        EventResult::Consumed(cb)
+    }
+
+    fn event_reset(&mut self) -> EventResult {
+        self.reset();
+        EventResult::Consumed(None)
+    }
+
+    /// Fills focused field with its `older` (or newer) value from history.
+    fn event_history(&mut self, older: bool) -> EventResult {
+        let idx = match self.get_focused_field() {
+            Some(idx) => idx,
+            None => return EventResult::Ignored,
+        };
+        let count = self.history
+            .get(self.fields[idx].get_label())
+            .map_or(0, |values| values.len());
+        if count == 0 {
+            return EventResult::Consumed(None);
+        }
+        let pos = match (self.history_pos, older) {
+            (Some((field, pos)), true) if field == idx => (pos + 1).min(count - 1),
+            (Some((field, pos)), false) if field == idx => pos.saturating_sub(1),
+            (_, true) => 0,
+            (_, false) => return EventResult::Consumed(None),
+        };
+        self.history_pos = Some((idx, pos));
+        let field = &self.fields[idx];
+        let value = field.value2str(&self.history[field.get_label()][pos]);
+        let view = get_widget_mut(get_fields_layout_mut(&mut self.view), &self.positions[idx]);
+        field.get_widget_manager().set_value(view, &value);
+        EventResult::Consumed(None)
+    }
+
+    /// Sets previous `values` of field with `label` (the latest first), they fill the field when
+    /// it's focused and [KeyBindings::previous_value] or [KeyBindings::next_value] is pressed.
+    ///
+    /// Values are the ones found in form's data (see [set_data]).
+    ///
+    /// [KeyBindings::previous_value]: struct.KeyBindings.html#structfield.previous_value
+    /// [KeyBindings::next_value]: struct.KeyBindings.html#structfield.next_value
+    /// [set_data]: #method.set_data
+    pub fn set_history(&mut self, label: &str, values: Vec<Value>) {
+        self.history.insert(label.to_owned(), values);
+        self.history_pos = None;
+    }
+
+    /// Sets previous `values` of field with `label`, see [set_history].
+    ///
+    /// Chainable variant.
+    ///
+    /// [set_history]: #method.set_history
+    pub fn history(mut self, label: &str, values: Vec<Value>) -> Self {
+        self.set_history(label, values);
+        self
+    }
+
+    /// Gets labels of fields which values are secret (see [FormField::is_secret]).
+    ///
+    /// [FormField::is_secret]: ../fields/trait.FormField.html#method.is_secret
+    pub fn secret_labels(&self) -> Vec<String> {
+        self.fields
+            .iter()
+            .filter(|f| f.is_secret())
+            .map(|f| f.get_label().to_owned())
+            .collect()
+    }
+
+    /// Sets `title` of the form on the top of it
+    pub fn title(mut self, title: &str) -> Self {
+        self.view.set_title(title);
+        self
+    }
+
+    /// Gives access to underlying [Dialog] for customization not covered by `FormView`.
+    ///
+    /// Safe changes are: title, padding, alignment, buttons' labels and adding buttons (they are
+    /// placed after `Cancel`, `Reset` & `Submit`, which keep working).
+    ///
+    /// Replacing dialog's content or the whole dialog breaks the form (fields can't be found,
+    /// `Cancel`, `Reset` & `Submit` may be gone).
+    ///
+    /// ```
+    /// # extern crate fui;
+    /// # use fui::cursive::views::Dialog;
+    /// # use fui::form::FormView;
+    /// # use std::mem;
+    /// # fn main() {
+    /// let form = FormView::new().with_dialog(|d| {
+    ///     let dialog = mem::replace(d, Dialog::new());
+    ///     *d = dialog.button("Help", |c| c.add_layer(Dialog::info("Fill the form")));
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// [Dialog]: ../../cursive/views/struct.Dialog.html
+    pub fn with_dialog<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut Dialog),
+    {
+        f(&mut self.view);
+        self
+    }
+
+    /// Sets keys triggering form's actions.
+    ///
+    /// Labels of `Reset` & `Submit` buttons are updated to show the first of their keys.
+    pub fn set_keybindings(&mut self, keybindings: KeyBindings) {
+        self.keybindings = keybindings;
+        self.refresh_button_labels();
+    }
+
+    /// Sets keys triggering form's actions.
+    ///
+    /// Chainable variant.
+    pub fn keybindings(mut self, keybindings: KeyBindings) -> Self {
+        self.set_keybindings(keybindings);
+        self
+    }
+
+    /// Sets built-in texts of the form (e.g. labels of buttons) & translations of messages.
+    ///
+    /// Labels of `Reset` & `Submit` buttons are updated to show the first of their keys.
+    pub fn set_strings(&mut self, strings: Strings) {
+        self.set_button_label(CANCEL_BTN, strings.cancel.clone());
+        self.strings = strings;
+        self.refresh_button_labels();
+    }
+
+    /// Sets built-in texts of the form (e.g. labels of buttons) & translations of messages.
+    ///
+    /// Chainable variant.
+    pub fn strings(mut self, strings: Strings) -> Self {
+        self.set_strings(strings);
+        self
+    }
+
+    /// Labels `Reset` & `Submit` buttons with their names & first of their keys.
+    fn refresh_button_labels(&mut self) {
+        let reset = label_with_key(&self.strings.reset, &self.keybindings.reset);
+        let submit = label_with_key(&self.strings.submit, &self.keybindings.submit);
+        self.set_button_label(RESET_BTN, reset);
+        self.set_button_label(SUBMIT_BTN, submit);
+    }
+
+    /// Sets label of `Submit` button (by default it's `Submit (Ctrl+f)`).
+    pub fn submit_label<IS: Into<String>>(mut self, label: IS) -> Self {
+        self.set_button_label(SUBMIT_BTN, label);
+        self
+    }
+
+    /// Sets label of `Cancel` button.
+    pub fn cancel_label<IS: Into<String>>(mut self, label: IS) -> Self {
+        self.set_button_label(CANCEL_BTN, label);
+        self
+    }
+
+    fn set_button_label<IS: Into<String>>(&mut self, idx: usize, label: IS) {
+        if let Some(button) = self.view.buttons_mut().nth(idx) {
+            button.set_label(label);
+        }
+    }
+
+    /// Gets underlying [Dialog].
+    ///
+    /// [Dialog]: ../../cursive/views/struct.Dialog.html
+    pub fn dialog(&self) -> &Dialog {
+        &self.view
+    }
+
+    /// Disables coloring fields by their validation state (useful for monochrome terminals).
+    pub fn disable_state_colors(mut self) -> Self {
+        self.state_colors = false;
+        self
+    }
+
+    /// Sets `styles` of fields' labels & errors.
+    pub fn set_field_styles(&mut self, styles: FieldStyles) {
+        self.field_styles = styles;
+        for idx in 0..self.fields.len() {
+            self.apply_field_style(idx);
+        }
+    }
+
+    /// Sets `styles` of fields' labels & errors.
+    ///
+    /// Chainable variant.
+    pub fn field_styles(mut self, styles: FieldStyles) -> Self {
+        self.set_field_styles(styles);
+        self
+    }
+
+    /// Sets `description` of the form shown above fields.
+    ///
+    /// It's wrapped to the form's width and it isn't a part of form's data.
+    pub fn description(mut self, description: &str) -> Self {
+        let layout = get_content_child_mut(&mut self.view, DESCRIPTION_IDX);
+        if children_count(layout) == 0 {
+            layout.add_child(TextView::new(description));
+            layout.add_child(DummyView);
+        } else {
+            layout
+                .get_child_mut(0)
+                .unwrap()
+                .as_any_mut()
+                .downcast_mut::<TextView>()
+                .unwrap()
+                .set_content(description);
+        }
+        self
+    }
+}
+
+impl ViewWrapper for FormView {
+    wrap_impl!(self.view: Dialog);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let bound = self.keybindings.find(&event);
+        let result = match bound {
+            Some(BoundAction::Submit) => self.event_submit(),
+            Some(BoundAction::Reset) => self.event_reset(),
+            Some(BoundAction::NextTab) => self.event_switch_tab(true),
+            Some(BoundAction::PreviousTab) => self.event_switch_tab(false),
+            Some(BoundAction::Help) => self.event_show_help(),
+            Some(BoundAction::PreviousValue) => self.event_history(true),
+            Some(BoundAction::NextValue) => self.event_history(false),
+            Some(BoundAction::SaveDraft) => self.event_save_draft(),
+            None => self.event_unbound(event),
+        };
+        match bound {
+            Some(BoundAction::PreviousValue) | Some(BoundAction::NextValue) => (),
+            // history is browsed again from the latest value
+            _ => self.history_pos = None,
+        }
+        // edited field may change visibility of others
+        self.refresh_visibility();
+        self.refresh_help_panel();
+        self.refresh_focus_style();
+        result
+    }
+}
+
+impl FormView {
+    /// Handles `event` which isn't bound by [KeyBindings].
+    ///
+    /// [KeyBindings]: struct.KeyBindings.html
+    fn event_unbound(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Mouse {
+                offset: _,
+                position: _,
+                event: MouseEvent::Press(btn),
+            } => {
+                if btn == MouseButton::Left {
+                    let result = self.with_view_mut(|v| v.on_event(event))
+                        .unwrap_or(EventResult::Ignored);
+                    match self.view.focus() {
+                        DialogFocus::Button(CANCEL_BTN) => self.event_cancel(),
+                        DialogFocus::Button(RESET_BTN) => self.event_reset(),
+                        DialogFocus::Button(SUBMIT_BTN) => self.event_submit(),
+                        DialogFocus::Button(idx) if self.is_custom_button(idx) => {
+                            self.event_validated(Some(idx))
+                        }
+                        // content or buttons added with `with_dialog`
+                        _ => result,
+                    }
+                } else {
+                    EventResult::Ignored
+                }
+            }
+            Event::Key(Key::Enter) => match self.view.focus() {
+                DialogFocus::Button(CANCEL_BTN) => self.event_cancel(),
+                DialogFocus::Button(RESET_BTN) => self.event_reset(),
+                DialogFocus::Button(SUBMIT_BTN) => self.event_submit(),
+                DialogFocus::Button(idx) if self.is_custom_button(idx) => {
+                    self.event_validated(Some(idx))
+                }
+                _ => match self.event_jump_to_error() {
+                    Some(result) => result,
+                    None => match self.with_view_mut(|v| v.on_event(event)) {
+                        Some(EventResult::Ignored) | None if self.keybindings.enter_submits => {
+                            self.event_submit()
+                        }
+                        result => result.unwrap_or(EventResult::Ignored),
+                    },
+                },
+            },
+            Event::WindowResize => self.event_resize(),
+            Event::Refresh => self.event_refresh(),
+            Event::Char('?') => match self.with_view_mut(|v| v.on_event(event)) {
+                Some(EventResult::Ignored) | None => match self.get_focused_field() {
+                    Some(idx) => self.event_toggle_help_panel(idx),
+                    None => EventResult::Ignored,
+                },
+                Some(result) => result,
+            },
+            _ => {
+                // default behaviour from ViewWrapper
+                self.with_view_mut(|v| v.on_event(event))
+                    .unwrap_or(EventResult::Ignored)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fields::{Autocomplete, Checkbox, DatePicker, DateTime, Duration, FilePicker, Hidden,
+                 KeyValue, Multiline, Multiselect, Number, Password, RadioGroup, Repeated, Select,
+                 Slider, Static, Tags, Text, Time, Timezone, WidgetManager};
+    use cursive::theme::{BaseColor, Color, Style};
+    use cursive::views::TextView;
+    use validators::Required;
+
+    #[test]
+    fn reset_restores_initial_values() {
+        let mut form = FormView::new()
+            .field(Text::new("t").initial("t-initial"))
+            .field(Checkbox::new("ch").initial(true))
+            .field(Autocomplete::new("ac", vec!["a", "b"]).initial("a"))
+            .field(Multiselect::new("ms", vec!["a", "b"]).initial(vec!["b"]));
+        form = form.with_values(&[("t", "changed"), ("ch", "false"), ("ac", "b"), ("ms", "a,b")]);
+
+        form.reset();
+
+        let exp: Value = ::serde_json::from_str(
+            r#"{ "t": "t-initial", "ch": true, "ac": "a", "ms": ["b"] }"#,
+        ).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    #[test]
+    fn set_field_value_fails_for_missing_field() {
+        let mut form = FormView::new().field(Text::new("t"));
+        assert_eq!(
+            form.set_field_value("missing", "v"),
+            Err("Field \"missing\" doesn't exist".to_string())
+        );
+    }
+
+    #[test]
+    fn with_values_shows_validation_errors() {
+        let result = FormView::new()
+            .field(Text::new("t1").validator(Required))
+            .field(Text::new("t2").validator(Required))
+            .with_values(&[("t1", "v1")])
+            .validate_all();
+        let mut exp = HashMap::new();
+        exp.insert("t2".to_string(), "Field is required".to_string());
+        assert_eq!(result, Err(exp));
+    }
+
+    #[test]
+    fn row_fields_are_validated() {
+        let result = FormView::new()
+            .field(Text::new("t1"))
+            .row(vec![
+                Box::new(Text::new("t2").width(10)),
+                Box::new(Checkbox::new("ch")),
+            ])
+            .field(Text::new("t3"))
+            .with_values(&[("t1", "v1"), ("t2", "v2"), ("ch", "true"), ("t3", "v3")])
+            .validate_all();
+        let exp: Value =
+            ::serde_json::from_str(r#"{ "t1": "v1", "t2": "v2", "ch": true, "t3": "v3" }"#)
+                .unwrap();
+        assert_eq!(result, Ok(exp));
+    }
+
+    #[test]
+    fn description_is_not_a_field() {
+        let result = FormView::new()
+            .description("first description")
+            .field(Text::new("t1"))
+            .description("second description")
+            .field(Text::new("t2"))
+            .with_values(&[("t1", "v1"), ("t2", "v2")])
+            .validate_all();
+        let exp: Value = ::serde_json::from_str(r#"{ "t1": "v1", "t2": "v2" }"#).unwrap();
+        assert_eq!(result, Ok(exp));
+    }
+
+    fn render_help(form: &FormView) -> String {
+        let mut help = Vec::new();
+        clap::App::new("app")
+            .args(&form.fields2clap_args())
+            .write_help(&mut help)
+            .unwrap();
+        String::from_utf8(help).unwrap()
+    }
+
+    #[test]
+    fn clap_args_use_fields_help() {
+        let form = FormView::new()
+            .field(Text::new("t").help("help for t"))
+            .field(Checkbox::new("ch").help("help for ch"))
+            .field(Autocomplete::new("ac", vec!["a"]).help("help for ac"))
+            .field(Multiselect::new("ms", vec!["a"]).help("help for ms"));
+        let args = form.fields2clap_args();
+        let helps = args.iter().map(|a| a.b.help).collect::<Vec<Option<&str>>>();
+        assert_eq!(
+            helps,
+            vec![
+                Some("help for t"),
+                Some("help for ch"),
+                Some("Opposite of --ch"),
+                Some("help for ac"),
+                Some("help for ms"),
+            ]
+        );
+        let help = render_help(&form);
+        assert!(
+            help.lines()
+                .any(|l| l.contains("--t <t>") & l.contains("help for t"))
+        );
+        assert!(
+            help.lines()
+                .any(|l| l.contains("--ch") & l.contains("help for ch"))
+        );
+    }
+
+    #[test]
+    fn clap_args_keep_multiline_help() {
+        let form = FormView::new().field(Text::new("t").help("first line\nsecond line"));
+        let help = render_help(&form);
+        assert!(help.contains("--t <t>\n            first line\n            second line"));
+    }
+
+    fn get_label_style(form: &FormView, idx: usize) -> Option<Style> {
+        let boxed_widget = get_widget(get_fields_layout(&form.view), &form.positions[idx])
+            .as_any()
+            .downcast_ref::<Box<AnyView>>()
+            .unwrap();
+        let label = (**boxed_widget)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap()
+            .get_child(0)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<TextView>()
+            .unwrap();
+        let content = label.get_content();
+        let style = content.spans().first().map(|s| *s.attr);
+        style
+    }
+
+    #[test]
+    fn failed_submit_colors_fields_by_state() {
+        let mut form = FormView::new()
+            .field(Text::new("t1").validator(Required))
+            .field(Text::new("t2").validator(Required))
+            .with_values(&[("t2", "v2")]);
+        assert_eq!(get_label_style(&form, 0), Some(Style::none()));
+
+        form.event_submit();
+
+        let red = Style::from(Color::Dark(BaseColor::Red));
+        let green = Style::from(Color::Dark(BaseColor::Green));
+        assert_eq!(get_label_style(&form, 0), Some(red));
+        assert_eq!(get_label_style(&form, 1), Some(green));
+
+        form.reset();
+        assert_eq!(get_label_style(&form, 0), Some(Style::none()));
+    }
+
+    #[test]
+    fn state_colors_can_be_disabled() {
+        let mut form = FormView::new()
+            .field(Text::new("t1").validator(Required))
+            .disable_state_colors();
+
+        form.event_submit();
+
+        assert_eq!(get_label_style(&form, 0), Some(Style::none()));
+    }
+
+    #[test]
+    fn failed_submit_focuses_first_invalid_field() {
+        let mut form = FormView::new()
+            .field(Text::new("t1"))
+            .field(Text::new("t2").validator(Required))
+            .field(Text::new("t3").validator(Required));
+        form.take_focus(Direction::front());
+        form.on_event(Event::Key(Key::Tab));
+        form.on_event(Event::Key(Key::Tab));
+        assert_eq!(form.get_focused_field(), Some(2));
+
+        form.event_submit();
+        assert_eq!(form.get_focused_field(), Some(1));
+
+        // from buttons too
+        form.on_event(Event::Key(Key::Tab));
+        form.on_event(Event::Key(Key::Tab));
+        assert_eq!(form.get_focused_field(), None);
+        form.event_submit();
+        assert_eq!(form.get_focused_field(), Some(1));
+    }
+
+    #[test]
+    fn focus_is_preserved_by_failed_submit_without_invalid_fields() {
+        let mut form = FormView::new()
+            .field(Text::new("t1"))
+            .field(Text::new("t2"))
+            .form_validator(|_| Err(FormError::Global("Always wrong".to_string())));
+        form.take_focus(Direction::front());
+        form.on_event(Event::Key(Key::Tab));
+        assert_eq!(form.get_focused_field(), Some(1));
+
+        form.event_submit();
+
+        assert_eq!(form.get_focused_field(), Some(1));
+    }
+
+    #[test]
+    fn focus_field_works_with_rows() {
+        let mut form = FormView::new()
+            .field(Text::new("t1"))
+            .row(vec![Box::new(Text::new("t2")), Box::new(Text::new("t3"))])
+            .field(Text::new("t4"));
+
+        form.focus_field(2);
+        assert_eq!(form.get_focused_field(), Some(2));
+        form.focus_field(3);
+        assert_eq!(form.get_focused_field(), Some(3));
+        form.focus_field(0);
+        assert_eq!(form.get_focused_field(), Some(0));
+    }
+
+    #[test]
+    fn keybindings_help_includes_focused_field_bindings() {
+        let mut form = FormView::new()
+            .field(Text::new("t"))
+            .field(Autocomplete::new("ac", vec!["a"]));
+
+        form.focus_field(0);
+        let help = form.keybindings_help();
+        assert!(help.contains("submit form"));
+        assert!(!help.contains("Ctrl+u"));
+
+        form.focus_field(1);
+        let help = form.keybindings_help();
+        assert!(help.contains("submit form"));
+        assert!(help.contains("Ctrl+u"));
+    }
+
+    #[test]
+    fn history_fills_focused_field() {
+        let mut form = FormView::new()
+            .field(Text::new("host"))
+            .field(Password::new("pass"))
+            .history("host", vec![Value::from("b.org"), Value::from("a.org")]);
+        assert_eq!(form.secret_labels(), vec!["pass"]);
+        form.focus_field(0);
+
+        form.on_event(Event::Alt(Key::Down));
+        assert_eq!(form.get_values()["host"], "");
+        form.on_event(Event::Alt(Key::Up));
+        assert_eq!(form.get_values()["host"], "b.org");
+        form.on_event(Event::Alt(Key::Up));
+        form.on_event(Event::Alt(Key::Up));
+        assert_eq!(form.get_values()["host"], "a.org");
+        form.on_event(Event::Alt(Key::Down));
+        assert_eq!(form.get_values()["host"], "b.org");
+
+        form.focus_field(1);
+        form.on_event(Event::Alt(Key::Up));
+        assert_eq!(form.get_values()["pass"], "");
+    }
+
+    #[test]
+    fn draft_keeps_unfinished_values() {
+        let form = FormView::new()
+            .field(Text::new("host").validator(Required))
+            .field(Number::integer("port").validator(Required))
+            .field(Password::new("pass"))
+            .with_values(&[("host", "a.org"), ("port", "80x"), ("pass", "secret")]);
+        let draft = form.draft();
+        assert_eq!(draft, json!({"host": "a.org", "port": "80x"}));
+
+        let mut form = FormView::new()
+            .field(Text::new("host"))
+            .field(Number::integer("port"));
+        form.set_draft(&json!({"port": "80x", "gone": "x"}));
+        assert_eq!(form.get_values()["host"], "");
+        assert_eq!(form.get_values()["port"], "80x");
+    }
+
+    #[test]
+    fn defaults_replace_initial_values() {
+        let mut form = FormView::new()
+            .field(Text::new("host").initial("localhost").validator(Required))
+            .field(Number::integer("port").initial(22.0));
+        form.set_defaults(&json!({"host": "a.org", "port": 80})).unwrap();
+        assert_eq!(form.get_values()["host"], "a.org");
+        form.set_field_value("host", "b.org").unwrap();
+        form.reset();
+        assert_eq!(form.get_values()["host"], "a.org");
+
+        let app = clap::App::new("app").args(&form.fields2clap_args());
+        let matches = app.clone().get_matches_from(vec!["app"]);
+        assert_eq!(form.clap_arg_matches2value(&matches), json!({"host": "a.org", "port": 80}));
+        let matches = app.get_matches_from(vec!["app", "--port", "8080"]);
+        assert_eq!(
+            form.clap_arg_matches2value(&matches),
+            json!({"host": "a.org", "port": 8080})
+        );
+    }
+
+    #[test]
+    fn reset_clears_errors() {
+        let mut form = FormView::new().field(Text::new("t").validator(Required));
+        form.event_submit();
+
+        form.reset();
+
+        let boxed_widget = get_widget(get_fields_layout(&form.view), &form.positions[0])
+            .as_any()
+            .downcast_ref::<Box<AnyView>>()
+            .unwrap();
+        let view = (**boxed_widget)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap()
+            .get_child(2)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<TextView>()
+            .unwrap();
+        assert_eq!(view.get_content().source(), "");
+    }
+
+    #[test]
+    fn placeholder_is_not_a_value() {
+        let form = FormView::new()
+            .field(Text::new("t").placeholder("e.g. archive.tar.gz"))
+            .field(Autocomplete::new("ac", vec!["a", "b"]).placeholder("e.g. a"))
+            .field(Password::new("p").placeholder("secret"))
+            .field(FilePicker::new("f").placeholder("e.g. /tmp/archive.tar.gz"))
+            .field(Duration::new("d").placeholder("e.g. 5m"));
+
+        let exp: Value =
+            serde_json::from_str(r#"{ "t": "", "ac": "", "p": "", "f": "", "d": null }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    /// Composite field which turns "from..to" into keys `from` & `to`.
+    struct Range(Box<FormField>);
+
+    impl FormField for Range {
+        fn build_widget(&self) -> Box<AnyView> {
+            self.0.build_widget()
+        }
+        fn validate(&self, data: &str) -> Result<Value, String> {
+            let bounds = data.split("..").collect::<Vec<&str>>();
+            if bounds.len() != 2 {
+                return Err("Expected: from..to".to_string());
+            }
+            let mut value = Map::new();
+            value.insert("from".to_string(), Value::String(bounds[0].to_string()));
+            value.insert("to".to_string(), Value::String(bounds[1].to_string()));
+            Ok(Value::Object(value))
+        }
+        fn get_label(&self) -> &str {
+            self.0.get_label()
+        }
+        fn get_initial(&self) -> String {
+            self.0.get_initial()
+        }
+        fn get_width(&self) -> Option<usize> {
+            self.0.get_width()
+        }
+        fn get_widget_manager(&self) -> &WidgetManager {
+            self.0.get_widget_manager()
+        }
+        fn clap_arg(&self) -> clap::Arg<'_, '_> {
+            self.0.clap_arg()
+        }
+        fn clap_args2str(&self, args: &clap::ArgMatches) -> String {
+            self.0.clap_args2str(args)
+        }
+        fn contribute(&self, value: Value) -> Map<String, Value> {
+            match value {
+                Value::Object(map) => map,
+                _ => Map::new(),
+            }
+        }
+    }
+
+    #[test]
+    fn composite_field_contributes_many_keys() {
+        let form = FormView::new()
+            .field(Text::new("t"))
+            .field(Range(Box::new(Text::new("range"))))
+            .with_values(&[("t", "x"), ("range", "1..5")]);
+
+        let exp: Value = serde_json::from_str(r#"{ "t": "x", "from": "1", "to": "5" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    #[test]
+    fn composite_field_contributes_many_keys_from_cli() {
+        let form = FormView::new().field(Range(Box::new(Text::new("range"))));
+        let args = form.fields2clap_args();
+        let matches = clap::App::new("app")
+            .args(args.as_slice())
+            .get_matches_from(vec!["app", "--range", "1..5"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "from": "1", "to": "5" }"#).unwrap();
+        assert_eq!(form.clap_arg_matches2value(&matches), exp);
+    }
+
+    #[test]
+    fn composite_field_errors_use_its_label() {
+        let form = FormView::new()
+            .field(Range(Box::new(Text::new("range"))))
+            .with_values(&[("range", "1")]);
+
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(errors.get("range").map(|x| x.as_ref()), Some("Expected: from..to"));
+    }
+
+    #[test]
+    fn schema_describes_fields() {
+        use validators::{Between, MinLen};
+
+        let form = FormView::new()
+            .field(Text::new("name").validator(Required).validator(MinLen(3)))
+            .field(Number::integer("level").min(1).validator(Between(1, 9)))
+            .field(Select::new("format", vec![("Tar", "tar"), ("Zip", "zip")]))
+            .field(Tags::new("tags").validator(MinLen(2)))
+            .field(Text::new("comment").validator(Required).visible_when("format", |v| v == "zip"))
+            .field(Static::new("note", "Nothing to fill"))
+            .field(Hidden::new("version", 2));
+
+        let schema = form.schema();
+        assert_eq!(
+            schema["properties"],
+            json!({
+                "name": {"type": "string", "minLength": 3},
+                "level": {"type": ["integer", "null"], "minimum": 1.0, "maximum": 9.0},
+                "format": {"type": "string", "enum": ["tar", "zip"]},
+                "tags": {"type": "array", "items": {"type": "string", "minLength": 2}},
+                "comment": {"type": "string"},
+                "version": {"const": 2},
+            })
+        );
+        assert_eq!(schema["required"], json!(["name"]));
+    }
+
+    #[test]
+    fn resize_rewraps_errors() {
+        use cursive::vec::Vec2;
+
+        let mut form = FormView::new()
+            .field(Text::new("t").width(50).validator(Required))
+            .field(Text::new("u").help("long help which is wrapped when terminal is narrowed"));
+        form.event_submit();
+        let wide = Vec2::new(100, 100);
+        let narrow = Vec2::new(20, 100);
+
+        let wide_size = form.required_size(wide);
+        form.layout(wide_size);
+        let narrow_size = form.required_size(narrow);
+        form.layout(narrow_size);
+        assert!(narrow_size.x <= wide_size.x);
+        assert!(narrow_size.y > wide_size.y);
+
+        form.on_event(Event::WindowResize);
+        let widened_size = form.required_size(wide);
+        assert_eq!(widened_size, wide_size);
+    }
+
+    #[test]
+    fn buttons_added_to_dialog_keep_submit_working() {
+        let mut form = FormView::new()
+            .field(Text::new("t").validator(Required))
+            .with_dialog(|d| {
+                let dialog = ::std::mem::replace(d, Dialog::new());
+                *d = dialog.button("Extra", |_| {});
+            });
+        let get_error = |form: &FormView| {
+            let boxed_widget = get_widget(get_fields_layout(&form.view), &form.positions[0])
+                .as_any()
+                .downcast_ref::<Box<AnyView>>()
+                .unwrap();
+            let view = (**boxed_widget)
+                .as_any()
+                .downcast_ref::<LinearLayout>()
+                .unwrap()
+                .get_child(2)
+                .unwrap()
+                .as_any()
+                .downcast_ref::<TextView>()
+                .unwrap();
+            view.get_content().source().to_owned()
+        };
+
+        form.take_focus(Direction::front());
+        form.on_event(Event::Key(Key::Tab));
+        form.on_event(Event::Key(Key::Right));
+        form.on_event(Event::Key(Key::Right));
+        form.on_event(Event::Key(Key::Right));
+        assert_eq!(form.dialog().focus(), DialogFocus::Button(3));
+        form.on_event(Event::Key(Key::Enter));
+        assert_eq!(get_error(&form), "");
+
+        form.on_event(Event::Key(Key::Left));
+        assert_eq!(form.dialog().focus(), DialogFocus::Button(SUBMIT_BTN));
+        form.on_event(Event::Key(Key::Enter));
+        assert_ne!(get_error(&form), "");
+    }
+
+    #[test]
+    fn password_confirmation_is_validated() {
+        use validators::SameAs;
+
+        let form = FormView::new()
+            .field(Text::new("password"))
+            .field(Text::new("confirm").validator(SameAs("password")));
+
+        let form = form.with_values(&[("password", "secret"), ("confirm", "secret")]);
+        assert!(form.validate_all().is_ok());
+
+        let form = form.with_values(&[("confirm", "typo")]);
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(
+            errors.get("confirm").map(|x| x.as_ref()),
+            Some("Value must be the same as \"password\"")
+        );
+    }
+
+    #[test]
+    fn add_field_works_like_field() {
+        let mut form = FormView::new().field(Text::new("a"));
+        for label in &["b", "c"] {
+            form.add_field(Text::new(*label).initial(*label));
+        }
+
+        assert_eq!(form.positions, vec![vec![0], vec![1], vec![2]]);
+        let exp: Value = serde_json::from_str(r#"{ "a": "", "b": "b", "c": "c" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    #[test]
+    fn number_fields_emit_numbers() {
+        let form = FormView::new()
+            .field(Number::integer("i"))
+            .field(Number::float("f"))
+            .field(Number::integer("empty"))
+            .with_values(&[("i", "3"), ("f", "2.5")]);
+
+        let exp: Value = serde_json::from_str(r#"{ "i": 3, "f": 2.5, "empty": null }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    #[test]
+    fn number_fields_check_type_and_range() {
+        let form = FormView::new()
+            .field(Number::integer("i").min(1).max(5))
+            .field(Number::integer("j"))
+            .with_values(&[("i", "7"), ("j", "2.5")]);
+
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(errors.get("i").map(|x| x.as_ref()), Some("Value must be at most 5"));
+        assert_eq!(errors.get("j").map(|x| x.as_ref()), Some("Value must be an integer"));
+    }
+
+    #[test]
+    fn number_is_changed_with_arrows_in_range() {
+        let mut form = FormView::new().field(Number::integer("i").initial(4).max(5));
+        form.take_focus(Direction::front());
+
+        form.on_event(Event::Key(Key::Up));
+        form.on_event(Event::Key(Key::Up));
+        form.on_event(Event::Char('x'));
+        let exp: Value = serde_json::from_str(r#"{ "i": 5 }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.on_event(Event::Key(Key::Down));
+        let exp: Value = serde_json::from_str(r#"{ "i": 4 }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    #[test]
+    fn multiline_keeps_newlines() {
+        let mut form = FormView::new()
+            .field(Multiline::new("m").initial("first\nsecond").validator(Required));
+
+        let exp: Value = serde_json::from_str(r#"{ "m": "first\nsecond" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.set_field_value("m", "").unwrap();
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(errors.get("m").map(|x| x.as_ref()), Some("Field is required"));
+    }
+
+    #[test]
+    fn select_emits_option_value() {
+        let options = vec![("Fast", "fast"), ("Best", "best")];
+        let mut form = FormView::new()
+            .field(Select::new("s1", options.clone()))
+            .field(Select::new("s2", options).initial("best"));
+
+        let exp: Value = serde_json::from_str(r#"{ "s1": "fast", "s2": "best" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.set_field_value("s1", "best").unwrap();
+        let exp: Value = serde_json::from_str(r#"{ "s1": "best", "s2": "best" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    #[test]
+    fn radio_group_emits_option_value() {
+        let options = vec![("JSON", "json"), ("YAML", "yaml"), ("TOML", "toml")];
+        let mut form = FormView::new()
+            .field(RadioGroup::new("r1", options.clone()))
+            .field(RadioGroup::new("r2", options).initial("toml"));
+
+        let exp: Value = serde_json::from_str(r#"{ "r1": "json", "r2": "toml" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.set_field_value("r1", "yaml").unwrap();
+        let exp: Value = serde_json::from_str(r#"{ "r1": "yaml", "r2": "toml" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    #[test]
+    fn date_is_typed_and_validated() {
+        let mut form = FormView::new().field(DatePicker::new("d"));
+        form.take_focus(Direction::front());
+
+        for c in "2020x0228".chars() {
+            form.on_event(Event::Char(c));
+        }
+        form.on_event(Event::Key(Key::Up));
+        let exp: Value = serde_json::from_str(r#"{ "d": "2020-02-29" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.set_field_value("d", "2019-02-29").unwrap();
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(
+            errors.get("d").map(|x| x.as_ref()),
+            Some("Value must be a date (YYYY-MM-DD)")
+        );
+    }
+
+    #[test]
+    fn time_is_entered_with_spinners() {
+        let mut form = FormView::new().field(Time::new("t"));
+        form.take_focus(Direction::front());
+
+        form.on_event(Event::Char('9'));
+        form.on_event(Event::Key(Key::Tab));
+        // empty minute starts from 0
+        form.on_event(Event::Key(Key::Up));
+        form.on_event(Event::Key(Key::Up));
+        let exp: Value = serde_json::from_str(r#"{ "t": "09:01" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    #[test]
+    fn times_get_timezone() {
+        let mut form = FormView::new()
+            .field(Time::new("t").initial("12:30").timezone(Timezone::Utc))
+            .field(
+                DateTime::new("dt")
+                    .initial("2018-03-21T12:30")
+                    .timezone(Timezone::Offset(-90)),
+            );
+
+        let exp: Value = serde_json::from_str(
+            r#"{ "t": "12:30Z", "dt": "2018-03-21T12:30-01:30" }"#,
+        ).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.set_field_value("dt", "2018-02-30T12:30").unwrap();
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(
+            errors.get("dt").map(|x| x.as_ref()),
+            Some("Value must be in YYYY-MM-DDTHH:MM format")
+        );
+    }
+
+    #[test]
+    fn slider_is_moved_in_range() {
+        let mut form = FormView::new()
+            .field(Slider::new("level", 1, 9, 1).initial(8))
+            .field(Slider::new("ratio", 0, 1, 0.1));
+        form.take_focus(Direction::front());
+
+        form.on_event(Event::Key(Key::Right));
+        form.on_event(Event::Key(Key::Right));
+        let exp: Value = serde_json::from_str(r#"{ "level": 9, "ratio": 0.0 }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.set_field_value("ratio", "0.3").unwrap();
+        let exp: Value = serde_json::from_str(r#"{ "level": 9, "ratio": 0.3 }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    #[test]
+    fn file_picker_checks_extensions() {
+        let mut form = FormView::new()
+            .field(FilePicker::new("f").extensions(vec!["tar", "tar.gz"]))
+            .field(FilePicker::dirs("d").initial("src"));
+
+        form.set_field_value("f", "backup.TAR.GZ").unwrap();
+        let exp: Value = serde_json::from_str(r#"{ "f": "backup.TAR.GZ", "d": "src" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.set_field_value("f", "backup.zip").unwrap();
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(
+            errors.get("f").map(|x| x.as_ref()),
+            Some("File must have extension: tar, tar.gz")
+        );
+    }
+
+    #[test]
+    fn tags_are_typed_and_removed() {
+        let mut form = FormView::new().field(Tags::new("hosts").initial(vec!["a"]));
+        form.take_focus(Direction::front());
+
+        for c in "b,c,b".chars() {
+            form.on_event(Event::Char(c));
+        }
+        form.on_event(Event::Key(Key::Enter));
+        let exp: Value = serde_json::from_str(r#"{ "hosts": ["a", "b", "c"] }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.on_event(Event::Key(Key::Backspace));
+        form.on_event(Event::Char('d'));
+        let exp: Value = serde_json::from_str(r#"{ "hosts": ["a", "b", "d"] }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    #[test]
+    fn required_tags_need_one_tag() {
+        let form = FormView::new().field(Tags::new("hosts").validator(Required));
+
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(errors.get("hosts").map(|x| x.as_ref()), Some("Field is required"));
+    }
+
+    #[test]
+    fn key_values_are_added_and_removed() {
+        let mut form = FormView::new().field(KeyValue::new("env").initial(vec![("A", "1")]));
+        form.take_focus(Direction::front());
+
+        form.on_event(Event::Key(Key::Del));
+        form.on_event(Event::Key(Key::Down));
+        for c in "B".chars() {
+            form.on_event(Event::Char(c));
+        }
+        form.on_event(Event::Key(Key::Tab));
+        for c in "x=y".chars() {
+            form.on_event(Event::Char(c));
+        }
+        form.on_event(Event::Key(Key::Enter));
+        let exp: Value = serde_json::from_str(r#"{ "env": { "B": "x=y" } }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        assert_eq!(
+            KeyValue::new("env").validate("A=1\nbroken"),
+            Err("Value must be KEY=VALUE: broken".to_string())
+        );
+    }
+
+    #[test]
+    fn durations_are_emitted_as_seconds_or_text() {
+        let mut form = FormView::new()
+            .field(Duration::new("timeout").initial("1h30m"))
+            .field(Duration::new("interval").initial("90m").normalized());
+
+        let exp: Value =
+            serde_json::from_str(r#"{ "timeout": 5400, "interval": "1h30m" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.set_field_value("timeout", "1y").unwrap();
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(
+            errors.get("timeout").map(|x| x.as_ref()),
+            Some("Value must be a duration (e.g. 1h30m)")
+        );
+    }
+
+    #[test]
+    fn hidden_field_adds_constant_without_cli_arg() {
+        let mut form = FormView::new()
+            .field(Hidden::new("api-version", 2))
+            .field(Text::new("name").initial("n"));
+        form.take_focus(Direction::front());
+        form.reset();
+
+        let exp: Value = serde_json::from_str(r#"{ "api-version": 2, "name": "n" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+        assert_eq!(form.get_focused_field(), Some(1));
+        assert_eq!(form.fields2clap_args().len(), 1);
+    }
+
+    #[test]
+    fn static_field_is_not_a_value() {
+        let mut form = FormView::new()
+            .field(Static::new("target", "/tmp/a.tar"))
+            .field(Text::new("name").initial("n"));
+
+        form.set_field_value("target", "/tmp/b.tar").unwrap();
+        let exp: Value = serde_json::from_str(r#"{ "name": "n" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+        assert_eq!(form.fields2clap_args().len(), 1);
+    }
+
+    #[test]
+    fn repeated_rows_are_added_removed_and_validated() {
+        let mut form =
+            FormView::new().field(Repeated::new(Number::integer("n")).initial(vec!["1"]));
+        form.take_focus(Direction::front());
+
+        form.on_event(Event::Key(Key::Ins));
+        form.on_event(Event::Key(Key::Ins));
+        form.on_event(Event::Key(Key::Tab));
+        form.on_event(Event::Char('2'));
+        let exp: Value = serde_json::from_str(r#"{ "n": [1, 2] }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        // removes focused (the second) row
+        form.on_event(Event::CtrlChar('d'));
+        let exp: Value = serde_json::from_str(r#"{ "n": [1] }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.set_field_value("n", r#"["1", "x"]"#).unwrap();
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(errors.get("n").map(|x| x.as_ref()), Some("#2: Value must be an integer"));
+    }
+
+    #[test]
+    fn repeated_schema_and_env_follow_repeated_field() {
+        ::std::env::set_var("FUI_TEST_REPEATED", "80, 443");
+        let form = FormView::new()
+            .field(Repeated::new(Number::integer("port")).default_from_env("FUI_TEST_REPEATED"));
+        assert_eq!(
+            form.schema()["properties"]["port"],
+            json!({"type": "array", "items": {"type": ["integer", "null"]}})
+        );
+        assert_eq!(form.validate_all(), Ok(json!({"port": [80, 443]})));
+    }
+
+    #[test]
+    fn sections_are_flat_or_nested() {
+        let build = || {
+            FormView::new()
+                .section(
+                    "connection",
+                    vec![
+                        Box::new(Text::new("host").initial("localhost")),
+                        Box::new(Number::integer("port").initial(80)),
+                    ],
+                )
+                .field(Text::new("query").initial("q"))
+        };
+        let mut form = build();
+        form.take_focus(Direction::front());
+        form.on_event(Event::Key(Key::Tab));
+        assert_eq!(form.get_focused_field(), Some(1));
+
+        let exp: Value =
+            serde_json::from_str(r#"{ "host": "localhost", "port": 80, "query": "q" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        let exp: Value = serde_json::from_str(
+            r#"{ "connection": { "host": "localhost", "port": 80 }, "query": "q" }"#,
+        ).unwrap();
+        assert_eq!(build().nest_sections().validate_all(), Ok(exp));
+    }
+
+    #[test]
+    fn conditional_fields_follow_other_fields() {
+        let mut form = FormView::new()
+            .field(Text::new("format"))
+            .field(
+                Number::integer("level")
+                    .validator(Required)
+                    .visible_when("format", |v| v == "gzip"),
+            )
+            .field(Text::new("path").initial("p"));
+        form.take_focus(Direction::front());
+        let exp: Value = serde_json::from_str(r#"{ "format": "", "path": "p" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        for c in "gzip".chars() {
+            form.on_event(Event::Char(c));
+        }
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(errors.get("level").map(|x| x.as_ref()), Some("Field is required"));
+        form.on_event(Event::Key(Key::Tab));
+        assert_eq!(form.get_focused_field(), Some(1));
+
+        form.set_field_value("format", "tar").unwrap();
+        let exp: Value = serde_json::from_str(r#"{ "format": "tar", "path": "p" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+    }
+
+    fn get_form_error(form: &FormView) -> String {
+        form.view
+            .get_content()
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap()
+            .get_child(FORM_ERROR_IDX)
+            .and_then(|c| c.as_any().downcast_ref::<LinearLayout>())
+            .and_then(|l| l.get_child(0))
+            .and_then(|c| c.as_any().downcast_ref::<TextView>())
+            .unwrap()
+            .get_content()
+            .source()
+            .to_owned()
+    }
+
+    #[test]
+    fn form_validators_report_field_and_global_errors() {
+        let mut form = FormView::new()
+            .field(Text::new("src-dir").initial("a"))
+            .field(Text::new("dst-dir").initial("a"))
+            .field(Text::new("file"))
+            .field(Checkbox::new("stdin"))
+            .form_validator(|data| {
+                if data["src-dir"] == data["dst-dir"] {
+                    let msg = "Value must differ from src-dir".to_string();
+                    return Err(FormError::Field("dst-dir".to_string(), msg));
+                }
+                Ok(())
+            })
+            .form_validator(|data| {
+                if data["file"] == "" && data["stdin"] == false {
+                    return Err(FormError::Global("Either file or stdin is required".into()));
+                }
+                Ok(())
+            });
+        form.take_focus(Direction::front());
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(
+            errors.get("dst-dir").map(|x| x.as_ref()),
+            Some("Value must differ from src-dir")
+        );
+        assert_eq!(
+            errors.get(FORM_ERROR_LABEL).map(|x| x.as_ref()),
+            Some("Either file or stdin is required")
+        );
+
+        form.on_event(Event::CtrlChar('f'));
+        assert_eq!(get_form_error(&form), "Either file or stdin is required");
+
+        form.set_field_value("dst-dir", "b").unwrap();
+        form.set_field_value("stdin", "true").unwrap();
+        form.on_event(Event::CtrlChar('f'));
+        assert_eq!(get_form_error(&form), "");
+        assert!(form.validate_all().is_ok());
+    }
+
+    #[test]
+    fn cli_skips_form_validators_when_fields_are_invalid() {
+        let form = FormView::new()
+            .field(Number::integer("n"))
+            .form_validator(|_| Err(FormError::Global("Always wrong".to_string())));
+        let app = || clap::App::new("my_app").args(form.fields2clap_args().as_slice());
+
+        let matches = app().get_matches_from(vec!["my_app", "--n", "abc"]);
+        let errors = form.validate_clap_arg_matches(&matches).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(!errors.contains(&"Always wrong".to_string()));
+
+        let matches = app().get_matches_from(vec!["my_app", "--n", "3"]);
+        let errors = form.validate_clap_arg_matches(&matches).unwrap_err();
+        assert_eq!(errors, vec!["Always wrong".to_string()]);
+    }
+
+    fn get_error(form: &FormView, idx: usize) -> String {
+        let boxed_widget = get_widget(get_fields_layout(&form.view), &form.positions[idx])
+            .as_any()
+            .downcast_ref::<Box<AnyView>>()
+            .unwrap();
+        (**boxed_widget)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap()
+            .get_child(2)
+            .and_then(|c| c.as_any().downcast_ref::<TextView>())
+            .unwrap()
+            .get_content()
+            .source()
+            .to_owned()
+    }
+
+    fn wait_for_async_validators(form: &mut FormView) {
+        while form.async_pending.is_some() {
+            ::std::thread::sleep(::std::time::Duration::from_millis(10));
+            form.on_event(Event::Refresh);
+        }
+    }
+
+    #[test]
+    fn async_validators_run_in_background() {
+        let mut form = FormView::new()
+            .field(Text::new("host").initial("missing"))
+            .async_validator("host", |host: &str| {
+                ::std::thread::sleep(::std::time::Duration::from_millis(20));
+                if host == "localhost" {
+                    None
+                } else {
+                    Some("Host can't be resolved".to_string())
+                }
+            });
+        form.take_focus(Direction::front());
+
+        form.on_event(Event::CtrlChar('f'));
+        assert!(form.async_pending.is_some());
+        assert_eq!(get_error(&form, 0), Strings::default().checking);
+        wait_for_async_validators(&mut form);
+        assert_eq!(get_error(&form, 0), "Host can't be resolved");
+
+        // result is reused for unchanged value
+        form.on_event(Event::CtrlChar('f'));
+        assert!(form.async_pending.is_none());
+
+        form.set_field_value("host", "localhost").unwrap();
+        form.on_event(Event::CtrlChar('f'));
+        wait_for_async_validators(&mut form);
+        assert_eq!(get_error(&form, 0), "");
+    }
+
+    #[test]
+    fn warnings_are_shown_without_blocking() {
+        let mut form = FormView::new()
+            .field(Text::new("output").initial("exists"))
+            .warning("output", |v: &str| {
+                if v == "exists" {
+                    Err("File will be overwritten".to_string())
+                } else {
+                    Ok(())
+                }
+            });
+        form.take_focus(Direction::front());
+        let exp: Value = serde_json::from_str(r#"{ "output": "exists" }"#).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+
+        form.on_event(Event::CtrlChar('f'));
+        assert_eq!(get_error(&form, 0), "File will be overwritten");
+        assert_eq!(form.collect_warnings(), vec![(0, "File will be overwritten".to_string())]);
+
+        form.set_field_value("output", "new").unwrap();
+        form.on_event(Event::CtrlChar('f'));
+        assert_eq!(get_error(&form, 0), "");
+    }
+
+    #[test]
+    fn error_summary_lists_errors_and_jumps_to_fields() {
+        let mut form = FormView::new()
+            .field(Text::new("t1").validator(Required))
+            .field(Text::new("t2"))
+            .field(Text::new("t3").validator(Required));
+        form.take_focus(Direction::front());
+        form.event_submit();
+        assert_eq!(form.get_focused_field(), Some(0));
+        {
+            let list = get_content_child_mut(&mut form.view, SUMMARY_IDX)
+                .get_child(1)
+                .and_then(|c| c.as_any().downcast_ref::<ErrorSummary>())
+                .map(|summary| &summary.view)
+                .unwrap();
+            assert_eq!(list.len(), 2);
+            assert_eq!(list.get_item(1).map(|x| x.0), Some("t3: Field is required"));
+        }
+
+        // summary is above the first field
+        form.on_event(Event::Shift(Key::Tab));
+        assert_eq!(form.get_focused_field(), None);
+        form.on_event(Event::Key(Key::Down));
+        form.on_event(Event::Key(Key::Enter));
+        assert_eq!(form.get_focused_field(), Some(2));
+
+        form.reset();
+        let list = get_content_child_mut(&mut form.view, SUMMARY_IDX)
+            .get_child(1)
+            .and_then(|c| c.as_any().downcast_ref::<ErrorSummary>())
+            .map(|summary| &summary.view)
+            .unwrap();
+        assert!(list.is_empty());
+    }
+
+    fn get_label_text(form: &FormView, idx: usize) -> String {
+        let boxed_widget = get_widget(get_fields_layout(&form.view), &form.positions[idx])
+            .as_any()
+            .downcast_ref::<Box<AnyView>>()
+            .unwrap();
+        (**boxed_widget)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap()
+            .get_child(0)
+            .and_then(|c| c.as_any().downcast_ref::<TextView>())
+            .unwrap()
+            .get_content()
+            .source()
+            .to_owned()
+    }
+
+    #[test]
+    fn required_fields_are_marked() {
+        let form = FormView::new()
+            .field(Text::new("name").validator(Required).help("your name"))
+            .field(Text::new("nick").help("your nick"))
+            .field(Number::integer("age").validator(Required).visible_when("name", |_| true))
+            .field(Hidden::new("version", 1));
+        assert_eq!(get_label_text(&form, 0), format!("{:20}: your name", "name*"));
+        assert_eq!(get_label_text(&form, 1), format!("{:20}: your nick", "nick"));
+        assert_eq!(get_label_text(&form, 2), format!("{:20}", "age*"));
+    }
+
+    #[test]
+    fn defaults_are_taken_from_env() {
+        ::std::env::set_var("FUI_TEST_TARGET", "/tmp/out.tar");
+        ::std::env::set_var("FUI_TEST_VERBOSE", "true");
+        let form = FormView::new()
+            .field(Text::new("target").validator(Required).default_from_env("FUI_TEST_TARGET"))
+            .field(Checkbox::new("verbose").default_from_env("FUI_TEST_VERBOSE"))
+            .field(Number::integer("level").initial(1).default_from_env("FUI_TEST_MISSING"));
+
+        let exp: Value = ::serde_json::from_str(
+            r#"{ "target": "/tmp/out.tar", "verbose": true, "level": 1 }"#,
+        ).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp.clone()));
+
+        let args = form.fields2clap_args();
+        let matches = clap::App::new("app")
+            .args(args.as_slice())
+            .get_matches_from_safe(vec!["app"])
+            .unwrap();
+        assert_eq!(form.clap_arg_matches2value(&matches), exp);
+    }
+
+    #[test]
+    fn checkbox_ignores_env_value_which_is_not_bool() {
+        ::std::env::set_var("FUI_TEST_CHECKBOX_YES", "yes");
+        let field = Checkbox::new("verbose")
+            .initial(true)
+            .default_from_env("FUI_TEST_CHECKBOX_YES");
+        let mut form = FormView::new().field(field);
+        form.take_focus(Direction::front());
+        assert_eq!(form.validate_all(), Ok(json!({"verbose": true})));
+
+        let args = form.fields2clap_args();
+        let matches = clap::App::new("app")
+            .args(args.as_slice())
+            .get_matches_from_safe(vec!["app"])
+            .unwrap();
+        assert_eq!(form.clap_arg_matches2value(&matches), json!({"verbose": true}));
+    }
+
+    #[test]
+    fn set_data_fills_fields_by_label() {
+        let mut form = FormView::new()
+            .field(Text::new("name").initial("initial"))
+            .field(Number::integer("level"))
+            .field(Checkbox::new("verbose"))
+            .field(Multiselect::new("formats", vec!["tar", "zip", "7z"]))
+            .field(KeyValue::new("env"))
+            .field(Repeated::new(Number::integer("port")))
+            .field(Text::new("untouched").initial("kept"));
+        let data: Value = ::serde_json::from_str(
+            r#"{
+                "name": "John", "level": 3, "verbose": true, "formats": ["tar", "7z"],
+                "env": {"A": "1"}, "port": [80, 443], "unknown": "ignored"
+            }"#,
+        ).unwrap();
+
+        form.set_data(&data).unwrap();
+
+        let exp: Value = ::serde_json::from_str(
+            r#"{
+                "name": "John", "level": 3, "verbose": true, "formats": ["tar", "7z"],
+                "env": {"A": "1"}, "port": [80, 443], "untouched": "kept"
+            }"#,
+        ).unwrap();
+        assert_eq!(form.validate_all(), Ok(exp));
+        assert!(form.set_data(&Value::from("x")).is_err());
+    }
+
+    #[test]
+    fn data_includes_invalid_values() {
+        let form = FormView::new()
+            .field(Text::new("name").validator(Required))
+            .field(Number::integer("level"))
+            .field(Checkbox::new("verbose"))
+            .field(Text::new("extra").visible_when("verbose", |v| v == "true"))
+            .with_values(&[("level", "1.5")]);
+
+        let exp: Value =
+            ::serde_json::from_str(r#"{ "name": "", "level": "1.5", "verbose": false }"#).unwrap();
+        assert_eq!(form.data(), exp);
+        assert!(get_error(&form, 0).is_empty());
+    }
+
+    #[test]
+    fn reset_is_available_as_button_and_binding() {
+        let mut form = FormView::new()
+            .field(Text::new("name").initial("initial").validator(Required))
+            .with_values(&[("name", "")]);
+        let initial: Value = ::serde_json::from_str(r#"{ "name": "initial" }"#).unwrap();
+        form.take_focus(Direction::front());
+        form.on_event(Event::CtrlChar('f'));
+        assert_ne!(get_error(&form, 0), "");
+
+        form.on_event(Event::CtrlChar('r'));
+        assert_eq!(get_error(&form, 0), "");
+        assert_eq!(form.data(), initial);
+
+        form.set_field_value("name", "changed").unwrap();
+        form.on_event(Event::Key(Key::Tab));
+        form.on_event(Event::Key(Key::Right));
+        assert_eq!(form.dialog().focus(), DialogFocus::Button(RESET_BTN));
+        form.on_event(Event::Key(Key::Enter));
+        assert_eq!(form.data(), initial);
+    }
+
+    #[test]
+    fn custom_buttons_get_validated_data() {
+        let mut form = FormView::new()
+            .field(Text::new("name").validator(Required))
+            .button("Preview", |_, _| {});
+        form.take_focus(Direction::front());
+        form.on_event(Event::Key(Key::Tab));
+        for _ in 0..3 {
+            form.on_event(Event::Key(Key::Right));
+        }
+        assert_eq!(form.dialog().focus(), DialogFocus::Button(3));
+
+        match form.on_event(Event::Key(Key::Enter)) {
+            EventResult::Consumed(None) => (),
+            _ => panic!("callback shouldn't be called with invalid data"),
+        }
+        assert_ne!(get_error(&form, 0), "");
+
+        form.set_field_value("name", "John").unwrap();
+        form.on_event(Event::Key(Key::Tab));
+        for _ in 0..3 {
+            form.on_event(Event::Key(Key::Right));
+        }
+        match form.on_event(Event::Key(Key::Enter)) {
+            EventResult::Consumed(Some(_)) => (),
+            _ => panic!("callback should be called with valid data"),
+        }
+        assert_eq!(get_error(&form, 0), "");
+    }
+
+    #[test]
+    fn confirm_asks_before_submit() {
+        let data: Value =
+            ::serde_json::from_str(r#"{ "target": "a.tar", "level": 3, "empty": null }"#).unwrap();
+        assert_eq!(
+            fill_template("Create {target} ({level}){empty}? {missing}", &data),
+            "Create a.tar (3)? {missing}"
+        );
+
+        let mut form = FormView::new().field(Text::new("target"));
+        match form.on_event(Event::CtrlChar('f')) {
+            EventResult::Consumed(None) => (),
+            _ => panic!("nothing to call without on_submit"),
+        }
+        form = form.confirm("Create {target}?");
+        match form.on_event(Event::CtrlChar('f')) {
+            EventResult::Consumed(Some(_)) => (),
+            _ => panic!("confirmation should be shown"),
+        }
+    }
+
+    #[test]
+    fn long_form_scrolls_to_focused_field() {
+        use cursive::vec::Vec2;
+
+        let mut form = FormView::new();
+        for idx in 0..30 {
+            form.add_field(Text::new(format!("field{}", idx)));
+        }
+        let size = form.required_size(Vec2::new(80, 20));
+        assert!(size.y <= 20);
+        form.layout(size);
+        form.take_focus(Direction::front());
+        assert_eq!(get_fields_scroll(&form.view).first, 0);
+
+        for _ in 0..29 {
+            form.on_event(Event::Key(Key::Tab));
+        }
+        assert_eq!(form.get_focused_field(), Some(29));
+        let scroll = get_fields_scroll(&form.view);
+        assert!(scroll.first > 0);
+        assert!(scroll.heights[scroll.first..].iter().sum::<usize>() <= scroll.size.y);
+
+        form.focus_field(0);
+        assert_eq!(get_fields_scroll(&form.view).first, 0);
+    }
+
+    #[test]
+    fn columns_place_fields_side_by_side() {
+        use cursive::vec::Vec2;
+
+        let mut form = FormView::new()
+            .field(Text::new("title"))
+            .columns(2)
+            .field(Text::new("host").validator(Required))
+            .field(Text::new("port"))
+            .field(Text::new("user").validator(Required));
+        assert_eq!(form.positions, vec![vec![0], vec![1, 0], vec![1, 2], vec![2, 0]]);
+        let size = form.required_size(Vec2::new(80, 40));
+        form.layout(size);
+
+        form.take_focus(Direction::front());
+        form.on_event(Event::Key(Key::Tab));
+        assert_eq!(form.get_focused_field(), Some(1));
+        form.on_event(Event::Key(Key::Tab));
+        assert_eq!(form.get_focused_field(), Some(2));
+        form.on_event(Event::Key(Key::Tab));
+        assert_eq!(form.get_focused_field(), Some(3));
+
+        form.event_submit();
+        assert_ne!(get_error(&form, 1), "");
+        assert_eq!(get_error(&form, 2), "");
+        assert_ne!(get_error(&form, 3), "");
+    }
+
+    #[test]
+    fn tabs_are_validated_together() {
+        let mut form = FormView::new()
+            .field(Text::new("name"))
+            .tab("Basic", vec![Box::new(Text::new("host").validator(Required))])
+            .tab("Advanced", vec![Box::new(Text::new("port").validator(Required))])
+            .with_values(&[("host", "localhost")]);
+        let tab_bar = |form: &FormView| {
+            get_fields_layout(&form.view)
+                .get_child(1)
+                .and_then(|c| c.as_any().downcast_ref::<TextView>())
+                .unwrap()
+                .get_content()
+                .source()
+                .to_owned()
+        };
+        assert_eq!(tab_bar(&form), " Basic   Advanced ");
+        assert_eq!(form.active_tab, 0);
+
+        form.event_submit();
+        assert_eq!(form.active_tab, 1);
+        assert_eq!(form.get_focused_field(), Some(2));
+
+        form.on_event(Event::Ctrl(Key::PageUp));
+        assert_eq!(form.active_tab, 0);
+        assert_eq!(form.get_focused_field(), Some(1));
+        assert!(form.keybindings_help().contains("next tab"));
+    }
+
+    #[test]
+    fn keybindings_are_configurable() {
+        let keybindings = KeyBindings {
+            submit: vec![Event::Ctrl(Key::Enter)],
+            enter_submits: true,
+            ..KeyBindings::default()
+        };
+        let mut form = FormView::new()
+            .field(Text::new("name").validator(Required))
+            .keybindings(keybindings);
+        form.take_focus(Direction::front());
+
+        form.on_event(Event::CtrlChar('f'));
+        assert_eq!(get_error(&form, 0), "");
+        form.on_event(Event::Ctrl(Key::Enter));
+        assert_ne!(get_error(&form, 0), "");
+
+        form.reset();
+        form.on_event(Event::Key(Key::Enter));
+        assert_ne!(get_error(&form, 0), "");
+
+        let help = form.keybindings_help();
+        assert!(help.contains(&format!("{:20}submit form", "Ctrl+Enter")));
+        let mut labels = Vec::new();
+        form.with_dialog(|d| {
+            labels = d.buttons_mut().map(|b| b.label().to_owned()).collect();
+        });
+        assert_eq!(labels[SUBMIT_BTN], "<Submit (Ctrl+Enter)>");
+    }
+
+    fn get_help_panel(form: &FormView) -> String {
+        let content = form.view
+            .get_content()
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        content
+            .get_child(HELP_PANEL_IDX)
+            .and_then(|v| v.as_any().downcast_ref::<LinearLayout>())
+            .and_then(|l| l.get_child(0))
+            .and_then(|v| v.as_any().downcast_ref::<TextView>())
+            .map(|v| v.get_content().source().to_owned())
+            .unwrap()
+    }
+
+    #[test]
+    fn help_panel_shows_focused_field_help() {
+        let mut form = FormView::new()
+            .field(Text::new("t").help("first line\nsecond line"))
+            .field(Checkbox::new("ch"));
+        assert_eq!(get_label_text(&form, 0), format!("{:20}: first line ...", "t"));
+
+        form.focus_field(0);
+        form.on_event(Event::Key(Key::F1));
+        assert_eq!(get_help_panel(&form), "Help: t\nfirst line\nsecond line");
+
+        form.on_event(Event::Key(Key::Tab));
+        assert_eq!(get_help_panel(&form), "Help: ch\nNo help for this field.");
+
+        form.on_event(Event::Char('?'));
+        assert_eq!(get_help_panel(&form), "");
+
+        form.focus_field(0);
+        form.on_event(Event::Char('?'));
+        assert_eq!(get_help_panel(&form), "");
+        assert_eq!(form.validate_all().unwrap()["t"], "?");
+    }
+
+    fn get_error_style(form: &FormView, idx: usize) -> Option<Style> {
+        let boxed_widget = get_widget(get_fields_layout(&form.view), &form.positions[idx])
+            .as_any()
+            .downcast_ref::<Box<AnyView>>()
+            .unwrap();
+        let error = (**boxed_widget)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap()
+            .get_child(2)
+            .and_then(|c| c.as_any().downcast_ref::<TextView>())
+            .unwrap();
+        let content = error.get_content();
+        let style = content.spans().first().map(|s| *s.attr);
+        style
+    }
+
+    #[test]
+    fn field_styles_follow_state_and_focus() {
+        use cursive::theme::Effect;
+
+        let styles = FieldStyles {
+            focused: Style::from(Effect::Bold),
+            ..FieldStyles::default()
+        };
+        let mut form = FormView::new()
+            .field(Text::new("name").validator(Required))
+            .field(Text::new("output").initial("exists"))
+            .warning("output", |v: &str| match v {
+                "exists" => Err("File will be overwritten".to_string()),
+                _ => Ok(()),
+            })
+            .field_styles(styles.clone());
+        form.take_focus(Direction::front());
+        form.on_event(Event::Refresh);
+        let bold = Style::from(Effect::Bold);
+        assert_eq!(get_label_style(&form, 0), Some(bold));
+
+        form.on_event(Event::CtrlChar('f'));
+        assert_eq!(get_label_style(&form, 0), Some(styles.invalid.combine(bold)));
+        assert_eq!(get_error_style(&form, 0), Some(styles.error));
+
+        form.set_field_value("name", "John").unwrap();
+        form.on_event(Event::Key(Key::Tab));
+        form.on_event(Event::CtrlChar('f'));
+        assert_eq!(get_label_style(&form, 0), Some(styles.valid));
+        assert_eq!(get_label_style(&form, 1), Some(styles.warning.combine(bold)));
+        assert_eq!(get_error_style(&form, 1), Some(styles.warning));
+    }
+
+    #[test]
+    fn strings_localize_buttons_and_messages() {
+        use validators::Min;
+
+        assert_eq!(match_template("a {} b {}", "a 1 b 22"), Some(vec!["1".into(), "22".into()]));
+        assert_eq!(match_template("a {} b", "a 1 c"), None);
+        assert_eq!(match_template("a", "a"), Some(vec![]));
+
+        let strings = Strings {
+            cancel: "Abbrechen".to_string(),
+            submit: "Absenden".to_string(),
+            ..Strings::default()
+        }.message("Value must be at least {}", "Wert muss mindestens {} sein");
+        let mut form = FormView::new()
+            .field(Number::integer("level").validator(Min(3)))
+            .strings(strings);
+        let mut labels = Vec::new();
+        form = form.with_dialog(|d| {
+            labels = d.buttons_mut().map(|b| b.label().to_owned()).collect();
+        });
+        assert_eq!(labels[CANCEL_BTN], "<Abbrechen>");
+        assert_eq!(labels[RESET_BTN], "<Reset (Ctrl+r)>");
+        assert_eq!(labels[SUBMIT_BTN], "<Absenden (Ctrl+f)>");
+
+        form.set_field_value("level", "1").ok();
+        form.event_submit();
+        assert_eq!(get_error(&form, 0), "Wert muss mindestens 3 sein");
+
+        let strings = Strings {
+            key_submit: "Formular absenden".to_string(),
+            ..Strings::default()
+        };
+        let form = FormView::new().field(Text::new("t")).strings(strings);
+        assert!(form.keybindings_help().contains("Formular absenden"));
+        assert!(!form.keybindings_help().contains("submit form"));
+    }
+}
@@ -114,50 +114,311 @@
 //! 2) user submit `form` with `data`
 //! 3) `handler` is called with `data` (point 2)
 //!
+//! `action` which `form` has only a single flag (like [Checkbox]) is a yes/no confirmation, so in
+//! CLI it can be answered with `--yes`. If it's not answered and stdin is a terminal, its `form`
+//! is shown (skipping action picking).
+//!
+//! [Checkbox]: fields/struct.Checkbox.html
+//!
 #![deny(missing_docs)]
+// written for Rust 2015, trait objects are bare (without `dyn`)
+#![allow(bare_trait_objects)]
+// wrappers of fields (like `Text`) build `Field` in `new`
+#![allow(clippy::new_ret_no_self)]
 
+extern crate atty;
 extern crate clap;
 #[macro_use]
 extern crate cursive as _cursive;
+#[cfg(feature = "derive")]
+extern crate fui_derive;
 extern crate glob;
+#[macro_use]
+extern crate lazy_static;
 extern crate regex;
+extern crate rpassword;
+extern crate serde;
+#[macro_use]
 extern crate serde_json;
+extern crate toml;
 
 /// Re-export of [Cursive](../cursive/index.html) crate.
 pub mod cursive {
     pub use _cursive::*;
 }
+pub use clap::Shell;
 pub use serde_json::value::Value;
 pub mod feeders;
 pub mod fields;
 pub mod form;
+pub mod loader;
+pub mod model;
+pub mod progress;
+mod state;
 pub mod utils;
 pub mod validators;
 pub mod views;
+pub mod wizard;
 
 use cursive::Cursive;
+use cursive::theme::{self, Theme};
 use cursive::traits::Boxable;
-use form::FormView;
-use std::cell::RefCell;
+use cursive::views::{BoxView, Dialog, TextView};
+use fields::FieldStyles;
+use form::{FormView, KeyBindings, Strings, SubmitOutcome};
+use model::FormModel;
+use progress::Progress;
+use serde::de::DeserializeOwned;
+use serde_json::map::Map;
+use std::cell::{Cell, RefCell};
 use std::collections::BTreeMap;
 use std::env;
 use std::ffi::OsString;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::process;
 use std::rc::Rc;
-use validators::OneOf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use validators::{OneOf, Required};
+
+enum Handler {
+    /// Called once TUI is closed.
+    Plain(Rc<Fn(Value)>),
+    /// Called while TUI is still running, its outcome decides what comes next.
+    WithOutcome(Rc<Fn(Value) -> SubmitOutcome>),
+    /// Called while TUI is still running, its error is shown and form stays open.
+    WithResult(Rc<Fn(Value) -> Result<(), String>>),
+    /// Like `WithResult`, but called in a background thread, so TUI stays responsive.
+    InBackground(Arc<Fn(Value, Progress) -> Result<(), String> + Send + Sync>),
+    /// Like `WithResult`, but its output is shown (in TUI) or printed (in CLI).
+    WithOutput(Rc<Fn(Value) -> Result<Value, String>>),
+    /// Returns program's exit code (`0` means success), it's run after TUI is closed.
+    WithExitCode(Rc<Fn(Value) -> i32>),
+}
+
+impl Handler {
+    fn call(&self, data: Value) -> Result<(), String> {
+        match *self {
+            Handler::Plain(ref hdlr) => hdlr(data),
+            Handler::WithOutcome(ref hdlr) => {
+                hdlr(data);
+            }
+            Handler::WithResult(ref hdlr) => return hdlr(data),
+            Handler::InBackground(ref hdlr) => return hdlr(data, Progress::stderr()),
+            Handler::WithOutput(ref hdlr) => println!("{}", output_text(&hdlr(data)?)),
+            Handler::WithExitCode(ref hdlr) => match hdlr(data) {
+                0 => (),
+                code => return Err(format!("Handler exited with code {}", code)),
+            },
+        }
+        Ok(())
+    }
+
+    /// Runs handler (outside of TUI) and gives program's exit code, errors are printed.
+    fn exit_code(&self, data: Value) -> i32 {
+        if let Handler::WithExitCode(ref hdlr) = *self {
+            return hdlr(data);
+        }
+        match self.call(data) {
+            Ok(()) => 0,
+            Err(e) => {
+                eprintln!("ERROR: {}", e);
+                ERROR_EXIT_CODE
+            }
+        }
+    }
+}
+
+/// Exit code of program when handler fails or there's nothing to run (e.g. missing last action).
+const ERROR_EXIT_CODE: i32 = 1;
+/// Exit code of program when data from CLI (or stdin) is invalid.
+const VALIDATION_EXIT_CODE: i32 = 2;
+
+/// Refresh rate while action's handler is running in background.
+const BACKGROUND_FPS: u32 = 10;
+
+/// Shows `error` returned by handler above its form, so form's values can be fixed.
+fn show_handler_error(c: &mut Cursive, error: String, strings: &Strings) {
+    let dialog = Dialog::text(error)
+        .title(strings.error.clone())
+        .button(strings.back_to_form.clone(), |c| {
+            c.pop_layer();
+        });
+    c.add_layer(dialog);
+}
+
+/// Formats `output` of handler: string is kept as it is, other values are pretty-printed JSON.
+fn output_text(output: &Value) -> String {
+    match *output {
+        Value::String(ref text) => text.clone(),
+        ref other => serde_json::to_string_pretty(other).unwrap(),
+    }
+}
+
+/// Checks if `SubmitOutcome::GoTo(path, data)` can open form of action with `path` (one of
+/// `paths`) and fill it with `data`.
+fn goto_error(paths: &[String], path: &str, data: &Value) -> Option<String> {
+    if !paths.iter().any(|p| p == path) {
+        Some(format!("There is no action {:?}", path))
+    } else if !data.is_object() {
+        Some(format!("Data must be an object, got: {}", data))
+    } else {
+        None
+    }
+}
+
+/// Quotes `word` for shell (in single quotes), if it's needed.
+fn shell_quote(word: &str) -> String {
+    let is_plain = |c: char| c.is_alphanumeric() || "-_./:=,@%+".contains(c);
+    if !word.is_empty() && word.chars().all(is_plain) {
+        word.to_owned()
+    } else {
+        format!("'{}'", word.replace('\'', "'\\''"))
+    }
+}
+
+/// Saves `draft` of form of action with `path` in `file` keeping drafts (`None` removes it).
+fn save_draft_to(file: &Path, path: &str, draft: Option<Value>) -> Result<(), String> {
+    let mut drafts = match state::load(file) {
+        Some(Value::Object(drafts)) => drafts,
+        _ => Map::new(),
+    };
+    match draft {
+        Some(draft) => {
+            drafts.insert(path.to_owned(), draft);
+        }
+        None if drafts.contains_key(path) => {
+            drafts.remove(path);
+        }
+        None => return Ok(()),
+    }
+    state::save(file, &Value::Object(drafts))
+}
+
+/// Checks if submitted data fits handler of the action.
+type Check = Rc<Fn(&Value) -> Result<(), String>>;
 
 struct Action<'action> {
     name: &'action str,
     help: &'action str,
     form: Option<FormView>,
-    handler: Rc<Fn(Value)>,
+    handler: Handler,
+    check: Option<Check>,
+    command: Option<clap::App<'action, 'action>>,
+    group: Option<&'action str>,
+    aliases: Vec<&'action str>,
+}
+
+/// Joins name of action's `group` (if any) and action's `name`.
+fn action_path(group: Option<&str>, name: &str) -> String {
+    match group {
+        Some(group) => format!("{} {}", group, name),
+        None => name.to_owned(),
+    }
 }
 
 impl<'action> Action<'action> {
+    fn new(
+        name: &'action str,
+        help: &'action str,
+        form: FormView,
+        handler: Handler,
+    ) -> Self {
+        Action {
+            name,
+            help,
+            form: Some(form),
+            handler,
+            check: None,
+            command: None,
+            group: None,
+            aliases: Vec::new(),
+        }
+    }
+
     fn cmd_with_desc(&self) -> String {
-        format!("{}: {}", self.name, self.help)
+        format!("{}: {}", self.path(), self.help)
+    }
+
+    /// Gets action's `name` preceded by name of its group (if any).
+    fn path(&self) -> String {
+        action_path(self.group, self.name)
+    }
+
+    /// Builds CLI subcommand of the action.
+    fn cli_cmd<'s>(&'s self) -> clap::App<'s, 's> {
+        let mut sub_cmd = match self.command {
+            Some(ref command) => command.clone(),
+            None => clap::SubCommand::with_name(self.name)
+                .about(self.help)
+                .args(self.form.as_ref().unwrap().fields2clap_args().as_slice()),
+        };
+        if self.yes_label().is_some() {
+            sub_cmd = sub_cmd.arg(yes_arg());
+        }
+        if !self.aliases.is_empty() {
+            sub_cmd = sub_cmd.visible_aliases(&self.aliases);
+        }
+        sub_cmd
     }
+
+    /// Gets `label` of confirmation `field` if action can be answered with `--yes`.
+    fn yes_label(&self) -> Option<&str> {
+        self.form
+            .as_ref()
+            .unwrap()
+            .confirm_label()
+            .filter(|&label| label != YES_ARG)
+    }
+}
+
+/// CLI flag which answers yes/no actions.
+const YES_ARG: &str = "yes";
+fn yes_arg<'a, 'b>() -> clap::Arg<'a, 'b> {
+    clap::Arg::with_name(YES_ARG)
+        .long(YES_ARG)
+        .help("Answers yes, so confirmation isn't asked")
+}
+
+/// Escapes `text` for roff (lines are kept).
+fn roff(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let line = line.replace('\\', "\\e").replace('-', "\\-");
+            if line.starts_with('.') || line.starts_with('\'') {
+                format!("\\&{}", line)
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n.br\n")
 }
 
+/// Hidden CLI option printing completion script for given shell.
+const COMPLETIONS_ARG: &str = "completions";
+/// CLI flag running the last action again (see `Fui::remember_last`).
+const AGAIN_ARG: &str = "again";
+/// CLI flag reading action & its data from stdin as JSON instead of arguments.
+const STDIN_JSON_ARG: &str = "stdin-json";
+/// CLI flag printing picked action & its data instead of running handler.
+const DRY_RUN_ARG: &str = "dry-run";
+/// CLI flag making program never open TUI (like it does when it isn't run in a terminal).
+const NO_TUI_ARG: &str = "no-tui";
+/// Name of file keeping the last action in program's state dir.
+const LAST_FILE: &str = "last.json";
+/// Name of file keeping previous values of fields in program's state dir.
+const HISTORY_FILE: &str = "history.json";
+/// Count of previous values kept for each field.
+const HISTORY_SIZE: usize = 20;
+/// Name of file keeping drafts of forms in program's state dir.
+const DRAFTS_FILE: &str = "drafts.json";
+
 /// Top level building block of `fui` crate
 pub struct Fui<'attrs, 'action> {
     actions: BTreeMap<String, Action<'action>>,
@@ -165,7 +426,42 @@ pub struct Fui<'attrs, 'action> {
     version: &'attrs str,
     about: &'attrs str,
     author: &'attrs str,
+    keybindings: Option<KeyBindings>,
+    theme: Option<Theme>,
+    field_styles: Option<FieldStyles>,
+    strings: Option<Strings>,
+    // groups of actions: (name, help) & group of actions being added
+    groups: Vec<(&'action str, &'action str)>,
+    group: Option<&'action str>,
+    // key of the last added action
+    last_action: Option<String>,
+    skip_single_picker: bool,
+    // file keeping the last action, `None` means default one (if remembering is on)
+    remember_last: bool,
+    last_file: Option<PathBuf>,
+    // file keeping values of fields, like above & labels of secret fields by actions
+    remember_history: bool,
+    history_file: Option<PathBuf>,
+    secret_labels: BTreeMap<String, Vec<String>>,
+    // file keeping drafts of forms, like above
+    remember_drafts: bool,
+    drafts_file: Option<PathBuf>,
+    // defaults of fields read from config file
+    config: Map<String, Value>,
+    // set by `--dry-run`: data is printed instead of running handler
+    dry_run: bool,
+    // CLI commands equivalent to submitted forms, printed when TUI is closed
+    show_cli_equivalent: bool,
+    cli_equivalents: Vec<String>,
+    // exit code of program set when input fails
+    exit_code: Cell<i32>,
+}
+impl<'attrs, 'action> Default for Fui<'attrs, 'action> {
+    fn default() -> Self {
+        Fui::new()
+    }
 }
+
 impl<'attrs, 'action> Fui<'attrs, 'action> {
     /// Creates a new `Fui` with empty actions
     pub fn new() -> Self {
@@ -175,6 +471,26 @@ impl<'attrs, 'action> Fui<'attrs, 'action> {
             version: "",
             about: "",
             author: "",
+            keybindings: None,
+            theme: None,
+            field_styles: None,
+            strings: None,
+            groups: Vec::new(),
+            group: None,
+            last_action: None,
+            skip_single_picker: false,
+            remember_last: false,
+            last_file: None,
+            remember_history: false,
+            history_file: None,
+            secret_labels: BTreeMap::new(),
+            remember_drafts: false,
+            drafts_file: None,
+            config: Map::new(),
+            dry_run: false,
+            show_cli_equivalent: false,
+            cli_equivalents: Vec::new(),
+            exit_code: Cell::new(0),
         }
     }
     /// Defines action by providing `name`, `help`, `form`, `hdlr`
@@ -190,243 +506,2243 @@ impl<'attrs, 'action> Fui<'attrs, 'action> {
     where
         F: Fn(Value) + 'static,
     {
-        let action_details = Action {
-            name: name,
-            help: help,
-            form: Some(form),
-            handler: Rc::new(hdlr),
-        };
-        self.actions
-            .insert(action_details.cmd_with_desc(), action_details);
+        self.add_action(Action::new(name, help, form, Handler::Plain(Rc::new(hdlr))));
         self
     }
 
-    /// Coordinates flow from action picking to handler running
-    // This must be moving, until FormView implements copy or FormViews are added to cursive once
-    // then top layer are switched (instead of current inserting/popping)
-    pub fn run(mut self) {
-        let args = env::args_os();
-        let input_data = if args.len() > 1 {
-            // input from CLI
-            self.input_from_cli(args)
-        } else {
-            // input from TUI
-            self.input_from_tui()
-        };
-        // run handler
-        if let Some((action, data)) = input_data {
-            let hdlr = self.actions.get(&action).unwrap().handler.clone();
-            hdlr(data);
-        }
+    /// Defines action like [action], but `hdlr` is run when form is submitted (TUI is still
+    /// running) and its [SubmitOutcome] decides if form is closed, stays open, user gets back to
+    /// the actions' picker or goes to form of another action (filled e.g. with handler's output).
+    ///
+    /// For CLI the outcome is ignored.
+    ///
+    /// ```no_run
+    /// # extern crate fui;
+    /// #[macro_use]
+    /// extern crate serde_json;
+    /// # use fui::Fui;
+    /// # use fui::form::{FormView, SubmitOutcome};
+    /// # use fui::fields::Text;
+    /// # fn main() {
+    /// Fui::new()
+    ///     .action_with_outcome(
+    ///         "archive",
+    ///         "Creates archive",
+    ///         FormView::new().field(Text::new("dir")),
+    ///         |data| {
+    ///             let archive = format!("{}.tar", data["dir"].as_str().unwrap());
+    ///             // ...
+    ///             SubmitOutcome::GoTo("upload".to_string(), json!({ "file": archive }))
+    ///         },
+    ///     )
+    ///     .action("upload", "Uploads file", FormView::new().field(Text::new("file")), |_| {})
+    ///     .run();
+    /// # }
+    /// ```
+    ///
+    /// [action]: #method.action
+    /// [SubmitOutcome]: form/enum.SubmitOutcome.html
+    pub fn action_with_outcome<F>(
+        mut self,
+        name: &'action str,
+        help: &'action str,
+        form: FormView,
+        hdlr: F,
+    ) -> Self
+    where
+        F: Fn(Value) -> SubmitOutcome + 'static,
+    {
+        self.add_action(Action::new(name, help, form, Handler::WithOutcome(Rc::new(hdlr))));
+        self
     }
 
-    fn build_cli_app(&self) -> clap::App {
-        let mut sub_cmds: Vec<clap::App> = Vec::new();
-        for action in self.actions.values() {
-            let args = action.form.as_ref().unwrap().fields2clap_args();
-            let sub_cmd = clap::SubCommand::with_name(action.name.as_ref())
-                .about(action.help.as_ref())
-                .args(args.as_slice());
-            sub_cmds.push(sub_cmd);
-        }
-        clap::App::new(self.name.as_ref())
-            .version(self.version.as_ref())
-            .about(self.about.as_ref())
-            .author(self.author.as_ref())
-            .subcommands(sub_cmds)
+    /// Defines action like [action], but `hdlr` is run when form is submitted (TUI is still
+    /// running) and its error is shown in a dialog, whose "Back to form" button gets back to the
+    /// form with submitted values, so user can fix them and submit the form again.
+    ///
+    /// In CLI the error is printed and program exits with code `1`.
+    ///
+    /// ```no_run
+    /// # extern crate fui;
+    /// # use std::fs;
+    /// # use fui::Fui;
+    /// # use fui::form::FormView;
+    /// # use fui::fields::Text;
+    /// # fn main() {
+    /// Fui::new()
+    ///     .action_with_result(
+    ///         "rm",
+    ///         "Removes file",
+    ///         FormView::new().field(Text::new("path")),
+    ///         |data| fs::remove_file(data["path"].as_str().unwrap()),
+    ///     )
+    ///     .run();
+    /// # }
+    /// ```
+    ///
+    /// [action]: #method.action
+    pub fn action_with_result<F, E>(
+        mut self,
+        name: &'action str,
+        help: &'action str,
+        form: FormView,
+        hdlr: F,
+    ) -> Self
+    where
+        F: Fn(Value) -> Result<(), E> + 'static,
+        E: Display,
+    {
+        let handler = move |data: Value| hdlr(data).map_err(|e| e.to_string());
+        self.add_action(Action::new(name, help, form, Handler::WithResult(Rc::new(handler))));
+        self
     }
 
-    fn input_from_cli<I, T>(&self, user_args: I) -> Option<(String, Value)>
+    /// Defines action like [action_with_result], but `hdlr` is run in a background thread, so
+    /// TUI stays responsive (e.g. while handler waits for network). Until `hdlr` finishes a
+    /// "Working..." dialog with a spinner is shown (see [action_with_progress]).
+    ///
+    /// In CLI `hdlr` is simply awaited.
+    ///
+    /// ```no_run
+    /// # extern crate fui;
+    /// # use std::net::TcpStream;
+    /// # use fui::Fui;
+    /// # use fui::form::FormView;
+    /// # use fui::fields::Text;
+    /// # fn main() {
+    /// Fui::new()
+    ///     .action_in_background(
+    ///         "ping",
+    ///         "Checks if host accepts connections",
+    ///         FormView::new().field(Text::new("address")),
+    ///         |data| TcpStream::connect(data["address"].as_str().unwrap()).map(|_| ()),
+    ///     )
+    ///     .run();
+    /// # }
+    /// ```
+    ///
+    /// [action_with_result]: #method.action_with_result
+    /// [action_with_progress]: #method.action_with_progress
+    pub fn action_in_background<F, E>(
+        self,
+        name: &'action str,
+        help: &'action str,
+        form: FormView,
+        hdlr: F,
+    ) -> Self
     where
-        I: IntoIterator<Item = T>,
-        T: Into<OsString> + Clone,
+        F: Fn(Value) -> Result<(), E> + Send + Sync + 'static,
+        E: Display,
     {
-        let user_args = user_args
-            .into_iter()
-            .map(|x| x.into())
-            .collect::<Vec<OsString>>();
+        self.action_with_progress(name, help, form, move |data, _| hdlr(data))
+    }
 
-        let app = self.build_cli_app();
+    /// Defines action like [action_in_background], but `hdlr` also gets [Progress] which it uses
+    /// to report its progress (shown with a progress bar & message in TUI).
+    ///
+    /// ```no_run
+    /// # extern crate fui;
+    /// # use fui::Fui;
+    /// # use fui::form::FormView;
+    /// # use fui::fields::Tags;
+    /// # fn main() {
+    /// Fui::new()
+    ///     .action_with_progress(
+    ///         "archive",
+    ///         "Creates archive",
+    ///         FormView::new().field(Tags::new("files")),
+    ///         |data, progress| {
+    ///             let files = data["files"].as_array().unwrap();
+    ///             for (idx, file) in files.iter().enumerate() {
+    ///                 progress.message(format!("Packing {}", file));
+    ///                 // ...
+    ///                 progress.set((idx + 1) as f32 / files.len() as f32);
+    ///             }
+    ///             Ok::<(), String>(())
+    ///         },
+    ///     )
+    ///     .run();
+    /// # }
+    /// ```
+    ///
+    /// [action_in_background]: #method.action_in_background
+    /// [Progress]: progress/struct.Progress.html
+    pub fn action_with_progress<F, E>(
+        mut self,
+        name: &'action str,
+        help: &'action str,
+        form: FormView,
+        hdlr: F,
+    ) -> Self
+    where
+        F: Fn(Value, Progress) -> Result<(), E> + Send + Sync + 'static,
+        E: Display,
+    {
+        let handler = move |data, progress| hdlr(data, progress).map_err(|e| e.to_string());
+        self.add_action(Action::new(name, help, form, Handler::InBackground(Arc::new(handler))));
+        self
+    }
 
-        let matches = app.get_matches_from(user_args);
-        let cmd_name = matches.subcommand_name().unwrap();
-        let cmd_matches = matches.subcommand_matches(cmd_name).unwrap();
-        let action = self.actions
-            .values()
-            .find(|action| action.name == cmd_name)
-            .unwrap();
-        let value = action
-            .form
-            .as_ref()
-            .unwrap()
-            .clap_arg_matches2value(cmd_matches);
-        Some((action.cmd_with_desc(), value))
+    /// Defines action like [action_with_result], but `hdlr` returns output, which is shown in
+    /// a scrollable dialog (in TUI) or printed to stdout (in CLI). String is shown as it is,
+    /// other values as pretty-printed JSON.
+    ///
+    /// The dialog lets user run another action (going back to actions' picker) or quit.
+    ///
+    /// ```no_run
+    /// # extern crate fui;
+    /// # use std::fs;
+    /// # use fui::{Fui, Value};
+    /// # use fui::form::FormView;
+    /// # use fui::fields::FilePicker;
+    /// # fn main() {
+    /// Fui::new()
+    ///     .action_with_output(
+    ///         "cat",
+    ///         "Shows file's content",
+    ///         FormView::new().field(FilePicker::new("path")),
+    ///         |data| fs::read_to_string(data["path"].as_str().unwrap()).map(Value::from),
+    ///     )
+    ///     .run();
+    /// # }
+    /// ```
+    ///
+    /// [action_with_result]: #method.action_with_result
+    pub fn action_with_output<F, E>(
+        mut self,
+        name: &'action str,
+        help: &'action str,
+        form: FormView,
+        hdlr: F,
+    ) -> Self
+    where
+        F: Fn(Value) -> Result<Value, E> + 'static,
+        E: Display,
+    {
+        let handler = move |data: Value| hdlr(data).map_err(|e| e.to_string());
+        self.add_action(Action::new(name, help, form, Handler::WithOutput(Rc::new(handler))));
+        self
     }
 
-    fn header(&self) -> String {
-        let header = if (self.name.len() > 0) & (self.version.len() > 0) {
-            format!("{} ({})", self.name, self.version)
-        } else if self.name.len() > 0 {
-            format!("{}", self.name)
-        } else {
-            format!("")
-        };
-        return header;
+    /// Defines action with `hdlr` returning program's exit code (`0` means success), e.g. to tell
+    /// scripts what went wrong. It's run like handler of [action], after TUI is closed.
+    ///
+    /// Other handlers' errors give exit code `1` and invalid data from CLI gives `2`.
+    ///
+    /// ```no_run
+    /// # extern crate fui;
+    /// # use std::path::Path;
+    /// # use fui::Fui;
+    /// # use fui::form::FormView;
+    /// # use fui::fields::Text;
+    /// # fn main() {
+    /// Fui::new()
+    ///     .action_with_exit_code(
+    ///         "exists",
+    ///         "Checks if file exists",
+    ///         FormView::new().field(Text::new("path")),
+    ///         |data| if Path::new(data["path"].as_str().unwrap()).exists() { 0 } else { 3 },
+    ///     )
+    ///     .run();
+    /// # }
+    /// ```
+    ///
+    /// [action]: #method.action
+    pub fn action_with_exit_code<F>(
+        mut self,
+        name: &'action str,
+        help: &'action str,
+        form: FormView,
+        hdlr: F,
+    ) -> Self
+    where
+        F: Fn(Value) -> i32 + 'static,
+    {
+        self.add_action(Action::new(name, help, form, Handler::WithExitCode(Rc::new(hdlr))));
+        self
     }
 
-    fn run_tui_cmd_picker(&self, c: &mut Cursive) -> Rc<RefCell<Option<String>>> {
-        let cmd: Rc<RefCell<Option<String>>> = Rc::new(RefCell::new(None));
-        let cmd_clone = Rc::clone(&cmd);
-        // TODO::: rm cloning for it
-        let actions = self.actions
-            .keys()
-            .map(|x| x.to_owned())
-            .collect::<Vec<String>>();
-        c.add_layer(
-            FormView::new()
-                .title(&self.header())
-                .field(
-                    fields::Autocomplete::new("action", actions.clone())
-                        .help("Pick action")
-                        .validator(OneOf(actions)),
-                )
-                .on_submit(move |c, data| {
-                    let value = data.get("action").unwrap().clone();
-                    *cmd_clone.borrow_mut() = Some(value.as_str().unwrap().to_string());
-                    c.quit();
-                })
-                .on_cancel(|c| c.quit())
-                .full_screen(),
-        );
-        c.run();
-        cmd
+    /// Defines action like [action], but `hdlr` also gets action's name (preceded by its group,
+    /// e.g. `"remote add"`), so a single function can handle several actions.
+    ///
+    /// ```no_run
+    /// # extern crate fui;
+    /// # use fui::Fui;
+    /// # use fui::form::FormView;
+    /// # use fui::fields::Text;
+    /// # use fui::Value;
+    /// # fn main() {
+    /// fn handle(action: &str, data: Value) {
+    ///     match action {
+    ///         "start" => println!("starting {}", data["service"]),
+    ///         _ => println!("stopping {}", data["service"]),
+    ///     }
+    /// }
+    ///
+    /// let form = || FormView::new().field(Text::new("service"));
+    /// Fui::new()
+    ///     .action_with_name("start", "Starts service", form(), handle)
+    ///     .action_with_name("stop", "Stops service", form(), handle)
+    ///     .run();
+    /// # }
+    /// ```
+    ///
+    /// [action]: #method.action
+    pub fn action_with_name<F>(
+        mut self,
+        name: &'action str,
+        help: &'action str,
+        form: FormView,
+        hdlr: F,
+    ) -> Self
+    where
+        F: Fn(&str, Value) + 'static,
+    {
+        let path = action_path(self.group, name);
+        let handler = Handler::Plain(Rc::new(move |data: Value| hdlr(&path, data)));
+        self.add_action(Action::new(name, help, form, handler));
+        self
     }
 
-    fn input_from_tui(&mut self) -> Option<(String, Value)> {
-        let mut c = cursive::Cursive::new();
+    /// Defines action like [action], but `hdlr` gets form's data deserialized into `T`.
+    ///
+    /// In TUI data which can't be deserialized is reported in a dialog (and the form stays
+    /// open), in CLI it's printed and `hdlr` isn't run (program exits with code `2`).
+    ///
+    /// ```no_run
+    /// # extern crate fui;
+    /// # use std::collections::HashMap;
+    /// # use fui::Fui;
+    /// # use fui::form::FormView;
+    /// # use fui::fields::Text;
+    /// # fn main() {
+    /// Fui::new()
+    ///     .action_typed(
+    ///         "greet",
+    ///         "Greets user",
+    ///         FormView::new().field(Text::new("name")),
+    ///         |args: HashMap<String, String>| println!("Hello {}!", args["name"]),
+    ///     )
+    ///     .run();
+    /// # }
+    /// ```
+    ///
+    /// [action]: #method.action
+    pub fn action_typed<T, F>(
+        mut self,
+        name: &'action str,
+        help: &'action str,
+        form: FormView,
+        hdlr: F,
+    ) -> Self
+    where
+        T: DeserializeOwned + 'static,
+        F: Fn(T) + 'static,
+    {
+        let check = |data: &Value| {
+            serde_json::from_value::<T>(data.clone())
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        };
+        let handler = move |data: Value| match serde_json::from_value::<T>(data) {
+            Ok(typed) => {
+                hdlr(typed);
+                0
+            }
+            Err(e) => {
+                eprintln!("ERROR: {}", e);
+                VALIDATION_EXIT_CODE
+            }
+        };
+        let mut action = Action::new(name, help, form, Handler::WithExitCode(Rc::new(handler)));
+        action.check = Some(Rc::new(check));
+        self.add_action(action);
+        self
+    }
 
-        let cmd = self.run_tui_cmd_picker(&mut c);
-        let selection = match cmd.borrow().clone() {
-            Some(v) => v,
-            None => return None,
+    /// Defines action like [action_typed], but its form is built by `T` (see [FormModel]).
+    ///
+    /// [action_typed]: #method.action_typed
+    /// [FormModel]: model/trait.FormModel.html
+    pub fn action_model<T, F>(mut self, name: &'action str, help: &'action str, hdlr: F) -> Self
+    where
+        T: FormModel + 'static,
+        F: Fn(T) + 'static,
+    {
+        let check = |data: &Value| T::from_data(data).map(|_| ());
+        let handler = move |data: Value| match T::from_data(&data) {
+            Ok(model) => {
+                hdlr(model);
+                0
+            }
+            Err(e) => {
+                eprintln!("ERROR: {}", e);
+                VALIDATION_EXIT_CODE
+            }
         };
+        let handler = Handler::WithExitCode(Rc::new(handler));
+        let mut action = Action::new(name, help, T::form(), handler);
+        action.check = Some(Rc::new(check));
+        self.add_action(action);
+        self
+    }
 
-        // form
-        // TODO: use find_layer_from_id when available
-        // https://github.com/
-        // gyscos/Cursive/commit/06305c89a9223ffa0b041c94df4a51a177b1c99a
-        // #diff-bbe86c39b8f295bd78f682413bd99e5aR247
-        let action = self.actions.get_mut(&selection).unwrap();
-        let mut form_view = (*action).form.take().unwrap();
-
-        let form_data: Rc<RefCell<Option<Value>>> = Rc::new(RefCell::new(None));
-        let form_data_submit = Rc::clone(&form_data);
-        form_view.set_on_submit(move |c: &mut Cursive, data: Value| {
-            *form_data_submit.borrow_mut() = Some(data);
-            c.quit();
-        });
-        form_view.set_on_cancel(move |c: &mut Cursive| {
-            //TODO: this should return to action picker
-            //TODO: forms are drained so can't be done now
-            c.quit();
-        });
-        c.add_layer(form_view.full_width());
-        c.run();
-        let form_data = form_data.borrow().clone().unwrap();
-        Some((selection, form_data))
+    fn add_action(&mut self, mut action: Action<'action>) {
+        action.group = self.group;
+        let key = action.cmd_with_desc();
+        let secrets = action.form.as_ref().map_or(Vec::new(), |f| f.secret_labels());
+        self.secret_labels.insert(key.clone(), secrets);
+        self.actions.insert(key.clone(), action);
+        self.last_action = Some(key);
     }
 
-    /// Sets program's `name.
+    /// Adds `alias` of the last defined action, it's accepted by CLI as action's name (TUI shows
+    /// just the name).
     ///
-    /// For CLI means [Clap::App::name]
+    /// ```
+    /// # extern crate fui;
+    /// # use fui::Fui;
+    /// # use fui::form::FormView;
+    /// # fn main() {
+    /// let app = Fui::new()
+    ///     .action("extract", "Extracts archive", FormView::new(), |_| {})
+    ///     .alias("x");
+    /// # }
+    /// ```
     ///
-    /// [clap::App::name]: ../clap/struct.App.html#method.name
-    pub fn name(mut self, name: &'attrs str) -> Self {
-        self.name = name;
+    /// Panics if there is no action yet.
+    pub fn alias(mut self, alias: &'action str) -> Self {
+        let key = self.last_action.as_ref().expect("alias needs an action");
+        self.actions.get_mut(key).unwrap().aliases.push(alias);
         self
     }
 
-    /// Sets program's `version`.
+    /// Defines group `name` (described by `help`) of actions defined by `build`.
     ///
-    /// For CLI means [Clap::App::version]
+    /// In CLI group is a subcommand with its actions as subcommands (e.g. `my_app remote add`),
+    /// in TUI group is picked like an action and then one of its actions is picked.
     ///
-    /// [clap::App::version]: ../clap/struct.App.html#method.version
-    pub fn version(mut self, version: &'attrs str) -> Self {
-        self.version = version.into();
-        self
+    /// ```
+    /// # extern crate fui;
+    /// # use fui::Fui;
+    /// # use fui::form::FormView;
+    /// # use fui::fields::Text;
+    /// # fn main() {
+    /// let app = Fui::new()
+    ///     .group("remote", "Manages remotes", |group| {
+    ///         group
+    ///             .action("add", "Adds remote", FormView::new().field(Text::new("url")), |_| {})
+    ///             .action("remove", "Removes remote", FormView::new(), |_| {})
+    ///     })
+    ///     .action("status", "Shows status", FormView::new(), |_| {});
+    /// # }
+    /// ```
+    ///
+    /// Panics if groups are nested.
+    pub fn group<F>(mut self, name: &'action str, help: &'action str, build: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        assert!(self.group.is_none(), "groups can't be nested");
+        self.groups.push((name, help));
+        self.group = Some(name);
+        let mut fui = build(self);
+        fui.group = None;
+        fui
     }
 
-    /// Sets program's `about`.
+    /// Uses `command` as CLI subcommand of action `name`, instead of the one generated from
+    /// fields of action's form (so an existing [clap::App] can be reused).
     ///
-    /// For CLI means [Clap::App::about]
+    /// `command` has to be named like the action and its arguments are read by fields' labels, so
+    /// they should be named like fields (e.g. `--verbose` for `Checkbox::new("verbose")`).
     ///
-    /// [clap::App::about]: ../clap/struct.App.html#method.about
-    pub fn about(mut self, about: &'attrs str) -> Self {
-        self.about = about.into();
+    /// Action in a group is preceded by group's name (e.g. `"remote add"`).
+    ///
+    /// ```
+    /// # extern crate clap;
+    /// # extern crate fui;
+    /// # use clap::{Arg, SubCommand};
+    /// # use fui::Fui;
+    /// # use fui::fields::{Checkbox, Text};
+    /// # use fui::form::FormView;
+    /// # fn main() {
+    /// let command = SubCommand::with_name("archive")
+    ///     .about("Creates archive")
+    ///     .arg(Arg::with_name("target").short("t").takes_value(true).required(true))
+    ///     .arg(Arg::with_name("verbose").short("v"));
+    /// let form = FormView::new()
+    ///     .field(Text::new("target"))
+    ///     .field(Checkbox::new("verbose"));
+    /// let app = Fui::new()
+    ///     .action("archive", "Creates archive", form, |v| println!("{:?}", v))
+    ///     .cli_command("archive", command);
+    /// # }
+    /// ```
+    ///
+    /// Panics if there is no action `name` or `command` is named differently.
+    ///
+    /// [clap::App]: ../clap/struct.App.html
+    pub fn cli_command(mut self, name: &str, command: clap::App<'action, 'action>) -> Self {
+        let action = self.actions
+            .values_mut()
+            .find(|action| action.path() == name)
+            .expect("no action with such name");
+        assert_eq!(command.get_name(), action.name, "command must be named like its action");
+        action.command = Some(command);
         self
     }
 
-    /// Sets program's `author`.
+    /// Coordinates flow from action picking to handler running
     ///
-    /// For CLI means [Clap::App::author]
+    /// With flag `--stdin-json` action & its data are read from stdin (as
+    /// `{"action": "remote add", "data": {"url": "..."}}`) and validated by action's form, so the
+    /// program can be scripted without a terminal.
     ///
-    /// [clap::App::author]: ../clap/struct.App.html#method.author
-    pub fn author(mut self, author: &'attrs str) -> Self {
-        self.author = author.into();
-        self
+    /// TUI is opened only in a terminal and without flag `--no-tui`, otherwise action must be given
+    /// in CLI.
+    ///
+    /// With flag `--dry-run` (in CLI and TUI) handler isn't run, validated data is printed in the
+    /// same JSON instead (handy for debugging forms and for `--stdin-json` later).
+    // This must be moving, until FormView implements copy or FormViews are added to cursive once
+    // then top layer are switched (instead of current inserting/popping)
+    pub fn run(mut self) {
+        let code = match self.input() {
+            Some((ref action, ref data)) if self.dry_run => {
+                let payload = self.payload(action, data);
+                println!("{}", serde_json::to_string_pretty(&payload).unwrap());
+                0
+            }
+            Some((action, data)) => {
+                let code = self.actions[&action].handler.exit_code(data);
+                if code == 0 {
+                    self.remove_draft(&action);
+                }
+                code
+            }
+            None => self.exit_code.get(),
+        };
+        if code != 0 {
+            process::exit(code);
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn cli_checkbox_is_serialized_ok_when_value_preset() {
-        let value = Fui::new()
-            .action(
-                "action1",
-                "desc",
-                FormView::new().field(fields::Checkbox::new("ch1")),
-                |_| {},
-            )
-            .input_from_cli(vec!["my_app", "action1", "--ch1"]);
+    /// Runs like [run], but instead of running handler it returns picked action's name (preceded
+    /// by its group, e.g. `"remote add"`) and its data, so caller decides what comes next.
+    ///
+    /// `None` means nothing was picked (e.g. form was cancelled) or action was handled already
+    /// (see [action_with_outcome]).
+    ///
+    /// ```no_run
+    /// # extern crate fui;
+    /// # use fui::Fui;
+    /// # use fui::form::FormView;
+    /// # use fui::fields::Text;
+    /// # fn main() {
+    /// let form = FormView::new().field(Text::new("target"));
+    /// let picked = Fui::new()
+    ///     .action("archive", "Creates archive", form, |_| {})
+    ///     .run_and_return();
+    /// if let Some((action, data)) = picked {
+    ///     println!("{}: {}", action, data);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [run]: #method.run
+    /// [action_with_outcome]: #method.action_with_outcome
+    pub fn run_and_return(mut self) -> Option<(String, Value)> {
+        let (action, data) = self.input()?;
+        // there is no handler to fail
+        if !self.dry_run {
+            self.remove_draft(&action);
+        }
+        Some((self.actions[&action].path(), data))
+    }
 
-        let exp: Value = serde_json::from_str(r#"{ "ch1": true }"#).unwrap();
-        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    /// Gets picked action (its key) & data from CLI or TUI.
+    fn input(&mut self) -> Option<(String, Value)> {
+        self.apply_config();
+        let args = env::args_os().collect::<Vec<OsString>>();
+        if let Some(shell) = self.completions_shell(args.clone()) {
+            print!("{}", self.gen_completions(shell));
+            return None;
+        }
+        let (stdin_json, dry_run, from_cli, tui) = {
+            let matches = self.build_cli_app().get_matches_from(args.clone());
+            let from_cli = matches.subcommand_name().is_some() || matches.is_present(AGAIN_ARG);
+            // TUI needs a terminal (it's missing e.g. in pipes or cron jobs)
+            let tui = !matches.is_present(NO_TUI_ARG) && atty::is(atty::Stream::Stdin)
+                && atty::is(atty::Stream::Stdout);
+            let stdin_json = matches.is_present(STDIN_JSON_ARG);
+            (stdin_json, matches.is_present(DRY_RUN_ARG), from_cli, tui)
+        };
+        self.dry_run = dry_run;
+        let input = if stdin_json {
+            match self.input_from_json(io::stdin()) {
+                Ok(input) => Some(input),
+                Err(e) => {
+                    eprintln!("ERROR: {}", e);
+                    self.exit_code.set(VALIDATION_EXIT_CODE);
+                    None
+                }
+            }
+        } else if from_cli {
+            match self.unanswered_confirm(args.clone()) {
+                // ask for confirmation in TUI
+                Some(action) if tui => self.input_from_tui(Some(action)),
+                // input from CLI
+                _ => self.input_from_cli(args),
+            }
+        } else if !tui {
+            eprintln!(
+                "ERROR: TUI can't be opened (not a terminal or --{} is given), pass action with \
+                 its arguments (see `{} --help`) or read them with --{}",
+                NO_TUI_ARG,
+                self.bin_name(),
+                STDIN_JSON_ARG
+            );
+            self.exit_code.set(ERROR_EXIT_CODE);
+            None
+        } else {
+            // input from TUI
+            self.input_from_tui(None)
+        };
+        // they're collected only in TUI, which is closed already
+        for cmd in self.cli_equivalents.drain(..) {
+            eprintln!("{}", cmd);
+        }
+        match input {
+            Some((ref action, ref data)) if !self.dry_run => self.save_state(action, data),
+            _ => (),
+        }
+        input
     }
 
-    #[test]
-    fn cli_checkbox_is_serialized_ok_when_value_missing() {
-        let value = Fui::new()
-            .action(
-                "action1",
-                "desc",
-                FormView::new().field(fields::Checkbox::new("ch1")),
-                |_| {},
-            )
-            .input_from_cli(vec!["my_app", "action1"]);
+    /// Remembers the last run action with its data, so it can be run again: with `--again` in
+    /// CLI or with "Repeat last" entry at the top of actions' picker.
+    ///
+    /// They're kept in `last.json` in program's state dir (`$XDG_STATE_HOME/<name>`, by default
+    /// `~/.local/state/<name>`).
+    pub fn remember_last(mut self) -> Self {
+        self.remember_last = true;
+        self
+    }
 
-        let exp: Value = serde_json::from_str(r#"{ "ch1": false }"#).unwrap();
-        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    /// Remembers the last run action like [remember_last], but in file `path`.
+    ///
+    /// [remember_last]: #method.remember_last
+    pub fn remember_last_in<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.remember_last = true;
+        self.last_file = Some(path.into());
+        self
     }
 
-    #[test]
-    fn cli_text_is_serialized_ok_when_value_preset() {
-        let value = Fui::new()
-            .action(
-                "action1",
-                "desc",
-                FormView::new().field(fields::Text::new("t1")),
-                |_| {},
-            )
-            .input_from_cli(vec!["my_app", "action1", "--t1", "v1"]);
+    /// Remembers submitted values of fields (but secret ones, like passwords), so they can be
+    /// filled again when the field is focused (see [FormView::set_history]).
+    ///
+    /// They're kept in `history.json` in program's state dir (see [remember_last]).
+    ///
+    /// [FormView::set_history]: form/struct.FormView.html#method.set_history
+    /// [remember_last]: #method.remember_last
+    pub fn remember_history(mut self) -> Self {
+        self.remember_history = true;
+        self
+    }
 
-        let exp: Value = serde_json::from_str(r#"{ "t1": "v1" }"#).unwrap();
-        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    /// Remembers submitted values of fields like [remember_history], but in file `path`.
+    ///
+    /// [remember_history]: #method.remember_history
+    pub fn remember_history_in<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.remember_history = true;
+        self.history_file = Some(path.into());
+        self
     }
 
-    //#[test]
+    /// Lets user save draft of action's form with `Ctrl+s` (see [KeyBindings::save_draft]),
+    /// next time the form is opened user is asked if the draft should be restored. Draft is
+    /// removed once the form is submitted and its handler succeeds.
+    ///
+    /// Drafts are kept in `drafts.json` in program's state dir (see [remember_last]), values of
+    /// secret fields (like passwords) aren't saved.
+    ///
+    /// [KeyBindings::save_draft]: form/struct.KeyBindings.html#structfield.save_draft
+    /// [remember_last]: #method.remember_last
+    pub fn remember_drafts(mut self) -> Self {
+        self.remember_drafts = true;
+        self
+    }
+
+    /// Lets user save drafts of forms like [remember_drafts], but they're kept in file `path`.
+    ///
+    /// [remember_drafts]: #method.remember_drafts
+    pub fn remember_drafts_in<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.remember_drafts = true;
+        self.drafts_file = Some(path.into());
+        self
+    }
+
+    /// Prints CLI command equivalent to each form submitted in TUI (to stderr, when TUI is
+    /// closed), e.g. `my_app remote add --url https://example.org`, so it can be scripted later.
+    pub fn show_cli_equivalent(mut self) -> Self {
+        self.show_cli_equivalent = true;
+        self
+    }
+
+    /// Builds CLI command running `action` (its key) with `data`.
+    fn cli_equivalent(&self, action: &str, data: &Value) -> String {
+        let action = &self.actions[action];
+        let mut words = vec![self.bin_name()];
+        words.extend(action.path().split(' ').map(|w| w.to_owned()));
+        words.extend(action.form.as_ref().unwrap().value2clap_args(data));
+        words
+            .iter()
+            .map(|w| shell_quote(w))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Gets file keeping drafts of forms, if they're remembered.
+    fn drafts_file(&self) -> Option<PathBuf> {
+        if !self.remember_drafts {
+            return None;
+        }
+        self.state_file(&self.drafts_file, DRAFTS_FILE)
+    }
+
+    /// Loads drafts of forms by paths of their actions.
+    fn load_drafts(&self) -> Map<String, Value> {
+        match self.drafts_file().and_then(|path| state::load(&path)) {
+            Some(Value::Object(drafts)) => drafts,
+            _ => Map::new(),
+        }
+    }
+
+    /// Asks user if saved draft of form should be restored.
+    fn ask_restore_draft(&self, c: &mut Cursive) -> bool {
+        let strings = self.strings.clone().unwrap_or_default();
+        let restore = Rc::new(Cell::new(false));
+        let restore_yes = Rc::clone(&restore);
+        let dialog = Dialog::text(strings.restore_draft)
+            .button(strings.no, |c| c.quit())
+            .button(strings.yes, move |c| {
+                restore_yes.set(true);
+                c.quit();
+            });
+        c.add_layer(dialog);
+        c.run();
+        c.pop_layer();
+        restore.get()
+    }
+
+    /// Gets `custom` file or file `name` in program's state dir.
+    fn state_file(&self, custom: &Option<PathBuf>, name: &str) -> Option<PathBuf> {
+        match *custom {
+            Some(ref path) => Some(path.clone()),
+            None => state::dir(&self.bin_name()).map(|dir| dir.join(name)),
+        }
+    }
+
+    /// Gets file keeping the last action, if it's remembered.
+    fn last_file(&self) -> Option<PathBuf> {
+        if !self.remember_last {
+            return None;
+        }
+        self.state_file(&self.last_file, LAST_FILE)
+    }
+
+    /// Gets file keeping values of fields, if they're remembered.
+    fn history_file(&self) -> Option<PathBuf> {
+        if !self.remember_history {
+            return None;
+        }
+        self.state_file(&self.history_file, HISTORY_FILE)
+    }
+
+    /// Loads previous values of fields by labels (the latest first).
+    fn load_history(&self) -> Map<String, Value> {
+        match self.history_file().and_then(|path| state::load(&path)) {
+            Some(Value::Object(history)) => history,
+            _ => Map::new(),
+        }
+    }
+
+    /// Adds values of `data` submitted to `action` (its key) to history, if it's remembered.
+    fn save_history(&self, action: &str, data: &Value) {
+        let (path, data) = match (self.history_file(), data.as_object()) {
+            (Some(path), Some(data)) => (path, data),
+            _ => return,
+        };
+        let mut history = self.load_history();
+        let secrets = &self.secret_labels[action];
+        for (label, value) in data.iter() {
+            if secrets.contains(label) || value.is_null() || value == "" {
+                continue;
+            }
+            let values = history
+                .entry(label.clone())
+                .or_insert_with(|| Value::Array(Vec::new()));
+            if let Value::Array(ref mut values) = *values {
+                values.retain(|v| v != value);
+                values.insert(0, value.clone());
+                values.truncate(HISTORY_SIZE);
+            }
+        }
+        if let Err(e) = state::save(&path, &Value::Object(history)) {
+            eprintln!("WARNING: Can't save history of values: {}", e);
+        }
+    }
+
+    /// Saves data submitted to `action` (its key), see [remember_last] & [remember_history].
+    ///
+    /// [remember_last]: #method.remember_last
+    /// [remember_history]: #method.remember_history
+    fn save_state(&self, action: &str, data: &Value) {
+        self.save_last(action, data);
+        self.save_history(action, data);
+    }
+
+    /// Removes draft of `action`'s (its key) form, once its handler succeeds.
+    fn remove_draft(&self, action: &str) {
+        if let Some(file) = self.drafts_file() {
+            if let Err(e) = save_draft_to(&file, &self.actions[action].path(), None) {
+                eprintln!("WARNING: Can't remove draft: {}", e);
+            }
+        }
+    }
+
+    /// Loads the last action (its key) & data, if it's (still) defined.
+    fn load_last(&self) -> Option<(String, Value)> {
+        let last = state::load(&self.last_file()?)?;
+        let key = self.action_key(last["action"].as_str()?)?;
+        Some((key, last["data"].clone()))
+    }
+
+    /// Saves `action` (its key) & `data` as the last action, if it's remembered.
+    fn save_last(&self, action: &str, data: &Value) {
+        let path = match self.last_file() {
+            Some(path) => path,
+            None => return,
+        };
+        if let Err(e) = state::save(&path, &self.payload(action, data)) {
+            eprintln!("WARNING: Can't save the last action: {}", e);
+        }
+    }
+
+    /// Puts `action` (its path) & `data` together, as they're read with `--stdin-json`.
+    fn payload(&self, action: &str, data: &Value) -> Value {
+        json!({ "action": self.actions[action].path(), "data": data })
+    }
+
+    /// Finds key of action by its `path` (name preceded by its group).
+    fn action_key(&self, path: &str) -> Option<String> {
+        self.actions
+            .iter()
+            .find(|&(_, action)| action.path() == path)
+            .map(|(key, _)| key.clone())
+    }
+
+    fn build_cli_app(&self) -> clap::App<'_, '_> {
+        let mut sub_cmds = self.actions
+            .values()
+            .filter(|action| action.group.is_none())
+            .map(|action| action.cli_cmd())
+            .collect::<Vec<clap::App>>();
+        for &(name, help) in self.groups.iter() {
+            let actions = self.actions
+                .values()
+                .filter(|action| action.group == Some(name))
+                .map(|action| action.cli_cmd())
+                .collect::<Vec<clap::App>>();
+            let group = clap::SubCommand::with_name(name)
+                .about(help)
+                .setting(clap::AppSettings::SubcommandRequiredElseHelp)
+                .subcommands(actions);
+            sub_cmds.push(group);
+        }
+        let app = clap::App::new(self.name)
+            .version(self.version.as_ref())
+            .about(self.about.as_ref())
+            .author(self.author.as_ref())
+            .arg(
+                clap::Arg::with_name(COMPLETIONS_ARG)
+                    .long(COMPLETIONS_ARG)
+                    .takes_value(true)
+                    .possible_values(&Shell::variants())
+                    .hidden(true),
+            )
+            .arg(
+                clap::Arg::with_name(STDIN_JSON_ARG)
+                    .long(STDIN_JSON_ARG)
+                    .help("Reads action & its data from stdin as JSON"),
+            )
+            .arg(
+                clap::Arg::with_name(DRY_RUN_ARG)
+                    .long(DRY_RUN_ARG)
+                    .help("Prints action & its data as JSON instead of running it"),
+            )
+            .arg(
+                clap::Arg::with_name(NO_TUI_ARG)
+                    .long(NO_TUI_ARG)
+                    .help("Never opens TUI (e.g. to ask for confirmation)"),
+            );
+        let app = if self.remember_last {
+            app.arg(
+                clap::Arg::with_name(AGAIN_ARG)
+                    .long(AGAIN_ARG)
+                    .help("Runs the last action again (with the same values)"),
+            )
+        } else {
+            app
+        };
+        app.subcommands(sub_cmds)
+    }
+
+    /// Generates completion script of all actions (and their arguments) for `shell`.
+    ///
+    /// It's also printed when program is run with hidden option `--completions <shell>`
+    /// (`bash`, `zsh`, `fish`, `powershell` or `elvish`), e.g.:
+    ///
+    /// ```shell
+    /// $ my_app --completions bash > /usr/share/bash-completion/completions/my_app
+    /// ```
+    ///
+    /// Completed program is named by [name] (or by its executable, if [name] isn't set).
+    ///
+    /// ```
+    /// # extern crate fui;
+    /// # use fui::{Fui, Shell};
+    /// # use fui::form::FormView;
+    /// # use fui::fields::Text;
+    /// # fn main() {
+    /// let form = FormView::new().field(Text::new("target"));
+    /// let script = Fui::new()
+    ///     .name("my_app")
+    ///     .action("archive", "Creates archive", form, |_| {})
+    ///     .gen_completions(Shell::Fish);
+    /// assert!(script.contains("archive"));
+    /// # }
+    /// ```
+    ///
+    /// [name]: #method.name
+    pub fn gen_completions(&self, shell: Shell) -> String {
+        let mut script = Vec::new();
+        self.build_cli_app()
+            .gen_completions_to(self.bin_name(), shell, &mut script);
+        String::from_utf8_lossy(&script).into_owned()
+    }
+
+    /// Generates man page (in roff format) describing each action in a subsection, with
+    /// arguments of action's fields as its options (described by fields' help).
+    ///
+    /// Like [gen_completions] it names program by [name] (or by its executable).
+    ///
+    /// ```
+    /// # extern crate fui;
+    /// # use fui::Fui;
+    /// # use fui::form::FormView;
+    /// # use fui::fields::Text;
+    /// # fn main() {
+    /// let form = FormView::new().field(Text::new("target").help("Path of the archive"));
+    /// let page = Fui::new()
+    ///     .name("my_app")
+    ///     .action("archive", "Creates archive", form, |_| {})
+    ///     .gen_manpage();
+    /// assert!(page.contains(".SS archive"));
+    /// # }
+    /// ```
+    ///
+    /// [gen_completions]: #method.gen_completions
+    /// [name]: #method.name
+    pub fn gen_manpage(&self) -> String {
+        let bin_name = self.bin_name();
+        let name = roff(&bin_name);
+        let mut page = format!(
+            ".TH \"{}\" 1 \"\" \"{} {}\"\n",
+            roff(&bin_name.to_uppercase()),
+            name,
+            roff(self.version)
+        );
+        page.push_str(".SH NAME\n");
+        page.push_str(&name);
+        if let Some(about) = self.about.lines().next() {
+            page.push_str(&format!(" \\- {}", roff(about)));
+        }
+        page.push_str("\n.SH SYNOPSIS\n");
+        page.push_str(&format!(".B {}\n[\\fIACTION\\fR [\\fIOPTIONS\\fR]]\n", name));
+        page.push_str(".SH DESCRIPTION\n");
+        if !self.about.is_empty() {
+            page.push_str(&format!("{}\n.PP\n", roff(self.about)));
+        }
+        page.push_str("Run without arguments to pick an action and fill its form in TUI.\n");
+        page.push_str(".SH ACTIONS\n");
+        for action in self.actions.values() {
+            page.push_str(&format!(".SS {}\n{}\n", roff(&action.path()), roff(action.help)));
+            if !action.aliases.is_empty() {
+                page.push_str(&format!(".br\nAliases: {}\n", roff(&action.aliases.join(", "))));
+            }
+            let mut args = action.form.as_ref().unwrap().fields2clap_args();
+            if action.yes_label().is_some() {
+                args.push(yes_arg());
+            }
+            for arg in args.iter() {
+                let mut switches = Vec::new();
+                if let Some(short) = arg.s.short {
+                    switches.push(format!("\\fB\\-{}\\fR", short));
+                }
+                if let Some(long) = arg.s.long {
+                    switches.push(format!("\\fB\\-\\-{}\\fR", roff(long)));
+                }
+                let mut usage = switches.join(", ");
+                // positional arguments have only values
+                if arg.b.is_set(clap::ArgSettings::TakesValue) || usage.is_empty() {
+                    if !usage.is_empty() {
+                        usage.push(' ');
+                    }
+                    usage.push_str(&format!("\\fI<{}>\\fR", roff(arg.b.name)));
+                }
+                page.push_str(&format!(".TP\n{}\n", usage));
+                if let Some(help) = arg.b.help.filter(|h| !h.is_empty()) {
+                    page.push_str(&format!("{}\n", roff(help)));
+                }
+            }
+        }
+        if !self.author.is_empty() {
+            page.push_str(&format!(".SH AUTHOR\n{}\n", roff(self.author)));
+        }
+        page
+    }
+
+    /// Gets program's name set by [name] or name of its executable.
+    ///
+    /// [name]: #method.name
+    fn bin_name(&self) -> String {
+        if self.name.is_empty() {
+            env::current_exe()
+                .ok()
+                .and_then(|p| p.file_name().map(|n| n.to_string_lossy().into_owned()))
+                .unwrap_or_default()
+        } else {
+            self.name.to_owned()
+        }
+    }
+
+    /// Finds shell passed with `--completions`.
+    fn completions_shell<I, T>(&self, user_args: I) -> Option<Shell>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let matches = self.build_cli_app().get_matches_from(user_args);
+        matches.value_of(COMPLETIONS_ARG)?.parse().ok()
+    }
+
+    fn input_from_cli<I, T>(&self, user_args: I) -> Option<(String, Value)>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let user_args = user_args
+            .into_iter()
+            .map(|x| x.into())
+            .collect::<Vec<OsString>>();
+
+        let app = self.build_cli_app();
+
+        let matches = app.get_matches_from(user_args);
+        if matches.is_present(AGAIN_ARG) {
+            let last = self.load_last();
+            if last.is_none() {
+                eprintln!("ERROR: There is no last action to run again");
+                self.exit_code.set(ERROR_EXIT_CODE);
+            }
+            return last;
+        }
+        let (action, cmd_matches) = self.find_cli_action(&matches)?;
+        let mut value = match action.form.as_ref().unwrap().validate_clap_arg_matches(cmd_matches) {
+            Ok(value) => value,
+            Err(errors) => {
+                for e in errors {
+                    eprintln!("ERROR: {:?}", e);
+                }
+                self.exit_code.set(VALIDATION_EXIT_CODE);
+                return None;
+            }
+        };
+        if let Some(label) = action.yes_label() {
+            if cmd_matches.is_present(YES_ARG) {
+                value[label] = Value::Bool(true);
+            }
+        }
+        // data is rejected like in TUI (e.g. when it doesn't fit typed handler)
+        if let Some(Err(e)) = action.check.as_ref().map(|check| check(&value)) {
+            eprintln!("ERROR: {}", e);
+            self.exit_code.set(VALIDATION_EXIT_CODE);
+            return None;
+        }
+        Some((action.cmd_with_desc(), value))
+    }
+
+    /// Reads action (its path) & data from `reader` as JSON and validates data with action's form.
+    fn input_from_json<R: io::Read>(&mut self, reader: R) -> Result<(String, Value), String> {
+        let payload: Value = serde_json::from_reader(reader)
+            .map_err(|e| format!("Can't parse JSON from stdin: {}", e))?;
+        let path = payload["action"]
+            .as_str()
+            .ok_or_else(|| r#"Expected JSON like {"action": ..., "data": {...}}"#.to_string())?;
+        let key = self.action_key(path)
+            .ok_or_else(|| format!("There is no action {:?}", path))?;
+        let action = self.actions.get_mut(&key).unwrap();
+        let form = action.form.as_mut().unwrap();
+        match payload.get("data") {
+            Some(data) => form.set_data(data)?,
+            None => form.set_data(&json!({}))?,
+        }
+        let data = form.validate_all().map_err(|errors| {
+            let mut errors = errors
+                .into_iter()
+                .map(|(label, e)| format!("{}: {}", label, e))
+                .collect::<Vec<String>>();
+            errors.sort();
+            errors.join("\n")
+        })?;
+        if let Some(check) = action.check.as_ref() {
+            check(&data)?;
+        }
+        Ok((key, data))
+    }
+
+    /// Finds action picked in CLI (with its group) and matches of its arguments.
+    fn find_cli_action<'s, 'm, 'a>(
+        &'s self,
+        matches: &'m clap::ArgMatches<'a>,
+    ) -> Option<(&'s Action<'action>, &'m clap::ArgMatches<'a>)> {
+        let cmd_name = matches.subcommand_name()?;
+        let cmd_matches = matches.subcommand_matches(cmd_name)?;
+        let (group, cmd_name, cmd_matches) = if self.groups.iter().any(|g| g.0 == cmd_name) {
+            let name = cmd_matches.subcommand_name()?;
+            (Some(cmd_name), name, cmd_matches.subcommand_matches(name)?)
+        } else {
+            (None, cmd_name, cmd_matches)
+        };
+        let action = self.actions
+            .values()
+            .find(|action| action.group == group && action.name == cmd_name)?;
+        Some((action, cmd_matches))
+    }
+
+    /// Finds yes/no action which got no answer from CLI.
+    fn unanswered_confirm<I, T>(&self, user_args: I) -> Option<String>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let matches = self.build_cli_app().get_matches_from(user_args);
+        let (action, cmd_matches) = self.find_cli_action(&matches)?;
+        let label = action.yes_label()?;
+        let negated = format!("no-{}", label);
+        let answers = [YES_ARG, label, negated.as_str()];
+        if answers.iter().any(|&arg| cmd_matches.is_present(arg)) {
+            None
+        } else {
+            Some(action.cmd_with_desc())
+        }
+    }
+
+    fn header(&self) -> String {
+        let header = if (!self.name.is_empty()) & (!self.version.is_empty()) {
+            format!("{} ({})", self.name, self.version)
+        } else if !self.name.is_empty() {
+            self.name.to_string()
+        } else {
+            String::new()
+        };
+        header
+    }
+
+    /// Gets the only action, if picker is skipped for it (see [skip_single_picker]).
+    ///
+    /// [skip_single_picker]: #method.skip_single_picker
+    fn only_action(&self) -> Option<String> {
+        if self.skip_single_picker && self.actions.len() == 1 {
+            self.actions.keys().next().cloned()
+        } else {
+            None
+        }
+    }
+
+    /// Gets picker's entry repeating the last action, if there is one.
+    fn repeat_last_item(&self) -> Option<String> {
+        let (key, _) = self.load_last()?;
+        let strings = self.strings.clone().unwrap_or_default();
+        Some(format!("{}: {}", strings.repeat_last, self.actions[&key].path()))
+    }
+
+    /// Picks action in TUI, actions in groups are picked after picking their group.
+    fn pick_tui_action(&self, c: &mut Cursive) -> Option<String> {
+        if let Some(action) = self.only_action() {
+            return Some(action);
+        }
+        let groups = self.groups
+            .iter()
+            .map(|&(name, help)| (format!("{}: {}", name, help), name))
+            .collect::<Vec<(String, &str)>>();
+        loop {
+            let mut items = self.actions
+                .iter()
+                .filter(|&(_, action)| action.group.is_none())
+                .map(|(key, _)| key.to_owned())
+                .chain(groups.iter().map(|g| g.0.clone()))
+                .collect::<Vec<String>>();
+            items.sort();
+            if let Some(repeat) = self.repeat_last_item() {
+                items.insert(0, repeat);
+            }
+            let picked = self.run_tui_cmd_picker(c, &self.header(), items);
+            c.pop_layer();
+            let picked = picked?;
+            let group = match groups.iter().find(|g| g.0 == picked) {
+                Some(&(_, group)) => group,
+                None => return Some(picked),
+            };
+            let items = self.actions
+                .iter()
+                .filter(|&(_, action)| action.group == Some(group))
+                .map(|(key, _)| key.to_owned())
+                .collect::<Vec<String>>();
+            let picked = self.run_tui_cmd_picker(c, group, items);
+            c.pop_layer();
+            // cancelling group's picker goes back to groups & actions
+            if picked.is_some() {
+                return picked;
+            }
+        }
+    }
+
+    fn run_tui_cmd_picker(
+        &self,
+        c: &mut Cursive,
+        title: &str,
+        actions: Vec<String>,
+    ) -> Option<String> {
+        let cmd: Rc<RefCell<Option<String>>> = Rc::new(RefCell::new(None));
+        let cmd_clone = Rc::clone(&cmd);
+        let strings = self.strings.clone().unwrap_or_default();
+        let mut picker = FormView::new()
+            .title(title)
+            .field(
+                fields::Autocomplete::new("action", feeders::Fuzzy::new(actions.clone()))
+                    .help(strings.pick_action.as_str())
+                    .validator(Required)
+                    .validator(OneOf(actions)),
+            )
+            .on_submit(move |c, data| {
+                let value = data.get("action").unwrap().clone();
+                *cmd_clone.borrow_mut() = Some(value.as_str().unwrap().to_string());
+                c.quit();
+            })
+            .on_cancel(|c| c.quit());
+        if let Some(ref keybindings) = self.keybindings {
+            picker.set_keybindings(keybindings.clone());
+        }
+        if let Some(ref styles) = self.field_styles {
+            picker.set_field_styles(styles.clone());
+        }
+        picker.set_strings(strings);
+        c.add_layer(picker.full_screen());
+        c.run();
+        cmd.replace(None)
+    }
+
+    /// Runs TUI starting from `picked` action or from actions' picker (if `None`).
+    fn input_from_tui(&mut self, mut picked: Option<String>) -> Option<(String, Value)> {
+        let mut c = cursive::Cursive::new();
+        if let Some(ref theme) = self.theme {
+            c.set_theme(theme.clone());
+        }
+
+        // data filling form of action opened with `SubmitOutcome::GoTo`
+        let mut prefill: Option<Value> = None;
+        loop {
+            let selection = match picked.take() {
+                Some(v) => v,
+                None => self.pick_tui_action(&mut c)?,
+            };
+            if Some(&selection) == self.repeat_last_item().as_ref() {
+                return self.load_last();
+            }
+
+            let history = self.load_history();
+            let path = self.actions[&selection].path();
+            let draft = match self.load_drafts().remove(&path) {
+                Some(ref draft) if self.ask_restore_draft(&mut c) => Some(draft.clone()),
+                _ => None,
+            };
+            let drafts_file = self.drafts_file();
+            let dry_run = self.dry_run;
+            let paths = self.actions.values().map(|a| a.path()).collect::<Vec<String>>();
+            // form
+            // TODO: use find_layer_from_id when available
+            // https://github.com/
+            // gyscos/Cursive/commit/06305c89a9223ffa0b041c94df4a51a177b1c99a
+            // #diff-bbe86c39b8f295bd78f682413bd99e5aR247
+            let action = self.actions.get_mut(&selection).unwrap();
+            let mut form_view = action.form.take().unwrap();
+            if let Some(ref keybindings) = self.keybindings {
+                form_view.set_keybindings(keybindings.clone());
+            }
+            if let Some(ref styles) = self.field_styles {
+                form_view.set_field_styles(styles.clone());
+            }
+            if let Some(ref strings) = self.strings {
+                form_view.set_strings(strings.clone());
+            }
+            for (label, values) in history {
+                if let Value::Array(values) = values {
+                    form_view.set_history(&label, values);
+                }
+            }
+            if let Some(ref draft) = draft {
+                form_view.set_draft(draft);
+            }
+            if let Some(file) = drafts_file {
+                let strings = self.strings.clone().unwrap_or_default();
+                form_view.set_on_save_draft(move |c: &mut Cursive, draft: Value| {
+                    let msg = match save_draft_to(&file, &path, Some(draft)) {
+                        Ok(()) => strings.draft_saved.clone(),
+                        Err(e) => e,
+                    };
+                    c.add_layer(Dialog::info(msg));
+                });
+            }
+            let prefill_error = prefill.take().and_then(|data| form_view.set_data(&data).err());
+
+            let form_data: Rc<RefCell<Option<Value>>> = Rc::new(RefCell::new(None));
+            let back_to_menu = Rc::new(Cell::new(false));
+            let goto: Rc<RefCell<Option<(String, Value)>>> = Rc::new(RefCell::new(None));
+            // data submitted to handler run while TUI is running & if the handler succeeded
+            let submitted: Rc<RefCell<Option<Value>>> = Rc::new(RefCell::new(None));
+            let succeeded = Arc::new(AtomicBool::new(false));
+            // in dry run every handler is skipped, so submitted data is simply returned
+            match (dry_run, &action.handler) {
+                (true, _) | (false, Handler::Plain(_)) | (false, Handler::WithExitCode(_)) => {
+                    let form_data_submit = Rc::clone(&form_data);
+                    let check = action.check.clone();
+                    let title = self.strings.clone().unwrap_or_default().invalid_data;
+                    form_view.set_on_submit(move |c: &mut Cursive, data: Value| {
+                        if let Some(Err(e)) = check.as_ref().map(|check| check(&data)) {
+                            c.add_layer(Dialog::info(e).title(title.clone()));
+                            return;
+                        }
+                        *form_data_submit.borrow_mut() = Some(data);
+                        c.quit();
+                    });
+                }
+                (false, Handler::WithOutcome(hdlr)) => {
+                    let hdlr = Rc::clone(hdlr);
+                    let back_to_menu_submit = Rc::clone(&back_to_menu);
+                    let goto_submit = Rc::clone(&goto);
+                    let submitted_submit = Rc::clone(&submitted);
+                    let succeeded_submit = Arc::clone(&succeeded);
+                    let title = self.strings.clone().unwrap_or_default().error;
+                    form_view.set_on_submit_outcome(move |c: &mut Cursive, data: Value| {
+                        *submitted_submit.borrow_mut() = Some(data.clone());
+                        let outcome = hdlr(data);
+                        succeeded_submit.store(true, Ordering::SeqCst);
+                        if let SubmitOutcome::GoTo(ref path, ref data) = outcome {
+                            // the form stays open, so mistakes of handler don't end the program
+                            if let Some(e) = goto_error(&paths, path, data) {
+                                c.add_layer(Dialog::info(e).title(title.clone()));
+                                return SubmitOutcome::KeepOpen;
+                            }
+                        }
+                        match outcome {
+                            SubmitOutcome::Close => c.quit(),
+                            SubmitOutcome::KeepOpen => (),
+                            SubmitOutcome::ReturnToMenu => {
+                                back_to_menu_submit.set(true);
+                                c.quit();
+                            }
+                            SubmitOutcome::GoTo(ref action, ref data) => {
+                                *goto_submit.borrow_mut() = Some((action.clone(), data.clone()));
+                                c.quit();
+                            }
+                        }
+                        outcome
+                    });
+                }
+                (false, Handler::WithResult(hdlr)) => {
+                    let hdlr = Rc::clone(hdlr);
+                    let strings = self.strings.clone().unwrap_or_default();
+                    let submitted_submit = Rc::clone(&submitted);
+                    let succeeded_submit = Arc::clone(&succeeded);
+                    form_view.set_on_submit(move |c: &mut Cursive, data: Value| {
+                        *submitted_submit.borrow_mut() = Some(data.clone());
+                        match hdlr(data) {
+                            Ok(()) => {
+                                succeeded_submit.store(true, Ordering::SeqCst);
+                                c.quit();
+                            }
+                            Err(e) => show_handler_error(c, e, &strings),
+                        }
+                    });
+                }
+                (false, Handler::WithOutput(hdlr)) => {
+                    let hdlr = Rc::clone(hdlr);
+                    let strings = self.strings.clone().unwrap_or_default();
+                    let back_to_menu_submit = Rc::clone(&back_to_menu);
+                    let submitted_submit = Rc::clone(&submitted);
+                    let succeeded_submit = Arc::clone(&succeeded);
+                    form_view.set_on_submit(move |c: &mut Cursive, data: Value| {
+                        *submitted_submit.borrow_mut() = Some(data.clone());
+                        let output = match hdlr(data) {
+                            Ok(output) => output,
+                            Err(e) => return show_handler_error(c, e, &strings),
+                        };
+                        succeeded_submit.store(true, Ordering::SeqCst);
+                        let back_to_menu = Rc::clone(&back_to_menu_submit);
+                        let dialog = Dialog::around(TextView::new(output_text(&output)))
+                            .title(strings.output.clone())
+                            .button(strings.run_another.clone(), move |c| {
+                                // the form must be on top, so it's put back for later
+                                c.pop_layer();
+                                back_to_menu.set(true);
+                                c.quit();
+                            })
+                            .button(strings.quit.clone(), |c| c.quit());
+                        c.add_layer(dialog);
+                    });
+                }
+                (false, Handler::InBackground(hdlr)) => {
+                    let hdlr = Arc::clone(hdlr);
+                    let strings = self.strings.clone().unwrap_or_default();
+                    let submitted_submit = Rc::clone(&submitted);
+                    let succeeded_submit = Arc::clone(&succeeded);
+                    form_view.set_on_submit(move |c: &mut Cursive, data: Value| {
+                        *submitted_submit.borrow_mut() = Some(data.clone());
+                        let hdlr = Arc::clone(&hdlr);
+                        let succeeded = Arc::clone(&succeeded_submit);
+                        let strings = strings.clone();
+                        let sink = c.cb_sink().clone();
+                        let (progress, view) = Progress::with_view();
+                        c.add_layer(Dialog::around(view).title(strings.working.clone()));
+                        // refreshing updates progress & picks up handler's result sent to `sink`
+                        c.set_fps(BACKGROUND_FPS);
+                        thread::spawn(move || {
+                            let result = hdlr(data, progress);
+                            let done = move |c: &mut Cursive| {
+                                c.set_fps(0);
+                                c.pop_layer();
+                                match result {
+                                    Ok(()) => {
+                                        succeeded.store(true, Ordering::SeqCst);
+                                        c.quit();
+                                    }
+                                    Err(e) => show_handler_error(c, e, &strings),
+                                }
+                            };
+                            sink.send(Box::new(done)).ok();
+                        });
+                    });
+                }
+            }
+            form_view.set_on_cancel(move |c: &mut Cursive| {
+                //TODO: this should return to action picker
+                c.quit();
+            });
+            c.add_layer(form_view.full_width());
+            if let Some(e) = prefill_error {
+                let title = self.strings.clone().unwrap_or_default().error;
+                c.add_layer(Dialog::info(e).title(title));
+            }
+            c.run();
+
+            let goto = goto.borrow_mut().take();
+            if back_to_menu.get() || goto.is_some() || self.show_cli_equivalent {
+                // form is put back, so the action can be picked again (or its data converted)
+                while let Some(mut layer) = c.pop_layer() {
+                    if let Some(form) = layer.as_any_mut().downcast_mut::<BoxView<FormView>>() {
+                        action.form = Some(mem::take(form.get_inner_mut()));
+                        break;
+                    }
+                }
+            }
+            let submitted = submitted.borrow_mut().take();
+            if self.show_cli_equivalent {
+                if let Some(data) = submitted.as_ref().or(form_data.borrow().as_ref()) {
+                    let cmd = self.cli_equivalent(&selection, data);
+                    self.cli_equivalents.push(cmd);
+                }
+            }
+            if let Some(data) = submitted {
+                self.save_state(&selection, &data);
+            }
+            // draft is kept, if handler failed (and form was left without fixing it)
+            if succeeded.load(Ordering::SeqCst) {
+                self.remove_draft(&selection);
+            }
+            if let Some((path, data)) = goto {
+                // it's checked with `goto_error` on submit
+                picked = self.action_key(&path);
+                prefill = Some(data);
+                continue;
+            }
+            if back_to_menu.get() {
+                continue;
+            }
+            let form_data = form_data.borrow().clone();
+            return form_data.map(|data| (selection, data));
+        }
+    }
+
+    /// Makes TUI start from action's form (without actions' picker) if there is only one action.
+    ///
+    /// Getting back to the picker (see [SubmitOutcome::ReturnToMenu]) opens the form again.
+    ///
+    /// [SubmitOutcome::ReturnToMenu]: form/enum.SubmitOutcome.html#variant.ReturnToMenu
+    pub fn skip_single_picker(mut self) -> Self {
+        self.skip_single_picker = true;
+        self
+    }
+
+    /// Sets program's `name.
+    ///
+    /// For CLI means [Clap::App::name]
+    ///
+    /// [clap::App::name]: ../clap/struct.App.html#method.name
+    pub fn name(mut self, name: &'attrs str) -> Self {
+        self.name = name;
+        self
+    }
+
+    /// Sets program's `version`.
+    ///
+    /// For CLI means [Clap::App::version]
+    ///
+    /// [clap::App::version]: ../clap/struct.App.html#method.version
+    pub fn version(mut self, version: &'attrs str) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets program's `about`.
+    ///
+    /// For CLI means [Clap::App::about]
+    ///
+    /// [clap::App::about]: ../clap/struct.App.html#method.about
+    pub fn about(mut self, about: &'attrs str) -> Self {
+        self.about = about;
+        self
+    }
+
+    /// Sets built-in texts & translations of messages of all forms (and actions' picker).
+    pub fn strings(mut self, strings: Strings) -> Self {
+        self.strings = Some(strings);
+        self
+    }
+
+    /// Sets keys triggering actions of all forms (and actions' picker).
+    pub fn keybindings(mut self, keybindings: KeyBindings) -> Self {
+        self.keybindings = Some(keybindings);
+        self
+    }
+
+    /// Sets [theme] (colors, borders, shadows) of TUI.
+    ///
+    /// [theme]: ../cursive/theme/struct.Theme.html
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Sets `styles` of fields' labels & errors (by validation state & focus) of all forms.
+    pub fn field_styles(mut self, styles: FieldStyles) -> Self {
+        self.field_styles = Some(styles);
+        self
+    }
+
+    /// Sets theme of TUI loaded from [toml file] at `path`.
+    ///
+    /// ```no_run
+    /// # extern crate fui;
+    /// # use fui::Fui;
+    /// # fn main() {
+    /// let app = Fui::new().theme_file("theme.toml").expect("invalid theme");
+    /// # }
+    /// ```
+    ///
+    /// [toml file]: ../cursive/theme/index.html#themes
+    pub fn theme_file<P: AsRef<Path>>(self, path: P) -> Result<Self, theme::Error> {
+        let theme = theme::load_theme_file(path)?;
+        Ok(self.theme(theme))
+    }
+
+    /// Reads defaults of fields from config file (TOML or JSON, picked by its extension), they
+    /// take place of initial values of fields in TUI & CLI (see [FormView::set_defaults]), so
+    /// CLI arguments override config, which overrides initial values.
+    ///
+    /// Top level keys are labels of fields of every action, table named like action (or its
+    /// group, containing table named like the action) sets defaults of the action only. Missing
+    /// file is like an empty one, `~` at the beginning of `path` means home dir.
+    ///
+    /// ```toml
+    /// verbose = true
+    ///
+    /// [archive]
+    /// level = 9
+    ///
+    /// [remote.add]
+    /// url = "https://example.com"
+    /// ```
+    ///
+    /// [FormView::set_defaults]: form/struct.FormView.html#method.set_defaults
+    pub fn config_file<P: AsRef<Path>>(mut self, path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        let path = match path.strip_prefix("~") {
+            Ok(rest) => Path::new(&utils::home_dir()).join(rest),
+            Err(_) => path.to_path_buf(),
+        };
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(self),
+            Err(e) => return Err(format!("Can't read {}: {}", path.display(), e)),
+        };
+        let config = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => serde_json::from_str::<Value>(&content).map_err(|e| e.to_string()),
+            Some("toml") => toml::from_str::<Value>(&content).map_err(|e| e.to_string()),
+            _ => Err("Config file must be .toml or .json".to_string()),
+        };
+        match config {
+            Ok(Value::Object(config)) => self.config = config,
+            Ok(_) => return Err(format!("{}: Config must be a table", path.display())),
+            Err(e) => return Err(format!("{}: {}", path.display(), e)),
+        }
+        Ok(self)
+    }
+
+    /// Gets defaults of `action`'s fields from config.
+    fn config_for(&self, action: &Action) -> Value {
+        let sections = self.actions
+            .values()
+            .map(|a| a.group.unwrap_or(a.name))
+            .collect::<Vec<&str>>();
+        let mut defaults = self.config
+            .iter()
+            .filter(|entry| !sections.contains(&entry.0.as_str()))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<Map<String, Value>>();
+        let section = match action.group {
+            Some(group) => self.config.get(group).and_then(|g| g.get(action.name)),
+            None => self.config.get(action.name),
+        };
+        if let Some(section) = section.and_then(|s| s.as_object()) {
+            defaults.extend(section.clone());
+        }
+        Value::Object(defaults)
+    }
+
+    /// Sets defaults from config in forms of all actions.
+    fn apply_config(&mut self) {
+        if self.config.is_empty() {
+            return;
+        }
+        let defaults = self.actions
+            .iter()
+            .map(|(key, action)| (key.clone(), self.config_for(action)))
+            .collect::<Vec<(String, Value)>>();
+        for (key, defaults) in defaults {
+            if let Some(form) = self.actions.get_mut(&key).unwrap().form.as_mut() {
+                form.set_defaults(&defaults).unwrap();
+            }
+        }
+    }
+
+    /// Sets program's `author`.
+    ///
+    /// For CLI means [Clap::App::author]
+    ///
+    /// [clap::App::author]: ../clap/struct.App.html#method.author
+    pub fn author(mut self, author: &'attrs str) -> Self {
+        self.author = author;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cli_checkbox_is_serialized_ok_when_value_preset() {
+        let value = Fui::new()
+            .action(
+                "action1",
+                "desc",
+                FormView::new().field(fields::Checkbox::new("ch1")),
+                |_| {},
+            )
+            .input_from_cli(vec!["my_app", "action1", "--ch1"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "ch1": true }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_checkbox_is_serialized_ok_when_value_missing() {
+        let value = Fui::new()
+            .action(
+                "action1",
+                "desc",
+                FormView::new().field(fields::Checkbox::new("ch1")),
+                |_| {},
+            )
+            .input_from_cli(vec!["my_app", "action1"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "ch1": false }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_checkbox_is_serialized_ok_when_negated() {
+        let value = Fui::new()
+            .action(
+                "action1",
+                "desc",
+                FormView::new().field(fields::Checkbox::new("ch1").initial(true)),
+                |_| {},
+            )
+            .input_from_cli(vec!["my_app", "action1", "--no-ch1"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "ch1": false }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_checkbox_is_serialized_ok_when_value_missing_and_initial_set() {
+        let value = Fui::new()
+            .action(
+                "action1",
+                "desc",
+                FormView::new().field(fields::Checkbox::new("ch1").initial(true)),
+                |_| {},
+            )
+            .input_from_cli(vec!["my_app", "action1"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "ch1": true }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_checkbox_last_flag_wins() {
+        let fui = Fui::new().action(
+            "action1",
+            "desc",
+            FormView::new().field(fields::Checkbox::new("ch1")),
+            |_| {},
+        );
+
+        let value = fui.input_from_cli(vec!["my_app", "action1", "--ch1", "--no-ch1"]);
+        let exp: Value = serde_json::from_str(r#"{ "ch1": false }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+
+        let value = fui.input_from_cli(vec!["my_app", "action1", "--no-ch1", "--ch1"]);
+        let exp: Value = serde_json::from_str(r#"{ "ch1": true }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_action_with_outcome_calls_handler() {
+        let called = Rc::new(Cell::new(false));
+        let called_hdlr = Rc::clone(&called);
+        let fui = Fui::new().action_with_outcome(
+            "action1",
+            "desc",
+            FormView::new().field(fields::Checkbox::new("ch1")),
+            move |_| {
+                called_hdlr.set(true);
+                SubmitOutcome::KeepOpen
+            },
+        );
+
+        let (action, data) = fui.input_from_cli(vec!["my_app", "action1", "--ch1"]).unwrap();
+        let exp: Value = serde_json::from_str(r#"{ "ch1": true }"#).unwrap();
+        assert_eq!(data, exp);
+        fui.actions.get(&action).unwrap().handler.call(data).unwrap();
+        assert!(called.get());
+    }
+
+    #[test]
+    fn handler_gets_action_name() {
+        let called = Rc::new(RefCell::new(Vec::new()));
+        let called_hdlr = Rc::clone(&called);
+        let hdlr = move |action: &str, _| called_hdlr.borrow_mut().push(action.to_string());
+        let hdlr = Rc::new(hdlr);
+        let (start, stop) = (Rc::clone(&hdlr), Rc::clone(&hdlr));
+        let fui = Fui::new()
+            .action_with_name("start", "desc", FormView::new(), move |a, d| start(a, d))
+            .group("remote", "Manages remotes", |fui| {
+                fui.action_with_name("stop", "desc", FormView::new(), move |a, d| stop(a, d))
+            });
+
+        for args in [vec!["my_app", "start"], vec!["my_app", "remote", "stop"]].iter() {
+            let (action, data) = fui.input_from_cli(args.clone()).unwrap();
+            fui.actions.get(&action).unwrap().handler.call(data).unwrap();
+        }
+        assert_eq!(*called.borrow(), vec!["start", "remote stop"]);
+    }
+
+    #[test]
+    fn cli_result_handler_returns_error() {
+        let fui = Fui::new().action_with_result(
+            "action1",
+            "desc",
+            FormView::new().field(fields::Checkbox::new("ch1")),
+            |data: Value| match data["ch1"].as_bool() {
+                Some(true) => Ok(()),
+                _ => Err("ch1 must be checked"),
+            },
+        );
+
+        let (action, data) = fui.input_from_cli(vec!["my_app", "action1", "--ch1"]).unwrap();
+        assert_eq!(fui.actions.get(&action).unwrap().handler.call(data), Ok(()));
+        let (action, data) = fui.input_from_cli(vec!["my_app", "action1"]).unwrap();
+        assert_eq!(
+            fui.actions.get(&action).unwrap().handler.call(data),
+            Err("ch1 must be checked".to_string())
+        );
+    }
+
+    #[test]
+    fn cli_background_handler_is_awaited() {
+        let fui = Fui::new().action_in_background(
+            "action1",
+            "desc",
+            FormView::new().field(fields::Text::new("host")),
+            |data: Value| match data["host"].as_str() {
+                Some("localhost") => Ok(()),
+                _ => Err("unknown host"),
+            },
+        );
+
+        let (action, data) = fui.input_from_cli(vec!["my_app", "action1", "--host", "localhost"])
+            .unwrap();
+        assert_eq!(fui.actions.get(&action).unwrap().handler.call(data), Ok(()));
+        let (action, data) = fui.input_from_cli(vec!["my_app", "action1", "--host", "far"])
+            .unwrap();
+        assert_eq!(
+            fui.actions.get(&action).unwrap().handler.call(data),
+            Err("unknown host".to_string())
+        );
+    }
+
+    #[test]
+    fn output_is_formatted_by_its_type() {
+        assert_eq!(output_text(&Value::from("line 1\nline 2")), "line 1\nline 2");
+        let output: Value = serde_json::from_str(r#"{ "size": 3 }"#).unwrap();
+        assert_eq!(output_text(&output), "{\n  \"size\": 3\n}");
+    }
+
+    #[test]
+    fn last_action_is_run_again() {
+        let dir = env::temp_dir().join(format!("fui-last-action-{}", process::id()));
+        let fui = Fui::new()
+            .group("remote", "Manages remotes", |fui| {
+                fui.action("add", "desc", FormView::new().field(fields::Text::new("url")), |_| {})
+            })
+            .remember_last_in(dir.join("last.json"));
+        assert_eq!(fui.load_last(), None);
+        assert_eq!(fui.repeat_last_item(), None);
+
+        let (action, data) = fui.input_from_cli(vec!["my_app", "remote", "add", "--url", "x"])
+            .unwrap();
+        fui.save_last(&action, &data);
+        assert_eq!(fui.repeat_last_item(), Some("Repeat last: remote add".to_string()));
+        let again = fui.input_from_cli(vec!["my_app", "--again"]);
+        ::std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(again, Some((action, data)));
+    }
+
+    #[test]
+    fn submitted_values_are_kept_in_history() {
+        let dir = env::temp_dir().join(format!("fui-history-{}", process::id()));
+        let form = FormView::new()
+            .field(fields::Text::new("host"))
+            .field(fields::Password::new("pass"));
+        let fui = Fui::new()
+            .action("login", "desc", form, |_| {})
+            .remember_history_in(dir.join("history.json"));
+
+        for host in &["a.org", "b.org", "a.org"] {
+            let args = vec!["my_app", "login", "--host", host, "--pass", "secret"];
+            let (action, data) = fui.input_from_cli(args).unwrap();
+            fui.save_state(&action, &data);
+        }
+        let history = fui.load_history();
+        ::std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(history["host"], json!(["a.org", "b.org"]));
+        assert!(!history.contains_key("pass"));
+    }
+
+    #[test]
+    fn draft_is_removed_after_submit() {
+        let dir = env::temp_dir().join(format!("fui-drafts-{}", process::id()));
+        let form = FormView::new().field(fields::Text::new("host"));
+        let fui = Fui::new()
+            .group("remote", "Manages remotes", |fui| fui.action("add", "desc", form, |_| {}))
+            .remember_drafts_in(dir.join("drafts.json"));
+        let file = fui.drafts_file().unwrap();
+        save_draft_to(&file, "remote add", Some(json!({"host": "a.o"}))).unwrap();
+        save_draft_to(&file, "other", Some(json!({}))).unwrap();
+        assert_eq!(fui.load_drafts()["remote add"], json!({"host": "a.o"}));
+
+        let (action, data) = fui.input_from_cli(vec!["my_app", "remote", "add", "--host", "a"])
+            .unwrap();
+        // handler isn't run yet
+        fui.save_state(&action, &data);
+        assert!(fui.load_drafts().contains_key("remote add"));
+        fui.remove_draft(&action);
+        let drafts = fui.load_drafts();
+        ::std::fs::remove_dir_all(&dir).unwrap();
+        assert!(!drafts.contains_key("remote add"));
+        assert!(drafts.contains_key("other"));
+    }
+
+    #[test]
+    fn config_file_sets_defaults() {
+        let path = env::temp_dir().join(format!("fui-config-{}.toml", process::id()));
+        let config = "verbose = true\nhost = \"all\"\n[add]\nhost = \"a.org\"\n[remote.rm]\n";
+        ::std::fs::write(&path, config).unwrap();
+        let form = || {
+            FormView::new()
+                .field(fields::Text::new("host").validator(Required))
+                .field(fields::Checkbox::new("verbose"))
+        };
+        let mut fui = Fui::new()
+            .action("add", "desc", form(), |_| {})
+            .group("remote", "Manages remotes", |fui| fui.action("rm", "desc", form(), |_| {}))
+            .config_file(&path)
+            .unwrap();
+        ::std::fs::remove_file(&path).unwrap();
+        fui.apply_config();
+
+        let (_, data) = fui.input_from_cli(vec!["my_app", "add"]).unwrap();
+        assert_eq!(data, json!({"host": "a.org", "verbose": true}));
+        let (_, data) = fui.input_from_cli(vec!["my_app", "remote", "rm", "--no-verbose"])
+            .unwrap();
+        assert_eq!(data, json!({"host": "all", "verbose": false}));
+        assert!(Fui::new().config_file("missing.toml").unwrap().config.is_empty());
+        assert!(Fui::new().config_file("Cargo.lock").is_err());
+    }
+
+    #[test]
+    fn stdin_json_is_validated_by_form() {
+        let form = FormView::new()
+            .field(fields::Text::new("url").validator(Required))
+            .field(fields::Checkbox::new("verbose"));
+        let mut fui = Fui::new().group("remote", "Manages remotes", |fui| {
+            fui.action("add", "desc", form, |_| {})
+        });
+
+        let payload = r#"{"action": "remote add", "data": {"url": "x", "verbose": true}}"#;
+        let (action, data) = fui.input_from_json(payload.as_bytes()).unwrap();
+        assert_eq!(fui.actions[&action].path(), "remote add");
+        assert_eq!(data, json!({"url": "x", "verbose": true}));
+
+        let payload = r#"{"action": "remote add", "data": {"url": ""}}"#;
+        let error = fui.input_from_json(payload.as_bytes()).unwrap_err();
+        assert!(error.starts_with("url: "), "{}", error);
+        let payload = r#"{"action": "remote rm"}"#;
+        assert!(fui.input_from_json(payload.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn dry_run_payload_can_be_read_from_stdin() {
+        let form = FormView::new().field(fields::Text::new("url"));
+        let mut fui = Fui::new().group("remote", "Manages remotes", |fui| {
+            fui.action("add", "desc", form, |_| panic!("handler mustn't be run"))
+        });
+        let args = vec!["my_app", "--dry-run", "remote", "add", "--url", "x"];
+        let matches = fui.build_cli_app().get_matches_from(args.clone());
+        assert!(matches.is_present(DRY_RUN_ARG));
+
+        let (action, data) = fui.input_from_cli(args).unwrap();
+        let payload = fui.payload(&action, &data).to_string();
+        assert_eq!(fui.input_from_json(payload.as_bytes()), Ok((action, data)));
+    }
+
+    #[test]
+    fn cli_equivalent_gives_the_same_data() {
+        let form = FormView::new()
+            .field(fields::Text::new("url"))
+            .field(fields::Checkbox::new("verbose"))
+            .field(fields::Multiselect::new("tags", vec!["a", "b", "c"]));
+        let fui = Fui::new()
+            .name("my_app")
+            .group("remote", "Manages remotes", |fui| {
+                fui.action("add", "desc", form, |_| {})
+            });
+        let (action, data) = fui.input_from_cli(vec![
+            "my_app", "remote", "add", "--url", "it's here", "--tags", "a", "--tags", "c",
+        ]).unwrap();
+
+        let cmd = fui.cli_equivalent(&action, &data);
+        assert_eq!(
+            cmd,
+            r#"my_app remote add --url 'it'\''s here' --no-verbose --tags a --tags c"#
+        );
+        let value = fui.input_from_cli(vec![
+            "my_app", "remote", "add", "--url", "it's here", "--no-verbose", "--tags", "a",
+            "--tags", "c",
+        ]);
+        assert_eq!(value, Some((action, data)));
+    }
+
+    #[test]
+    fn cli_failures_set_exit_code() {
+        let form = FormView::new().field(fields::Text::new("host").validator(Required));
+        let fui = Fui::new().action("login", "desc", form, |_| {});
+        assert_eq!(fui.input_from_cli(vec!["my_app", "login", "--host", ""]), None);
+        assert_eq!(fui.exit_code.get(), VALIDATION_EXIT_CODE);
+
+        let form = FormView::new().field(fields::Text::new("host"));
+        let fui = Fui::new().action_with_exit_code("ping", "desc", form, |data| {
+            if data["host"] == "up" { 0 } else { 3 }
+        });
+        let (action, data) = fui.input_from_cli(vec!["my_app", "ping", "--host", "down"])
+            .unwrap();
+        let handler = &fui.actions[&action].handler;
+        assert_eq!(handler.exit_code(data.clone()), 3);
+        assert!(handler.call(data).is_err());
+        assert_eq!(handler.exit_code(json!({"host": "up"})), 0);
+    }
+
+    #[test]
+    fn goto_to_missing_action_is_an_error() {
+        let fui = Fui::new()
+            .action("add", "desc", FormView::new().field(fields::Text::new("url")), |_| {})
+            .group("remote", "Manages remotes", |fui| {
+                fui.action("rm", "desc", FormView::new(), |_| {})
+            });
+        let paths = fui.actions.values().map(|a| a.path()).collect::<Vec<String>>();
+        assert_eq!(goto_error(&paths, "remote rm", &json!({})), None);
+        assert_eq!(
+            goto_error(&paths, "remote add", &json!({})),
+            Some(r#"There is no action "remote add""#.to_string())
+        );
+        assert!(goto_error(&paths, "add", &json!("x")).is_some());
+    }
+
+    fn confirm_fui<'a, 'b>() -> Fui<'a, 'b> {
+        Fui::new().action(
+            "action1",
+            "desc",
+            FormView::new().field(fields::Checkbox::new("sure")),
+            |_| {},
+        )
+    }
+
+    #[test]
+    fn cli_confirm_is_answered_with_yes() {
+        let value = confirm_fui().input_from_cli(vec!["my_app", "action1", "--yes"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "sure": true }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_confirm_is_unanswered_without_flags() {
+        let fui = confirm_fui();
+
+        assert_eq!(
+            fui.unanswered_confirm(vec!["my_app", "action1"]),
+            Some("action1: desc".to_string())
+        );
+        assert_eq!(fui.unanswered_confirm(vec!["my_app", "action1", "--yes"]), None);
+        assert_eq!(fui.unanswered_confirm(vec!["my_app", "action1", "--sure"]), None);
+        assert_eq!(fui.unanswered_confirm(vec!["my_app", "action1", "--no-sure"]), None);
+    }
+
+    #[test]
+    fn cli_yes_is_skipped_for_not_confirm_actions() {
+        let fui = Fui::new().action(
+            "action1",
+            "desc",
+            FormView::new()
+                .field(fields::Checkbox::new("ch1"))
+                .field(fields::Checkbox::new("ch2")),
+            |_| {},
+        );
+
+        assert_eq!(fui.unanswered_confirm(vec!["my_app", "action1"]), None);
+        let matches = fui.build_cli_app()
+            .get_matches_from_safe(vec!["my_app", "action1", "--yes"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn cli_yes_is_skipped_for_form_of_hidden_field() {
+        let fui = Fui::new().action(
+            "action1",
+            "desc",
+            FormView::new().field(fields::Hidden::new("version", 2)),
+            |_| {},
+        );
+
+        assert_eq!(fui.unanswered_confirm(vec!["my_app", "action1"]), None);
+        let value = fui.input_from_cli(vec!["my_app", "action1"]);
+        assert_eq!(value, Some(("action1: desc".to_string(), json!({"version": 2}))));
+    }
+
+    #[test]
+    fn cli_password_is_serialized_ok_when_value_preset() {
+        let value = Fui::new()
+            .action(
+                "action1",
+                "desc",
+                FormView::new().field(fields::Password::new("pass")),
+                |_| {},
+            )
+            .input_from_cli(vec!["my_app", "action1", "--pass", "secret"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "pass": "secret" }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_number_is_serialized_ok_when_negative() {
+        let value = Fui::new()
+            .action(
+                "action1",
+                "desc",
+                FormView::new().field(fields::Number::integer("n")),
+                |_| {},
+            )
+            .input_from_cli(vec!["my_app", "action1", "--n", "-3"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "n": -3 }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_select_is_serialized_ok() {
+        let fui = || {
+            Fui::new().action(
+                "action1",
+                "desc",
+                FormView::new().field(fields::Select::new("s", vec![("A", "a"), ("B", "b")])),
+                |_| {},
+            )
+        };
+
+        let value = fui().input_from_cli(vec!["my_app", "action1", "--s", "b"]);
+        let exp: Value = serde_json::from_str(r#"{ "s": "b" }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+
+        let value = fui().input_from_cli(vec!["my_app", "action1"]);
+        let exp: Value = serde_json::from_str(r#"{ "s": "a" }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+
+        let fui = fui();
+        let matches = fui.build_cli_app()
+            .get_matches_from_safe(vec!["my_app", "action1", "--s", "c"]);
+        assert!(matches.is_err());
+    }
+
+    #[test]
+    fn cli_slider_uses_initial_when_value_missing() {
+        let fui = Fui::new().action(
+            "action1",
+            "desc",
+            FormView::new().field(fields::Slider::new("level", 1, 9, 1)),
+            |_| {},
+        );
+
+        let value = fui.input_from_cli(vec!["my_app", "action1"]);
+        let exp: Value = serde_json::from_str(r#"{ "level": 1 }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_tags_are_serialized_ok() {
+        let value = Fui::new()
+            .action(
+                "action1",
+                "desc",
+                FormView::new().field(fields::Tags::new("host")),
+                |_| {},
+            )
+            .input_from_cli(vec!["my_app", "action1", "--host", "h1", "--host", "h2"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "host": ["h1", "h2"] }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_key_values_are_serialized_ok() {
+        let value = Fui::new()
+            .action(
+                "action1",
+                "desc",
+                FormView::new().field(fields::KeyValue::new("header")),
+                |_| {},
+            )
+            .input_from_cli(vec![
+                "my_app", "action1", "--header", "Accept=*/*", "--header", "X-Id=1",
+            ]);
+
+        let exp: Value =
+            serde_json::from_str(r#"{ "header": { "Accept": "*/*", "X-Id": "1" } }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_hidden_is_serialized_ok() {
+        let value = Fui::new()
+            .action(
+                "action1",
+                "desc",
+                FormView::new()
+                    .field(fields::Hidden::new("id", "action-1"))
+                    .field(fields::Text::new("t1")),
+                |_| {},
+            )
+            .input_from_cli(vec!["my_app", "action1", "--t1", "v1"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "id": "action-1", "t1": "v1" }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_repeated_is_serialized_ok() {
+        let value = Fui::new()
+            .action(
+                "action1",
+                "desc",
+                FormView::new().field(fields::Repeated::new(fields::Text::new("file"))),
+                |_| {},
+            )
+            .input_from_cli(vec!["my_app", "action1", "--file", "a", "--file", "b"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "file": ["a", "b"] }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_text_is_serialized_ok_when_value_preset() {
+        let value = Fui::new()
+            .action(
+                "action1",
+                "desc",
+                FormView::new().field(fields::Text::new("t1")),
+                |_| {},
+            )
+            .input_from_cli(vec!["my_app", "action1", "--t1", "v1"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "t1": "v1" }"#).unwrap();
+        assert_eq!(value, Some(("action1: desc".to_string(), exp)));
+    }
+
+    //#[test]
     //fn cli_text_is_serialized_ok_when_value_missing() {
     //    // clap blocks this case, optionally test ensuring that
     //}
@@ -469,4 +2785,222 @@ mod tests {
     //fn cli_multiselect_is_serialized_ok_when_value_missing() {
     //    // clap blocks this case, optionally test ensuring that
     //}
+
+    #[test]
+    fn theme_is_loaded_from_file() {
+        let path = env::temp_dir().join("fui_theme_is_loaded_from_file.toml");
+        ::std::fs::write(&path, "shadow = false\n[colors]\nview = \"black\"\n").unwrap();
+        let app = Fui::new().theme_file(&path).unwrap();
+        ::std::fs::remove_file(&path).unwrap();
+        let theme = app.theme.unwrap();
+        assert!(!theme.shadow);
+        assert!(Fui::new().theme_file(&path).is_err());
+        assert!(Fui::new().theme(theme).theme.is_some());
+    }
+
+    #[test]
+    fn typed_action_gets_deserialized_data() {
+        use std::collections::HashMap;
+
+        let got = Rc::new(RefCell::new(None));
+        let got_clone = Rc::clone(&got);
+        let app = Fui::new().action_typed(
+            "action1",
+            "desc",
+            FormView::new().field(fields::Number::integer("level")),
+            move |args: HashMap<String, u64>| *got_clone.borrow_mut() = Some(args["level"]),
+        );
+        let action = app.actions.get("action1: desc").unwrap();
+        let check = action.check.as_ref().unwrap();
+        let valid: Value = serde_json::from_str(r#"{ "level": 3 }"#).unwrap();
+        let invalid: Value = serde_json::from_str(r#"{ "level": "high" }"#).unwrap();
+        assert!(check(&valid).is_ok());
+        assert!(check(&invalid).is_err());
+
+        assert_eq!(action.handler.exit_code(invalid), VALIDATION_EXIT_CODE);
+        assert_eq!(*got.borrow(), None);
+        let (_, data) = app.input_from_cli(vec!["my_app", "action1", "--level", "3"])
+            .unwrap();
+        action.handler.call(data).unwrap();
+        assert_eq!(*got.borrow(), Some(3));
+    }
+
+    #[test]
+    fn cli_typed_action_fails_with_validation_exit_code() {
+        use std::collections::HashMap;
+
+        let app = Fui::new().action_typed(
+            "action1",
+            "desc",
+            FormView::new().field(fields::Number::integer("level")),
+            |_: HashMap<String, u64>| panic!("handler mustn't be run"),
+        );
+        let data = json!({"level": -3});
+        assert_eq!(app.actions["action1: desc"].handler.exit_code(data), VALIDATION_EXIT_CODE);
+        assert_eq!(app.input_from_cli(vec!["my_app", "action1", "--level", "-3"]), None);
+        assert_eq!(app.exit_code.get(), VALIDATION_EXIT_CODE);
+    }
+
+    #[test]
+    fn model_action_rejects_the_same_data_in_cli_and_stdin_json() {
+        struct Level(u64);
+        impl FormModel for Level {
+            fn form() -> FormView {
+                FormView::new().field(fields::Number::integer("level"))
+            }
+            fn from_data(data: &Value) -> Result<Self, String> {
+                data["level"]
+                    .as_u64()
+                    .map(Level)
+                    .ok_or_else(|| "level must be positive".to_string())
+            }
+        }
+
+        let got = Rc::new(Cell::new(0));
+        let got_clone = Rc::clone(&got);
+        let mut app = Fui::new()
+            .action_model("action1", "desc", move |level: Level| got_clone.set(level.0));
+        assert_eq!(app.input_from_cli(vec!["my_app", "action1", "--level", "-3"]), None);
+        assert_eq!(app.exit_code.get(), VALIDATION_EXIT_CODE);
+        let payload = r#"{"action": "action1", "data": {"level": -3}}"#;
+        let error = app.input_from_json(payload.as_bytes()).unwrap_err();
+        assert_eq!(error, "level must be positive");
+
+        let (action, data) = app.input_from_cli(vec!["my_app", "action1", "--level", "3"])
+            .unwrap();
+        assert_eq!(app.actions[&action].handler.exit_code(data), 0);
+        assert_eq!(got.get(), 3);
+    }
+
+    #[test]
+    fn cli_command_replaces_generated_args() {
+        let command = clap::SubCommand::with_name("archive")
+            .arg(clap::Arg::with_name("target").short("t").takes_value(true))
+            .arg(clap::Arg::with_name("verbose").short("v"));
+        let form = FormView::new()
+            .field(fields::Text::new("target"))
+            .field(fields::Checkbox::new("verbose"));
+        let value = Fui::new()
+            .action("archive", "desc", form, |_| {})
+            .cli_command("archive", command)
+            .input_from_cli(vec!["my_app", "archive", "-t", "a.tar", "-v"]);
+
+        let exp: Value = serde_json::from_str(r#"{ "target": "a.tar", "verbose": true }"#).unwrap();
+        assert_eq!(value, Some(("archive: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn completions_cover_actions_and_args() {
+        let app = Fui::new()
+            .name("my_app")
+            .action(
+                "archive",
+                "desc",
+                FormView::new().field(fields::Checkbox::new("verbose")),
+                |_| {},
+            )
+            .action("extract", "desc", FormView::new(), |_| {});
+
+        let script = app.gen_completions(Shell::Bash);
+        assert!(script.contains("my_app"));
+        assert!(script.contains("archive"));
+        assert!(script.contains("extract"));
+        assert!(script.contains("--verbose"));
+
+        let shell = app.completions_shell(vec!["my_app", "--completions", "zsh"]);
+        assert_eq!(shell.map(|s| s.to_string()), Some("ZSH".to_string()));
+        assert!(app.completions_shell(vec!["my_app", "archive"]).is_none());
+    }
+
+    #[test]
+    fn cli_positional_fields_are_read_in_order() {
+        let form = FormView::new()
+            .field(fields::Text::new("archive").positional())
+            .field(fields::Checkbox::new("verbose"))
+            .field(fields::Text::new("dest").positional());
+        let value = Fui::new()
+            .action("extract", "desc", form, |_| {})
+            .input_from_cli(vec!["my_app", "extract", "a.tar", "--verbose", "/tmp"]);
+
+        let exp = json!({"archive": "a.tar", "verbose": true, "dest": "/tmp"});
+        assert_eq!(value, Some(("extract: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_short_flags_are_accepted() {
+        let form = FormView::new()
+            .field(fields::Text::new("target").short('t'))
+            .field(fields::Checkbox::new("verbose").short('v'));
+        let value = Fui::new()
+            .action("archive", "desc", form, |_| {})
+            .input_from_cli(vec!["my_app", "archive", "-t", "a.tar", "-v"]);
+
+        let exp = json!({"target": "a.tar", "verbose": true});
+        assert_eq!(value, Some(("archive: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_actions_in_groups_are_nested_subcommands() {
+        let app = Fui::new()
+            .group("remote", "desc", |group| {
+                group
+                    .action("add", "desc", FormView::new().field(fields::Text::new("url")), |_| {})
+                    .action("remove", "desc", FormView::new(), |_| {})
+            })
+            .action("add", "desc", FormView::new().field(fields::Text::new("path")), |_| {});
+
+        let value = app.input_from_cli(vec!["my_app", "remote", "add", "--url", "x.org"]);
+        let exp = json!({"url": "x.org"});
+        assert_eq!(value, Some(("remote add: desc".to_string(), exp)));
+
+        let value = app.input_from_cli(vec!["my_app", "add", "--path", "a.txt"]);
+        let exp = json!({"path": "a.txt"});
+        assert_eq!(value, Some(("add: desc".to_string(), exp)));
+    }
+
+    #[test]
+    fn cli_accepts_action_aliases() {
+        let app = Fui::new()
+            .action("extract", "desc", FormView::new().field(fields::Text::new("f")), |_| {})
+            .alias("x")
+            .alias("ex")
+            .action("list", "desc", FormView::new(), |_| {});
+
+        for name in ["extract", "x", "ex"].iter() {
+            let value = app.input_from_cli(vec!["my_app", name, "--f", "a.tar"]);
+            assert_eq!(value, Some(("extract: desc".to_string(), json!({"f": "a.tar"}))));
+        }
+    }
+
+    #[test]
+    fn picker_is_skipped_for_single_action() {
+        let app = Fui::new().action("extract", "desc", FormView::new(), |_| {});
+        assert_eq!(app.only_action(), None);
+
+        let app = app.skip_single_picker();
+        assert_eq!(app.only_action(), Some("extract: desc".to_string()));
+
+        let app = app.action("list", "desc", FormView::new(), |_| {});
+        assert_eq!(app.only_action(), None);
+    }
+
+    #[test]
+    fn manpage_describes_actions_and_fields() {
+        let form = FormView::new()
+            .field(fields::Text::new("dst-dir").help("Where to extract\n.files"))
+            .field(fields::Checkbox::new("verbose"));
+        let page = Fui::new()
+            .name("my_app")
+            .version("1.0")
+            .about("Handles archives")
+            .action("extract", "Extracts archive", form, |_| {})
+            .gen_manpage();
+
+        assert!(page.starts_with(".TH \"MY_APP\" 1 \"\" \"my_app 1.0\"\n"));
+        assert!(page.contains(".SH NAME\nmy_app \\- Handles archives\n"));
+        assert!(page.contains(".SS extract\nExtracts archive\n"));
+        let dst_dir = ".TP\n\\fB\\-\\-dst\\-dir\\fR \\fI<dst\\-dir>\\fR\n";
+        assert!(page.contains(&format!("{}Where to extract\n.br\n\\&.files\n", dst_dir)));
+        assert!(page.contains(".TP\n\\fB\\-\\-no\\-verbose\\fR\n"));
+    }
 }
@@ -0,0 +1,487 @@
+//! Builds forms from declarative definitions, so forms can be tweaked without recompiling.
+//!
+//! Definition is a JSON document (or any [Value], e.g. YAML deserialized with `serde_yaml`):
+//!
+//! ```
+//! use fui::loader;
+//!
+//! let form = loader::from_str(r#"{
+//!     "title": "Archive",
+//!     "description": "Creates an archive",
+//!     "fields": [
+//!         {
+//!             "type": "autocomplete",
+//!             "label": "target",
+//!             "help": "Where to save the archive",
+//!             "feeder": "dirs",
+//!             "validators": ["required", {"path_free": null, "message": "Target exists"}]
+//!         },
+//!         {"type": "select", "label": "format", "options": [["tar", "tar"], ["zip", "zip"]]},
+//!         {"section": "Advanced", "fields": [
+//!             {"type": "integer", "label": "level", "initial": 6, "validators": [
+//!                 {"between": [1, 9]}
+//!             ]}
+//!         ]}
+//!     ]
+//! }"#).unwrap();
+//! ```
+//!
+//! Each field needs `type` and `label`, other keys are optional:
+//!
+//! * `help`, `width`, `env` (see [default_from_env]), `initial`, `validators` are common
+//! * `placeholder` for `text`, `password`, `autocomplete`, `duration`, `file` & `dir`
+//! * `options` (list of values or pairs: shown label & value) for `select` & `radio`
+//! * `feeder` (list of suggestions, `"dir_items"` or `"dirs"`) for `autocomplete` & `multiselect`
+//! * `min`, `max`, `step` for `integer`, `float` & `slider` (which needs `min` & `max`)
+//! * `extensions` for `file`, `text` for `static`, `value` for `hidden`
+//!
+//! Other types are: `multiline`, `checkbox`, `tags`, `date`, `time`, `datetime`, `key_value`.
+//!
+//! Validator is its name (e.g. `"required"`) or an object with its name and argument (e.g.
+//! `{"min_len": 3}`), which may replace validator's error with `message`. Names are the same as
+//! [validators] but lowercase with `_` (e.g. `dir_exists`).
+//!
+//! An item of `fields` with `section` (and its own `fields`) adds a [section].
+//!
+//! [Value]: ../enum.Value.html
+//! [default_from_env]: ../fields/struct.Field.html#method.default_from_env
+//! [validators]: ../validators/index.html
+//! [section]: ../form/struct.FormView.html#method.section
+use serde_json;
+use serde_json::map::Map;
+use serde_json::value::Value;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::rc::Rc;
+
+use feeders::{DirItems, Feeder};
+use fields::{Autocomplete, Checkbox, DatePicker, DateTime, Duration, Field, FilePicker,
+             FormField, Hidden, KeyValue, Multiline, Multiselect, Number, Password, RadioGroup,
+             Select, Slider, Static, Tags, Text, Time, WidgetManager};
+use form::FormView;
+use validators::{Between, DifferentFrom, DirExists, Email, FileExists, IsInteger, IsNumber, Max,
+                 MaxLen, Min, MinLen, OneOf, PathExists, PathFree, Regex, Required, SameAs, Url,
+                 Validator, Writable};
+
+type Spec = Map<String, Value>;
+
+/// Builds form from JSON `definition`.
+pub fn from_str(definition: &str) -> Result<FormView, String> {
+    let value = serde_json::from_str(definition).map_err(|e| e.to_string())?;
+    from_value(&value)
+}
+
+/// Builds form from JSON definition stored in file at `path`.
+pub fn from_file<P: AsRef<Path>>(path: P) -> Result<FormView, String> {
+    let path = path.as_ref();
+    let mut content = String::new();
+    File::open(path)
+        .and_then(|mut f| f.read_to_string(&mut content))
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    from_str(&content)
+}
+
+/// Builds form from `definition`.
+pub fn from_value(definition: &Value) -> Result<FormView, String> {
+    let spec = definition
+        .as_object()
+        .ok_or("Definition must be an object")?;
+    let mut form = FormView::new();
+    if let Some(title) = get_str(spec, "title")? {
+        form = form.title(title);
+    }
+    if let Some(description) = get_str(spec, "description")? {
+        form = form.description(description);
+    }
+    for (idx, item) in get_array(spec, "fields")?.iter().enumerate() {
+        let error = |e| format!("fields[{}]: {}", idx, e);
+        let item = item.as_object()
+            .ok_or_else(|| error("field must be an object".to_string()))?;
+        match get_str(item, "section").map_err(&error)? {
+            Some(name) => {
+                let fields = get_array(item, "fields")
+                    .and_then(build_fields)
+                    .map_err(&error)?;
+                form = form.section(name, fields);
+            }
+            None => form.add_boxed_field(build_field(item).map_err(&error)?),
+        }
+    }
+    Ok(form)
+}
+
+fn build_fields(items: &[Value]) -> Result<Vec<Box<FormField>>, String> {
+    let mut fields = Vec::new();
+    for (idx, item) in items.iter().enumerate() {
+        let field = item.as_object()
+            .ok_or("field must be an object".to_string())
+            .and_then(build_field)
+            .map_err(|e| format!("fields[{}]: {}", idx, e))?;
+        fields.push(field);
+    }
+    Ok(fields)
+}
+
+fn build_field(spec: &Spec) -> Result<Box<FormField>, String> {
+    let kind = get_str(spec, "type")?.ok_or("missing `type`")?;
+    let label = get_str(spec, "label")?.ok_or("missing `label`")?;
+    let placeholder = get_str(spec, "placeholder")?.unwrap_or("");
+    match kind {
+        "text" => finish(Text::new(label).placeholder(placeholder), spec, str_initial),
+        "password" => finish(Password::new(label).placeholder(placeholder), spec, str_initial),
+        "multiline" => finish(Multiline::new(label), spec, str_initial),
+        "checkbox" => finish(Checkbox::new(label), spec, bool_initial),
+        "integer" | "float" => {
+            let mut field = if kind == "integer" {
+                Number::integer(label)
+            } else {
+                Number::float(label)
+            };
+            if let Some(min) = get_f64(spec, "min")? {
+                field = field.min(min);
+            }
+            if let Some(max) = get_f64(spec, "max")? {
+                field = field.max(max);
+            }
+            if let Some(step) = get_f64(spec, "step")? {
+                field = field.step(step);
+            }
+            finish(field, spec, |f, v| {
+                v.as_f64().map(|v| f.initial(v)).ok_or("must be a number".to_string())
+            })
+        }
+        "slider" => {
+            let min = get_f64(spec, "min")?.ok_or("missing `min`")?;
+            let max = get_f64(spec, "max")?.ok_or("missing `max`")?;
+            let step = get_f64(spec, "step")?.unwrap_or(1.0);
+            if step <= 0.0 {
+                return Err("`step` must be positive".to_string());
+            }
+            if max < min {
+                return Err("`max` must not be less than `min`".to_string());
+            }
+            finish(Slider::new(label, min, max, step), spec, |f, v| {
+                v.as_f64().map(|v| f.initial(v)).ok_or("must be a number".to_string())
+            })
+        }
+        "select" => finish(Select::new(label, get_options(spec)?), spec, str_initial),
+        "radio" => finish(RadioGroup::new(label, get_options(spec)?), spec, str_initial),
+        "autocomplete" => {
+            let field = Autocomplete::new(label, get_feeder(spec)?).placeholder(placeholder);
+            finish(field, spec, str_initial)
+        }
+        "multiselect" => finish(
+            Multiselect::new(label, get_feeder(spec)?),
+            spec,
+            strings_initial,
+        ),
+        "tags" => finish(Tags::new(label), spec, strings_initial),
+        "date" => finish(DatePicker::new(label), spec, str_initial),
+        "time" => finish(Time::new(label), spec, str_initial),
+        "datetime" => finish(DateTime::new(label), spec, str_initial),
+        "duration" => finish(Duration::new(label).placeholder(placeholder), spec, str_initial),
+        "file" | "dir" => {
+            let mut field = if kind == "file" {
+                FilePicker::new(label)
+            } else {
+                FilePicker::dirs(label)
+            };
+            if let Some(extensions) = get_strings(spec, "extensions")? {
+                field = field.extensions(extensions);
+            }
+            finish(field.placeholder(placeholder), spec, str_initial)
+        }
+        "key_value" => finish(KeyValue::new(label), spec, |f, v| {
+            let pairs = v.as_object().ok_or("must be an object")?;
+            let pairs = pairs
+                .iter()
+                .map(|(k, v)| v.as_str().map(|v| (k.clone(), v.to_string())))
+                .collect::<Option<Vec<(String, String)>>>()
+                .ok_or("must have string values")?;
+            Ok(f.initial(pairs))
+        }),
+        "static" => {
+            let text = get_str(spec, "text")?.unwrap_or("");
+            finish(Static::new(label, text), spec, no_initial)
+        }
+        "hidden" => {
+            let value = spec.get("value").cloned().unwrap_or(Value::Null);
+            finish(Hidden::new(label, value), spec, no_initial)
+        }
+        _ => Err(format!("unknown type `{}`", kind)),
+    }
+}
+
+/// Applies options common to all fields.
+fn finish<W, T, I>(
+    mut field: Field<W, T>,
+    spec: &Spec,
+    initial: I,
+) -> Result<Box<FormField>, String>
+where
+    W: WidgetManager + 'static,
+    T: 'static,
+    Field<W, T>: FormField,
+    I: Fn(Field<W, T>, &Value) -> Result<Field<W, T>, String>,
+{
+    if let Some(help) = get_str(spec, "help")? {
+        field = field.help(help);
+    }
+    if let Some(width) = get_f64(spec, "width")? {
+        field = field.width(width as usize);
+    }
+    if let Some(env) = get_str(spec, "env")? {
+        field = field.default_from_env(env);
+    }
+    match spec.get("initial") {
+        None | Some(&Value::Null) => {}
+        Some(value) => field = initial(field, value).map_err(|e| format!("`initial` {}", e))?,
+    }
+    for validator in get_array(spec, "validators")?.iter() {
+        field = add_validator(field, validator)?;
+    }
+    Ok(Box::new(field))
+}
+
+fn str_initial<W: WidgetManager>(
+    field: Field<W, String>,
+    value: &Value,
+) -> Result<Field<W, String>, String> {
+    value
+        .as_str()
+        .map(|v| field.initial(v))
+        .ok_or("must be a string".to_string())
+}
+
+fn bool_initial<W: WidgetManager>(
+    field: Field<W, bool>,
+    value: &Value,
+) -> Result<Field<W, bool>, String> {
+    value
+        .as_bool()
+        .map(|v| field.initial(v))
+        .ok_or("must be a boolean".to_string())
+}
+
+fn strings_initial<W: WidgetManager>(
+    field: Field<W, Vec<String>>,
+    value: &Value,
+) -> Result<Field<W, Vec<String>>, String> {
+    to_strings(value)
+        .map(|v| field.initial(v))
+        .ok_or("must be a list of strings".to_string())
+}
+
+fn no_initial<F>(_field: F, _value: &Value) -> Result<F, String> {
+    Err("isn't supported by this type".to_string())
+}
+
+fn add_validator<W: WidgetManager, T>(
+    field: Field<W, T>,
+    spec: &Value,
+) -> Result<Field<W, T>, String> {
+    let (name, arg, message) = match *spec {
+        Value::String(ref name) => (name.as_str(), &Value::Null, None),
+        Value::Object(ref map) => {
+            let mut names = map.iter().filter(|&(k, _)| k != "message");
+            match (names.next(), names.next()) {
+                (Some((name, arg)), None) => (name.as_str(), arg, get_str(map, "message")?),
+                _ => return Err("validator must have exactly one name".to_string()),
+            }
+        }
+        _ => return Err("validator must be a string or an object".to_string()),
+    };
+    let number = || {
+        arg.as_f64()
+            .ok_or(format!("validator `{}` needs a number", name))
+    };
+    let string = || {
+        arg.as_str()
+            .ok_or(format!("validator `{}` needs a string", name))
+    };
+    macro_rules! add {
+        ($validator:expr) => {
+            match message {
+                Some(msg) => field.validator($validator.message(msg)),
+                None => field.validator($validator),
+            }
+        };
+    }
+    let field = match name {
+        "required" => add!(Required),
+        "path_free" => add!(PathFree),
+        "dir_exists" => add!(DirExists),
+        "file_exists" => add!(FileExists),
+        "path_exists" => add!(PathExists),
+        "writable" => add!(Writable),
+        "is_number" => add!(IsNumber),
+        "is_integer" => add!(IsInteger),
+        "url" => add!(Url),
+        "email" => add!(Email),
+        "min" => add!(Min(number()?)),
+        "max" => add!(Max(number()?)),
+        "between" => {
+            let range = arg.as_array()
+                .and_then(|r| match r.as_slice() {
+                    [ref min, ref max] => Some((min.as_f64()?, max.as_f64()?)),
+                    _ => None,
+                })
+                .ok_or("validator `between` needs a pair of numbers")?;
+            add!(Between(range.0, range.1))
+        }
+        "min_len" => add!(MinLen(number()? as usize)),
+        "max_len" => add!(MaxLen(number()? as usize)),
+        "regex" => add!(Regex::new(string()?).map_err(|e| e.to_string())?),
+        "one_of" => {
+            let options = to_strings(arg).ok_or("validator `one_of` needs a list of strings")?;
+            add!(OneOf(options))
+        }
+        "same_as" => add!(SameAs(string()?.to_string())),
+        "different_from" => add!(DifferentFrom(string()?.to_string())),
+        _ => return Err(format!("unknown validator `{}`", name)),
+    };
+    Ok(field)
+}
+
+fn get_feeder(spec: &Spec) -> Result<Rc<Feeder>, String> {
+    match spec.get("feeder") {
+        Some(value) => match value.as_str() {
+            Some("dir_items") => Ok(Rc::new(DirItems::new())),
+            Some("dirs") => Ok(Rc::new(DirItems::dirs())),
+            _ => to_strings(value)
+                .map(|items| Rc::new(items) as Rc<Feeder>)
+                .ok_or("`feeder` must be a list of strings, \"dir_items\" or \"dirs\"".to_string()),
+        },
+        None => Err("missing `feeder`".to_string()),
+    }
+}
+
+fn get_options(spec: &Spec) -> Result<Vec<(String, String)>, String> {
+    let error = "`options` must be a list of strings or pairs of strings";
+    let mut options = Vec::new();
+    for option in get_array(spec, "options")?.iter() {
+        let option = match *option {
+            Value::String(ref value) => (value.clone(), value.clone()),
+            Value::Array(_) => match to_strings(option) {
+                Some(ref pair) if pair.len() == 2 => (pair[0].clone(), pair[1].clone()),
+                _ => return Err(error.to_string()),
+            },
+            _ => return Err(error.to_string()),
+        };
+        options.push(option);
+    }
+    Ok(options)
+}
+
+fn to_strings(value: &Value) -> Option<Vec<String>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|v| v.as_str().map(|s| s.to_string()))
+        .collect()
+}
+
+fn get_str<'a>(spec: &'a Spec, key: &str) -> Result<Option<&'a str>, String> {
+    match spec.get(key) {
+        None | Some(&Value::Null) => Ok(None),
+        Some(value) => value
+            .as_str()
+            .map(Some)
+            .ok_or(format!("`{}` must be a string", key)),
+    }
+}
+
+fn get_f64(spec: &Spec, key: &str) -> Result<Option<f64>, String> {
+    match spec.get(key) {
+        None | Some(&Value::Null) => Ok(None),
+        Some(value) => value
+            .as_f64()
+            .map(Some)
+            .ok_or(format!("`{}` must be a number", key)),
+    }
+}
+
+fn get_strings(spec: &Spec, key: &str) -> Result<Option<Vec<String>>, String> {
+    match spec.get(key) {
+        None | Some(&Value::Null) => Ok(None),
+        Some(value) => to_strings(value)
+            .map(Some)
+            .ok_or(format!("`{}` must be a list of strings", key)),
+    }
+}
+
+fn get_array<'a>(spec: &'a Spec, key: &str) -> Result<&'a [Value], String> {
+    match spec.get(key) {
+        None | Some(&Value::Null) => Ok(&[]),
+        Some(value) => value
+            .as_array()
+            .map(|items| items.as_slice())
+            .ok_or(format!("`{}` must be a list", key)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn form_is_built_from_definition() {
+        let form = from_str(
+            r#"{
+            "title": "Archive",
+            "fields": [
+                {"type": "text", "label": "name", "help": "Archive's name", "initial": "a.tar",
+                 "validators": ["required", {"min_len": 3, "message": "Name is too short"}]},
+                {"type": "select", "label": "format", "options": ["tar", ["Zip", "zip"]]},
+                {"section": "Advanced", "fields": [
+                    {"type": "integer", "label": "level", "validators": [{"between": [1, 9]}]},
+                    {"type": "multiselect", "label": "exclude", "feeder": ["*.o", "*.tmp"]}
+                ]}
+            ]
+        }"#,
+        ).unwrap();
+        let args = form.fields2clap_args();
+        let names = args.iter().map(|a| a.b.name).collect::<Vec<&str>>();
+        assert_eq!(names, vec!["name", "format", "level", "exclude"]);
+        assert_eq!(args[0].b.help, Some("Archive's name"));
+
+        let form = form.with_values(&[("name", "ab"), ("format", "zip"), ("level", "10")]);
+        let errors = form.validate_all().unwrap_err();
+        assert_eq!(errors.get("name"), Some(&"Name is too short".to_string()));
+        assert!(errors.contains_key("level"));
+        assert!(!errors.contains_key("format"));
+    }
+
+    #[test]
+    fn invalid_definition_is_reported() {
+        let error = |definition| from_str(definition).err().unwrap();
+        assert_eq!(error("[]"), "Definition must be an object");
+        assert_eq!(
+            error(r#"{"fields": [{"type": "text"}]}"#),
+            "fields[0]: missing `label`"
+        );
+        assert_eq!(
+            error(r#"{"fields": [{"type": "color", "label": "c"}]}"#),
+            "fields[0]: unknown type `color`"
+        );
+        assert_eq!(
+            error(r#"{"fields": [{"section": "s", "fields": [{"type": "text", "label": "a",
+                "validators": ["nope"]}]}]}"#),
+            "fields[0]: fields[0]: unknown validator `nope`"
+        );
+        assert_eq!(
+            error(r#"{"fields": [{"type": "checkbox", "label": "c", "initial": "yes"}]}"#),
+            "fields[0]: `initial` must be a boolean"
+        );
+        assert_eq!(
+            error(r#"{"fields": [{"type": "slider", "label": "s", "min": 0, "max": 5,
+                "step": 0}]}"#),
+            "fields[0]: `step` must be positive"
+        );
+        assert_eq!(
+            error(r#"{"fields": [{"type": "slider", "label": "s", "min": 5, "max": 0}]}"#),
+            "fields[0]: `max` must not be less than `min`"
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! Structs edited with forms.
+//!
+//! With `derive` feature `#[derive(FormModel)]` builds form with a field for each of struct's
+//! fields:
+//!
+//! * `String` is [Text], `bool` is [Checkbox], numbers are [Number] (required unless they are
+//!   `Option`), `Vec<String>` is [Tags]
+//! * label is field's name with `_` replaced by `-`
+//! * `#[field(label = "...")]` sets label, `#[field(help = "...")]` sets help
+//! * `#[field(validator = "...")]` appends validator (expression using [validators]), it can be
+//!   repeated
+//! * `#[field(widget = "...")]` sets field's type (e.g. `Multiline`), it's built with `new(label)`
+//!
+//! ```ignore
+//! extern crate fui;
+//!
+//! use fui::Fui;
+//! use fui::model::FormModel;
+//!
+//! #[derive(FormModel)]
+//! struct Archive {
+//!     #[field(help = "Files to archive", validator = "Required")]
+//!     files: Vec<String>,
+//!     #[field(validator = "Between(1, 9)")]
+//!     level: Option<u8>,
+//!     #[field(label = "dry", help = "Only print what would be done")]
+//!     dry_run: bool,
+//! }
+//!
+//! fn main() {
+//!     Fui::new()
+//!         .action_model("archive", "Creates archive", |archive: Archive| {
+//!             println!("{:?} {:?} {}", archive.files, archive.level, archive.dry_run);
+//!         })
+//!         .run();
+//! }
+//! ```
+//!
+//! [Text]: ../fields/struct.Text.html
+//! [Checkbox]: ../fields/struct.Checkbox.html
+//! [Number]: ../fields/struct.Number.html
+//! [Tags]: ../fields/struct.Tags.html
+//! [validators]: ../validators/index.html
+use serde_json::value::Value;
+
+use form::FormView;
+
+#[cfg(feature = "derive")]
+pub use fui_derive::FormModel;
+
+/// Struct edited with a form, usually it's derived (see [module] docs).
+///
+/// [module]: index.html
+pub trait FormModel: Sized {
+    /// Builds form with a field for each of struct's fields.
+    fn form() -> FormView;
+    /// Builds struct from form's `data`.
+    fn from_data(data: &Value) -> Result<Self, String>;
+}
+
+/// Value of struct's field taken from form's data.
+pub trait FormValue: Sized {
+    /// Converts `value` emitted by field.
+    fn from_value(value: &Value) -> Result<Self, String>;
+}
+
+impl FormValue for String {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match *value {
+            Value::Null => Ok("".to_string()),
+            Value::String(ref s) => Ok(s.clone()),
+            ref other => Ok(other.to_string()),
+        }
+    }
+}
+
+impl FormValue for bool {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        value.as_bool().ok_or("Value must be a boolean".to_string())
+    }
+}
+
+macro_rules! impl_form_value_for_integer {
+    ($($t:ty),*) => {
+        $(
+            impl FormValue for $t {
+                fn from_value(value: &Value) -> Result<Self, String> {
+                    let v = match (value.as_i64(), value.as_u64()) {
+                        (Some(v), _) => v as i128,
+                        (None, Some(v)) => v as i128,
+                        _ => return Err("Value must be an integer".to_string()),
+                    };
+                    if v < <$t>::min_value() as i128 || v > <$t>::max_value() as i128 {
+                        return Err(format!("Value must fit in {}", stringify!($t)));
+                    }
+                    Ok(v as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_form_value_for_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl FormValue for f64 {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        value.as_f64().ok_or("Value must be a number".to_string())
+    }
+}
+
+impl FormValue for f32 {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        f64::from_value(value).map(|v| v as f32)
+    }
+}
+
+impl<T: FormValue> FormValue for Vec<T> {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match *value {
+            Value::Null => Ok(Vec::new()),
+            Value::Array(ref items) => items.iter().map(T::from_value).collect(),
+            _ => Err("Value must be a list".to_string()),
+        }
+    }
+}
+
+impl<T: FormValue> FormValue for Option<T> {
+    fn from_value(value: &Value) -> Result<Self, String> {
+        match *value {
+            Value::Null => Ok(None),
+            ref other => T::from_value(other).map(Some),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Contains `Progress` reported by handlers running in background and `ProgressView` showing it.
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use cursive::Printer;
+use cursive::event::{Event, EventResult};
+use cursive::vec::Vec2;
+use cursive::view::View;
+
+/// Frames of spinner shown until handler reports what part of work is done.
+const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+/// Width of progress bar (with its brackets).
+const BAR_WIDTH: usize = 32;
+
+enum Update {
+    Done(f32),
+    Message(String),
+}
+
+/// Handle given to handler running in background to report its progress.
+///
+/// In TUI progress is shown in [ProgressView], in CLI messages are printed to stderr.
+///
+/// [ProgressView]: struct.ProgressView.html
+#[derive(Clone)]
+pub struct Progress {
+    sender: Option<Sender<Update>>,
+}
+
+impl Progress {
+    /// Creates `Progress` printing its messages to stderr.
+    pub fn stderr() -> Self {
+        Progress { sender: None }
+    }
+
+    /// Creates `Progress` and view showing it.
+    pub fn with_view() -> (Self, ProgressView) {
+        let (sender, receiver) = mpsc::channel();
+        (Progress { sender: Some(sender) }, ProgressView::new(receiver))
+    }
+
+    /// Sets part of work which is done (from `0.0` to `1.0`), it turns spinner into progress bar.
+    pub fn set(&self, done: f32) {
+        if let Some(ref sender) = self.sender {
+            sender.send(Update::Done(done)).ok();
+        }
+    }
+
+    /// Shows `message` describing current step of work.
+    pub fn message<IS: Into<String>>(&self, message: IS) {
+        match self.sender {
+            Some(ref sender) => {
+                sender.send(Update::Message(message.into())).ok();
+            }
+            None => eprintln!("{}", message.into()),
+        }
+    }
+}
+
+/// Shows spinner (or progress bar) and the last message reported by [Progress].
+///
+/// It's updated on `Event::Refresh`, so Cursive's fps must be set while it's shown.
+///
+/// [Progress]: struct.Progress.html
+pub struct ProgressView {
+    receiver: Receiver<Update>,
+    done: Option<f32>,
+    message: String,
+    frame: usize,
+}
+
+impl ProgressView {
+    fn new(receiver: Receiver<Update>) -> Self {
+        ProgressView {
+            receiver,
+            done: None,
+            message: String::new(),
+            frame: 0,
+        }
+    }
+
+    /// Applies updates reported so far.
+    fn update(&mut self) {
+        while let Ok(update) = self.receiver.try_recv() {
+            match update {
+                Update::Done(done) => self.done = Some(done.clamp(0.0, 1.0)),
+                Update::Message(message) => self.message = message,
+            }
+        }
+    }
+
+    /// Text of the first line: spinner or progress bar with percents.
+    fn indicator(&self) -> String {
+        match self.done {
+            None => SPINNER[self.frame % SPINNER.len()].to_string(),
+            Some(done) => {
+                let inner = BAR_WIDTH - 2;
+                let filled = (done * inner as f32).round() as usize;
+                format!(
+                    "[{}{}] {:>3}%",
+                    "#".repeat(filled),
+                    " ".repeat(inner - filled),
+                    (done * 100.0).round()
+                )
+            }
+        }
+    }
+}
+
+impl View for ProgressView {
+    fn draw(&self, printer: &Printer) {
+        printer.print((0, 0), &self.indicator());
+        printer.print((0, 1), &self.message);
+    }
+
+    fn required_size(&mut self, _req: Vec2) -> Vec2 {
+        self.update();
+        let width = self.message.chars().count().max(BAR_WIDTH + 5);
+        Vec2::new(width, 2)
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Refresh => {
+                self.update();
+                self.frame += 1;
+                EventResult::Consumed(None)
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn view_shows_reported_progress() {
+        let (progress, mut view) = Progress::with_view();
+        assert_eq!(view.indicator(), "|");
+        view.on_event(Event::Refresh);
+        assert_eq!(view.indicator(), "/");
+
+        progress.message("Packing a.txt");
+        progress.set(0.5);
+        view.on_event(Event::Refresh);
+        assert_eq!(view.message, "Packing a.txt");
+        assert_eq!(view.indicator(), format!("[{}{}]  50%", "#".repeat(15), " ".repeat(15)));
+
+        progress.set(2.0);
+        view.on_event(Event::Refresh);
+        assert_eq!(view.indicator(), format!("[{}] 100%", "#".repeat(30)));
+    }
+}
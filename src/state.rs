@@ -0,0 +1,31 @@
+//! Persists state of program between its runs (e.g. last run action).
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json;
+use serde_json::value::Value;
+
+/// Returns dir keeping state of program `name` (`$XDG_STATE_HOME/<name>`, by default
+/// `~/.local/state/<name>`).
+pub fn dir(name: &str) -> Option<PathBuf> {
+    let base = match env::var_os("XDG_STATE_HOME") {
+        Some(ref dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => env::home_dir()?.join(".local").join("state"),
+    };
+    Some(base.join(name))
+}
+
+/// Loads value saved in `path`, missing or broken file gives `None`.
+pub fn load(path: &Path) -> Option<Value> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Saves `value` in `path` (creating its dirs).
+pub fn save(path: &Path, value: &Value) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    fs::write(path, value.to_string()).map_err(|e| e.to_string())
+}
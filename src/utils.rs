@@ -1,5 +1,8 @@
 //! Various kinds of helpers.
 use std::env;
+use std::ffi::OsStr;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Returns current working dir as String.
 pub fn cwd() -> String {
@@ -15,3 +18,256 @@ pub fn home_dir() -> String {
         .map(|p| p.into_os_string().into_string().unwrap())
         .unwrap()
 }
+
+/// Checks if `path` ends with one of `extensions` (case insensitive), empty `extensions` match
+/// anything.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::utils::has_extension;
+/// # fn main() {
+/// let extensions = vec!["tar.gz".to_string(), "zip".to_string()];
+/// assert!(has_extension("archive.TAR.GZ", &extensions));
+/// assert!(!has_extension("archive.gz", &extensions));
+/// # }
+/// ```
+pub fn has_extension<P: AsRef<Path>>(path: P, extensions: &[String]) -> bool {
+    let name = path.as_ref()
+        .file_name()
+        .and_then(OsStr::to_str)
+        .unwrap_or("")
+        .to_lowercase();
+    extensions.is_empty() || extensions
+        .iter()
+        .any(|e| name.ends_with(&format!(".{}", e.to_lowercase())))
+}
+
+/// Parses date in `YYYY-MM-DD` format (ISO-8601) to (year, month, day).
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::utils::parse_date;
+/// # fn main() {
+/// assert_eq!(parse_date("2020-02-29"), Some((2020, 2, 29)));
+/// assert_eq!(parse_date("2021-02-29"), None);
+/// assert_eq!(parse_date("2021-2-3"), None);
+/// # }
+/// ```
+pub fn parse_date(text: &str) -> Option<(i64, u32, u32)> {
+    let parts = text.split('-').collect::<Vec<&str>>();
+    if parts.len() != 3 || parts[0].len() != 4 || parts[1].len() != 2 || parts[2].len() != 2 {
+        return None;
+    }
+    if !parts.iter().all(|p| p.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+    let year = parts[0].parse::<i64>().ok()?;
+    let month = parts[1].parse::<u32>().ok()?;
+    let day = parts[2].parse::<u32>().ok()?;
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+/// Formats (year, month, day) as `YYYY-MM-DD`.
+pub fn format_date(date: (i64, u32, u32)) -> String {
+    format!("{:04}-{:02}-{:02}", date.0, date.1, date.2)
+}
+
+/// Moves `date` by `days` (backwards if negative).
+pub fn add_days(date: (i64, u32, u32), days: i64) -> (i64, u32, u32) {
+    civil_from_days(days_from_civil(date) + days)
+}
+
+/// Returns current date (UTC).
+pub fn today() -> (i64, u32, u32) {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    civil_from_days(secs / SECS_PER_DAY)
+}
+
+/// Parses time in `HH:MM` or `HH:MM:SS` format (ISO-8601) to (hour, minute, second).
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::utils::parse_time;
+/// # fn main() {
+/// assert_eq!(parse_time("23:59"), Some((23, 59, 0)));
+/// assert_eq!(parse_time("07:30:15"), Some((7, 30, 15)));
+/// assert_eq!(parse_time("24:00"), None);
+/// # }
+/// ```
+pub fn parse_time(text: &str) -> Option<(u32, u32, u32)> {
+    let parts = text.split(':').collect::<Vec<&str>>();
+    if parts.len() < 2 || parts.len() > 3 || parts.iter().any(|p| p.len() != 2) {
+        return None;
+    }
+    if !parts.iter().all(|p| p.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+    let hour = parts[0].parse::<u32>().ok()?;
+    let minute = parts[1].parse::<u32>().ok()?;
+    let second = match parts.get(2) {
+        Some(p) => p.parse::<u32>().ok()?,
+        None => 0,
+    };
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    Some((hour, minute, second))
+}
+
+/// Parses duration like `1h30m` or `90s` to seconds.
+///
+/// Units are `d`, `h`, `m` & `s`, number without unit means seconds.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::utils::parse_duration;
+/// # fn main() {
+/// assert_eq!(parse_duration("1h30m"), Some(5400));
+/// assert_eq!(parse_duration("90"), Some(90));
+/// assert_eq!(parse_duration("1x"), None);
+/// # }
+/// ```
+pub fn parse_duration(text: &str) -> Option<u64> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    if let Ok(secs) = text.parse::<u64>() {
+        return Some(secs);
+    }
+    let mut total = 0u64;
+    let mut number = String::new();
+    for c in text.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c {
+            'd' => SECS_PER_DAY as u64,
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        let value = number.parse::<u64>().ok()?;
+        total = total.checked_add(value.checked_mul(unit)?)?;
+        number.clear();
+    }
+    if number.is_empty() {
+        Some(total)
+    } else {
+        None
+    }
+}
+
+/// Formats `secs` as duration (e.g. `1h30m`).
+pub fn format_duration(secs: u64) -> String {
+    if secs == 0 {
+        return "0s".to_string();
+    }
+    let units = [('d', SECS_PER_DAY as u64), ('h', 60 * 60), ('m', 60), ('s', 1)];
+    let mut rest = secs;
+    let mut text = String::new();
+    for &(name, size) in units.iter() {
+        if rest >= size {
+            text.push_str(&format!("{}{}", rest / size, name));
+            rest %= size;
+        }
+    }
+    text
+}
+
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Converts date to number of days since 1970-01-01.
+fn days_from_civil(date: (i64, u32, u32)) -> i64 {
+    let (year, month, day) = (date.0, date.1 as i64, date.2 as i64);
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = (if year >= 0 { year } else { year - 399 }) / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Converts number of days since 1970-01-01 to date.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = (if days >= 0 { days } else { days - 146096 }) / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400;
+    let year = if month <= 2 { year + 1 } else { year };
+    (year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_added_across_months_and_years() {
+        assert_eq!(add_days((2020, 2, 28), 1), (2020, 2, 29));
+        assert_eq!(add_days((2021, 2, 28), 1), (2021, 3, 1));
+        assert_eq!(add_days((2021, 1, 1), -1), (2020, 12, 31));
+        assert_eq!(add_days((1970, 1, 1), 0), (1970, 1, 1));
+        assert_eq!(days_from_civil((2000, 3, 1)), 11017);
+    }
+
+    #[test]
+    fn dates_are_parsed_strictly() {
+        assert_eq!(parse_date("2000-02-29"), Some((2000, 2, 29)));
+        assert_eq!(parse_date("1900-02-29"), None);
+        assert_eq!(parse_date("2020-13-01"), None);
+        assert_eq!(parse_date("2020-04-31"), None);
+        assert_eq!(parse_date("2020-00-10"), None);
+        assert_eq!(parse_date("+020-01-10"), None);
+        assert_eq!(parse_date(""), None);
+    }
+
+    #[test]
+    fn durations_are_parsed_and_normalized() {
+        assert_eq!(parse_duration("2d1s"), Some(2 * 86400 + 1));
+        assert_eq!(parse_duration("90s"), Some(90));
+        assert_eq!(parse_duration("1h 30m"), None);
+        assert_eq!(parse_duration("h"), None);
+        assert_eq!(parse_duration("10m5"), None);
+        assert_eq!(format_duration(5400), "1h30m");
+        assert_eq!(format_duration(90), "1m30s");
+        assert_eq!(format_duration(0), "0s");
+    }
+
+    #[test]
+    fn times_are_parsed_strictly() {
+        assert_eq!(parse_time("00:00"), Some((0, 0, 0)));
+        assert_eq!(parse_time("12:60"), None);
+        assert_eq!(parse_time("12:00:60"), None);
+        assert_eq!(parse_time("1:00"), None);
+        assert_eq!(parse_time("12:00:00:00"), None);
+        assert_eq!(parse_time("12"), None);
+    }
+}
@@ -1,16 +1,119 @@
 //! Provides data validators used by `fields`.
-use regex::Regex;
+use regex;
+use serde_json::map::Map;
+use serde_json::value::Value;
 use std::any::Any;
-use std::fmt::Debug;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
 use std::ops::Deref;
 use std::path::Path;
 
+/// Values (as `str`) of all form's `fields` by their labels, available during validation.
+pub type Context = HashMap<String, String>;
+
 /// Adds behaviour of validation.
-pub trait Validator: Debug {
+///
+/// It's implemented for closures returning `Result<(), String>`, so one-off rules don't need
+/// a new type.
+///
+/// Examples
+///
+/// ```
+/// use fui::fields::{FormField, Text};
+///
+/// let field = Text::new("name").validator(|v: &str| {
+///     if v.starts_with('_') {
+///         Err("Value can't start with '_'".to_string())
+///     } else {
+///         Ok(())
+///     }
+/// });
+/// assert_eq!(field.validate("_x"), Err("Value can't start with '_'".to_string()));
+/// ```
+pub trait Validator {
     /// Validates data returning None (when Ok) or String with error.
     fn validate(&self, data: &str) -> Option<String>;
+    /// Validates data knowing values of other `fields` (`context`).
+    ///
+    /// By default `context` is skipped and `validate` is used.
+    fn validate_with(&self, data: &str, _context: &Context) -> Option<String> {
+        self.validate(data)
+    }
     /// Allows downcasting `self` to a `Any`.
     fn as_any(&self) -> &Any;
+    /// Adds constraints checked by this validator to JSON Schema of validated value (see
+    /// [FormView::schema]), by default there are none.
+    ///
+    /// [FormView::schema]: ../form/struct.FormView.html#method.schema
+    fn schema(&self, _schema: &mut Map<String, Value>) {}
+    /// Replaces error of this validator with `msg`.
+    ///
+    /// ```
+    /// use fui::validators::{MinLen, Validator};
+    ///
+    /// let v = MinLen(3).message("Name is too short");
+    /// assert_eq!(v.validate("ab"), Some("Name is too short".to_string()));
+    /// ```
+    fn message<IS: Into<String>>(self, msg: IS) -> WithMessage<Self>
+    where
+        Self: Sized,
+    {
+        WithMessage(self, msg.into())
+    }
+}
+
+/// Wraps validator replacing its error with custom message, see [Validator::message].
+///
+/// [Validator::message]: trait.Validator.html#method.message
+pub struct WithMessage<V>(V, String);
+
+impl<V: Validator + 'static> Validator for WithMessage<V> {
+    fn validate(&self, data: &str) -> Option<String> {
+        self.0.validate(data).map(|_| self.1.clone())
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Option<String> {
+        self.0.validate_with(data, context).map(|_| self.1.clone())
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+    fn schema(&self, schema: &mut Map<String, Value>) {
+        self.0.schema(schema)
+    }
+}
+
+impl<F> Validator for F
+where
+    F: Fn(&str) -> Result<(), String> + 'static,
+{
+    fn validate(&self, data: &str) -> Option<String> {
+        self(data).err()
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+/// Adds behaviour of validation run in background thread, for slow checks (e.g. resolving host).
+///
+/// It's implemented for closures, see [FormView::async_validator].
+///
+/// [FormView::async_validator]: ../form/struct.FormView.html#method.async_validator
+pub trait AsyncValidator: Send + Sync {
+    /// Validates data returning None (when Ok) or String with error.
+    fn validate(&self, data: &str) -> Option<String>;
+}
+
+impl<F> AsyncValidator for F
+where
+    F: Fn(&str) -> Option<String> + Send + Sync,
+{
+    fn validate(&self, data: &str) -> Option<String> {
+        self(data)
+    }
 }
 
 /// Ensures data is included.
@@ -29,7 +132,7 @@ pub struct Required;
 
 impl Validator for Required {
     fn validate(&self, data: &str) -> Option<String> {
-        if data.len() == 0 {
+        if data.is_empty() {
             Some("Field is required".to_string())
         } else {
             None
@@ -151,8 +254,92 @@ impl Validator for FileExists {
     }
 }
 
+/// Ensures data is path which exists (file, dir, etc.).
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{PathExists, Validator};
+///
+/// assert_eq!(PathExists.validate("./src"), None);
+/// assert_eq!(PathExists.validate("./missing"), Some("Path doesn't exist".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PathExists;
+
+impl Validator for PathExists {
+    fn validate(&self, data: &str) -> Option<String> {
+        if Path::new(data).exists() {
+            None
+        } else {
+            Some("Path doesn't exist".to_string())
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+/// Ensures data is file path which exists, the same as [FileExists].
+///
+/// [FileExists]: struct.FileExists.html
+pub use self::FileExists as IsFile;
+
+/// Ensures data is dir path which exists, the same as [DirExists].
+///
+/// [DirExists]: struct.DirExists.html
+pub use self::DirExists as IsDir;
+
+/// Ensures data is path which can be written.
+///
+/// Path which doesn't exist yet (e.g. output file) is writable if its parent dir is.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Validator, Writable};
+///
+/// assert_eq!(Writable.validate("./src/new-file"), None);
+/// assert_eq!(
+///     Writable.validate("./missing-dir/new-file"),
+///     Some("Parent dir doesn't exist".to_string())
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Writable;
+
+impl Validator for Writable {
+    fn validate(&self, data: &str) -> Option<String> {
+        let path = Path::new(data);
+        let (checked, missing) = if path.exists() {
+            (path, "Path doesn't exist")
+        } else {
+            let parent = match path.parent() {
+                Some(p) if p.as_os_str().is_empty() => Path::new("."),
+                Some(p) => p,
+                None => return Some("Parent dir doesn't exist".to_string()),
+            };
+            (parent, "Parent dir doesn't exist")
+        };
+        match checked.metadata() {
+            Ok(ref m) if m.permissions().readonly() => Some("Path isn't writable".to_string()),
+            Ok(_) => None,
+            Err(_) => Some(missing.to_string()),
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
 /// Ensures value is one of provided options.
 ///
+/// Empty value is skipped, combine it with [Required] to reject it.
+///
+/// [Required]: struct.Required.html
+///
 /// Examples
 ///
 /// ```
@@ -161,6 +348,7 @@ impl Validator for FileExists {
 ///
 /// let v = OneOf(vec!["a", "b"]);
 /// assert_eq!(v.validate("a"), None);
+/// assert_eq!(v.validate(""), None);
 /// assert_eq!(v.validate("xxx"), Some("Value must be one of options".to_string()));
 /// ```
 #[derive(Clone, Debug)]
@@ -171,7 +359,9 @@ where
     T: Deref<Target = str>,
 {
     fn validate(&self, data: &str) -> Option<String> {
-        if let None = self.0.iter().position(|x| &**x == data) {
+        if data.is_empty() {
+            None
+        } else if !self.0.iter().any(|x| &**x == data) {
             Some("Value must be one of options".to_string())
         } else {
             None
@@ -181,9 +371,471 @@ where
     fn as_any(&self) -> &Any {
         self
     }
+
+    fn schema(&self, schema: &mut Map<String, Value>) {
+        let options = self.0.iter().map(|x| Value::from(&**x)).collect();
+        schema.insert("enum".to_string(), Value::Array(options));
+    }
+}
+
+/// Ensures value is the same as value of `field` labeled by passed label (e.g. password
+/// confirmation).
+///
+/// If [Context] lacks the `field` (e.g. validator is used alone) there is nothing to compare with,
+/// so any value is fine.
+///
+/// [Context]: type.Context.html
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Context, SameAs, Validator};
+///
+/// let mut context = Context::new();
+/// context.insert("password".to_string(), "secret".to_string());
+/// let v = SameAs("password");
+/// assert_eq!(v.validate_with("secret", &context), None);
+/// assert_eq!(
+///     v.validate_with("xxx", &context),
+///     Some("Value must be the same as \"password\"".to_string())
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct SameAs<T>(pub T);
+
+impl<T: AsRef<str> + Debug + 'static> Validator for SameAs<T> {
+    fn validate(&self, _data: &str) -> Option<String> {
+        None
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Option<String> {
+        let label = self.0.as_ref();
+        match context.get(label) {
+            Some(other) if other == data => None,
+            Some(_) => Some(format!("Value must be the same as \"{}\"", label)),
+            None => None,
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+/// Ensures value differs from value of `field` labeled by passed label (e.g. new password).
+///
+/// If [Context] lacks the `field` (e.g. validator is used alone) there is nothing to compare with,
+/// so any value is fine.
+///
+/// [Context]: type.Context.html
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Context, DifferentFrom, Validator};
+///
+/// let mut context = Context::new();
+/// context.insert("old".to_string(), "secret".to_string());
+/// let v = DifferentFrom("old");
+/// assert_eq!(v.validate_with("xxx", &context), None);
+/// assert_eq!(
+///     v.validate_with("secret", &context),
+///     Some("Value must differ from \"old\"".to_string())
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct DifferentFrom<T>(pub T);
+
+impl<T: AsRef<str> + Debug + 'static> Validator for DifferentFrom<T> {
+    fn validate(&self, _data: &str) -> Option<String> {
+        None
+    }
+
+    fn validate_with(&self, data: &str, context: &Context) -> Option<String> {
+        let label = self.0.as_ref();
+        match context.get(label) {
+            Some(other) if other == data => Some(format!("Value must differ from \"{}\"", label)),
+            _ => None,
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+/// Parses `data` as number for numeric validators.
+fn parse_number(data: &str) -> Result<f64, String> {
+    data.trim()
+        .parse::<f64>()
+        .map_err(|_| "Value must be a number".to_string())
+}
+
+/// Ensures value is a number.
+///
+/// Empty value is skipped, combine it with [Required] to reject it.
+///
+/// [Required]: struct.Required.html
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{IsNumber, Validator};
+///
+/// assert_eq!(IsNumber.validate("-1.5"), None);
+/// assert_eq!(IsNumber.validate("x"), Some("Value must be a number".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct IsNumber;
+
+impl Validator for IsNumber {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.is_empty() {
+            return None;
+        }
+        parse_number(data).err()
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+/// Ensures value is an integer.
+///
+/// Empty value is skipped, combine it with [Required] to reject it.
+///
+/// [Required]: struct.Required.html
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{IsInteger, Validator};
+///
+/// assert_eq!(IsInteger.validate("-15"), None);
+/// assert_eq!(IsInteger.validate("1.5"), Some("Value must be an integer".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct IsInteger;
+
+impl Validator for IsInteger {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.is_empty() || data.trim().parse::<i64>().is_ok() {
+            None
+        } else {
+            Some("Value must be an integer".to_string())
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+}
+
+/// Ensures value is a number not lower than passed one.
+///
+/// Empty value is skipped, combine it with [Required] to reject it.
+///
+/// [Required]: struct.Required.html
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Min, Validator};
+///
+/// assert_eq!(Min(1).validate("1"), None);
+/// assert_eq!(Min(1).validate("0.5"), Some("Value must be at least 1".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Min<N>(pub N);
+
+impl<N: Into<f64> + Copy + Display + Debug + 'static> Validator for Min<N> {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.is_empty() {
+            return None;
+        }
+        match parse_number(data) {
+            Ok(v) if v < self.0.into() => Some(format!("Value must be at least {}", self.0)),
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    fn schema(&self, schema: &mut Map<String, Value>) {
+        schema.insert("minimum".to_string(), Value::from(self.0.into()));
+    }
+}
+
+/// Ensures value is a number not greater than passed one.
+///
+/// Empty value is skipped, combine it with [Required] to reject it.
+///
+/// [Required]: struct.Required.html
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Max, Validator};
+///
+/// assert_eq!(Max(9).validate("9"), None);
+/// assert_eq!(Max(9).validate("10"), Some("Value must be at most 9".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Max<N>(pub N);
+
+impl<N: Into<f64> + Copy + Display + Debug + 'static> Validator for Max<N> {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.is_empty() {
+            return None;
+        }
+        match parse_number(data) {
+            Ok(v) if v > self.0.into() => Some(format!("Value must be at most {}", self.0)),
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    fn schema(&self, schema: &mut Map<String, Value>) {
+        schema.insert("maximum".to_string(), Value::from(self.0.into()));
+    }
+}
+
+/// Ensures value is a number in range (both ends included).
+///
+/// Empty value is skipped, combine it with [Required] to reject it.
+///
+/// [Required]: struct.Required.html
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Between, Validator};
+///
+/// assert_eq!(Between(1, 9).validate("5"), None);
+/// assert_eq!(Between(1, 9).validate("10"), Some("Value must be between 1 and 9".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Between<N>(pub N, pub N);
+
+impl<N: Into<f64> + Copy + Display + Debug + 'static> Validator for Between<N> {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.is_empty() {
+            return None;
+        }
+        match parse_number(data) {
+            Ok(v) if v < self.0.into() || v > self.1.into() => {
+                Some(format!("Value must be between {} and {}", self.0, self.1))
+            }
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    fn schema(&self, schema: &mut Map<String, Value>) {
+        schema.insert("minimum".to_string(), Value::from(self.0.into()));
+        schema.insert("maximum".to_string(), Value::from(self.1.into()));
+    }
+}
+
+/// Ensures value has at least passed number of characters.
+///
+/// Whitespace around value isn't counted, so value of only spaces is rejected like empty one.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{MinLen, Validator};
+///
+/// assert_eq!(MinLen(2).validate("ab"), None);
+/// let error = "Value must have at least 2 characters".to_string();
+/// assert_eq!(MinLen(2).validate(" a  "), Some(error));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MinLen(pub usize);
+
+impl Validator for MinLen {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.trim().chars().count() < self.0 {
+            Some(format!("Value must have at least {} characters", self.0))
+        } else {
+            None
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    fn schema(&self, schema: &mut Map<String, Value>) {
+        schema.insert("minLength".to_string(), Value::from(self.0));
+    }
+}
+
+/// Ensures value has at most passed number of characters.
+///
+/// Whitespace around value isn't counted (like for [MinLen]).
+///
+/// [MinLen]: struct.MinLen.html
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{MaxLen, Validator};
+///
+/// let error = "Value must have at most 3 characters".to_string();
+/// assert_eq!(MaxLen(3).validate("żółw"), Some(error));
+/// assert_eq!(MaxLen(4).validate(" żółw  "), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MaxLen(pub usize);
+
+impl Validator for MaxLen {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.trim().chars().count() > self.0 {
+            Some(format!("Value must have at most {} characters", self.0))
+        } else {
+            None
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    fn schema(&self, schema: &mut Map<String, Value>) {
+        schema.insert("maxLength".to_string(), Value::from(self.0));
+    }
+}
+
+const URL_PATTERN: &str = r"^[a-zA-Z][a-zA-Z0-9+.-]*://[^\s/?#@]+(@[^\s/?#@]+)?(:\d+)?([/?#]\S*)?$";
+const EMAIL_PATTERN: &str = r"^[^\s@]+@[^\s@.]+(\.[^\s@.]+)+$";
+
+lazy_static! {
+    // compiled once, validators run on every edit
+    static ref URL_REGEX: regex::Regex = regex::Regex::new(URL_PATTERN).unwrap();
+    static ref EMAIL_REGEX: regex::Regex = regex::Regex::new(EMAIL_PATTERN).unwrap();
+}
+
+/// Ensures value looks like URL with scheme and host (e.g. `https://example.com/path`).
+///
+/// It's only syntactic check. Empty value is skipped, combine it with [Required] to reject it.
+///
+/// [Required]: struct.Required.html
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Url, Validator};
+///
+/// assert_eq!(Url.validate("https://example.com:8080/path?q=1"), None);
+/// assert_eq!(Url.validate("example.com"), Some("Value must be a URL".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Url;
+
+impl Validator for Url {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.is_empty() || URL_REGEX.is_match(data) {
+            None
+        } else {
+            Some("Value must be a URL".to_string())
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    fn schema(&self, schema: &mut Map<String, Value>) {
+        schema.insert("format".to_string(), Value::from("uri"));
+    }
+}
+
+/// Ensures value looks like e-mail address (e.g. `john@example.com`).
+///
+/// It's only syntactic check. Empty value is skipped, combine it with [Required] to reject it.
+///
+/// [Required]: struct.Required.html
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Email, Validator};
+///
+/// assert_eq!(Email.validate("john@example.com"), None);
+/// assert_eq!(Email.validate("john@localhost"), Some("Value must be an email".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Email;
+
+impl Validator for Email {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.is_empty() || EMAIL_REGEX.is_match(data) {
+            None
+        } else {
+            Some("Value must be an email".to_string())
+        }
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    fn schema(&self, schema: &mut Map<String, Value>) {
+        schema.insert("format".to_string(), Value::from("email"));
+    }
+}
+
+/// Ensures value matches regular expression, error message can be customized with [message].
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Regex, Validator};
+///
+/// let v = Regex::new(r"^\d+\.\d+\.\d+$").unwrap().message("Value must be a semver");
+/// assert_eq!(v.validate("1.2.3"), None);
+/// assert_eq!(v.validate("1.2"), Some("Value must be a semver".to_string()));
+/// ```
+///
+/// [message]: trait.Validator.html#method.message
+#[derive(Clone, Debug)]
+pub struct Regex(regex::Regex);
+
+impl Regex {
+    /// Creates a new `Regex` validator from `pattern`, fails if `pattern` is invalid.
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(pattern).map(Regex)
+    }
 }
 
 impl Validator for Regex {
+    fn validate(&self, data: &str) -> Option<String> {
+        self.0.validate(data)
+    }
+
+    fn as_any(&self) -> &Any {
+        self
+    }
+
+    fn schema(&self, schema: &mut Map<String, Value>) {
+        self.0.schema(schema)
+    }
+}
+
+impl Validator for regex::Regex {
     fn validate(&self, data: &str) -> Option<String> {
         if self.is_match(data) {
             None
@@ -198,4 +850,182 @@ impl Validator for Regex {
     fn as_any(&self) -> &Any {
         self
     }
+
+    fn schema(&self, schema: &mut Map<String, Value>) {
+        schema.insert("pattern".to_string(), Value::from(self.as_str()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fields::{FormField, Password, Text};
+    use serde_json::value::Value;
+
+    #[test]
+    fn one_of_accepts_empty_when_optional() {
+        let field = Text::new("f").validator(OneOf(vec!["a", "b"]));
+        assert_eq!(field.validate(""), Ok(Value::String("".to_string())));
+    }
+
+    #[test]
+    fn one_of_accepts_listed_when_optional() {
+        let field = Text::new("f").validator(OneOf(vec!["a", "b"]));
+        assert_eq!(field.validate("a"), Ok(Value::String("a".to_string())));
+    }
+
+    #[test]
+    fn one_of_rejects_unlisted_when_optional() {
+        let field = Text::new("f").validator(OneOf(vec!["a", "b"]));
+        assert_eq!(
+            field.validate("x"),
+            Err("Value must be one of options".to_string())
+        );
+    }
+
+    #[test]
+    fn one_of_rejects_empty_when_required() {
+        let field = Text::new("f")
+            .validator(Required)
+            .validator(OneOf(vec!["a", "b"]));
+        assert_eq!(field.validate(""), Err("Field is required".to_string()));
+    }
+
+    #[test]
+    fn one_of_accepts_listed_when_required() {
+        let field = Text::new("f")
+            .validator(Required)
+            .validator(OneOf(vec!["a", "b"]));
+        assert_eq!(field.validate("b"), Ok(Value::String("b".to_string())));
+    }
+
+    #[test]
+    fn one_of_rejects_unlisted_when_required() {
+        let field = Text::new("f")
+            .validator(Required)
+            .validator(OneOf(vec!["a", "b"]));
+        assert_eq!(
+            field.validate("x"),
+            Err("Value must be one of options".to_string())
+        );
+    }
+
+    #[test]
+    fn same_as_is_skipped_without_context() {
+        let field = Text::new("f").validator(SameAs("other"));
+        assert_eq!(field.validate("a"), Ok(Value::String("a".to_string())));
+    }
+
+    #[test]
+    fn different_from_rejects_same_value() {
+        let field = Text::new("f").validator(DifferentFrom("other"));
+        let mut context = Context::new();
+        context.insert("other".to_string(), "a".to_string());
+        assert_eq!(
+            field.validate_with("a", &context),
+            Err("Value must differ from \"other\"".to_string())
+        );
+        assert_eq!(
+            field.validate_with("b", &context),
+            Ok(Value::String("b".to_string()))
+        );
+    }
+
+    #[test]
+    fn password_is_not_shown_in_errors() {
+        let field = Password::new("p").validator(Regex::new("^a+$").unwrap());
+        let error = field.validate("secret").unwrap_err();
+        assert!(!error.contains("secret"));
+        assert!(error.contains("*****"));
+    }
+
+    #[test]
+    fn regex_uses_custom_message() {
+        let field = Text::new("f").validator(Regex::new("^[a-z]+$").unwrap());
+        assert_eq!(field.validate("abc"), Ok(Value::String("abc".to_string())));
+        assert!(field.validate("ABC").unwrap_err().contains("does not match"));
+
+        let field = Text::new("f")
+            .validator(Regex::new("^[a-z]+$").unwrap().message("Value must be an identifier"));
+        assert_eq!(
+            field.validate("ABC"),
+            Err("Value must be an identifier".to_string())
+        );
+    }
+
+    #[test]
+    fn numeric_validators_skip_empty_and_reject_text() {
+        let field = Text::new("f").validator(IsInteger).validator(Between(1, 9));
+        assert_eq!(field.validate(""), Ok(Value::String("".to_string())));
+        assert_eq!(field.validate("x"), Err("Value must be an integer".to_string()));
+        assert_eq!(
+            field.validate("0"),
+            Err("Value must be between 1 and 9".to_string())
+        );
+        assert_eq!(Min(0.5).validate("x"), Some("Value must be a number".to_string()));
+        assert_eq!(Max(0.5).validate(" 0.5 "), None);
+    }
+
+    #[test]
+    fn length_validators_reject_blank_and_long_values() {
+        let field = Text::new("name").validator(MinLen(1)).validator(MaxLen(5));
+        assert_eq!(
+            field.validate("   "),
+            Err("Value must have at least 1 characters".to_string())
+        );
+        assert_eq!(
+            field.validate("abcdef"),
+            Err("Value must have at most 5 characters".to_string())
+        );
+        assert_eq!(field.validate("abc"), Ok(Value::String("abc".to_string())));
+        // both count value without surrounding whitespace
+        assert_eq!(MinLen(2).validate("  ab  "), None);
+        assert_eq!(MaxLen(2).validate("  ab  "), None);
+    }
+
+    #[test]
+    fn filesystem_validators_check_path_kind() {
+        assert_eq!(IsFile.validate("./src"), Some("It's not a file".to_string()));
+        assert_eq!(IsDir.validate("./src"), None);
+        assert_eq!(PathExists.validate("./Cargo.toml"), None);
+        assert_eq!(Writable.validate("new-file"), None);
+        assert_eq!(Writable.validate("./Cargo.toml"), None);
+    }
+
+    #[test]
+    fn url_and_email_are_checked_syntactically() {
+        assert_eq!(Url.validate("ftp://user@host/file"), None);
+        assert_eq!(Url.validate("http://"), Some("Value must be a URL".to_string()));
+        assert_eq!(Url.validate("http://exa mple.com"), Some("Value must be a URL".to_string()));
+        assert_eq!(Email.validate("a.b+c@sub.example.org"), None);
+        assert_eq!(Email.validate("a@b@example.org"), Some("Value must be an email".to_string()));
+        assert_eq!(Email.validate("a@example."), Some("Value must be an email".to_string()));
+        assert_eq!(Email.validate(""), None);
+    }
+
+    #[test]
+    fn closure_validator_sees_data() {
+        let field = Text::new("f")
+            .validator(Required)
+            .validator(|v: &str| if v.len() < 3 { Ok(()) } else { Err("too long".into()) });
+        assert_eq!(field.validate(""), Err("Field is required".to_string()));
+        assert_eq!(field.validate("abc"), Err("too long".to_string()));
+        assert_eq!(field.validate("ab"), Ok(Value::String("ab".to_string())));
+    }
+
+    #[test]
+    fn message_replaces_errors_only() {
+        let field = Text::new("f")
+            .validator(Required.message("Name is missing"))
+            .validator(SameAs("other").message("Names differ"));
+        assert!(field.is_required());
+        assert_eq!(field.validate(""), Err("Name is missing".to_string()));
+        let mut context = Context::new();
+        context.insert("other".to_string(), "a".to_string());
+        assert_eq!(field.validate_with("b", &context), Err("Names differ".to_string()));
+        assert_eq!(
+            field.validate_with("a", &context),
+            Ok(Value::String("a".to_string()))
+        );
+    }
 }
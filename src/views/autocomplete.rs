@@ -8,7 +8,7 @@ use cursive::view::ViewWrapper;
 use cursive::views::{EditView, LinearLayout, SelectView};
 
 use feeders::Feeder;
-use super::is_value_from_select;
+use super::{is_value_from_select, EditWithPlaceholder};
 
 // TODO: better performance while typing
 
@@ -34,7 +34,7 @@ impl Autocomplete {
         let shown_count = 5;
 
         let select = SelectView::<String>::new()
-                .with_all_str(feeder.query("", 0, shown_count).into_iter())
+                .with_all_str(feeder.query("", 0, shown_count))
                 //TODO: make fixed height for select equal to shown_count
                 // use cursive::traits::Boxable;
                 //.fixed_height(shown_count)
@@ -43,10 +43,10 @@ impl Autocomplete {
                 ;
 
         let layout = LinearLayout::vertical()
-            .child(EditView::new())
+            .child(EditWithPlaceholder::new(EditView::new()))
             .child(select);
 
-        let ac = Autocomplete {
+        Autocomplete {
             view: layout,
 
             feeder: Rc::new(feeder),
@@ -56,9 +56,7 @@ impl Autocomplete {
             typed_value: Rc::new("".to_string()),
 
             on_submit: None,
-        };
-
-        ac
+        }
     }
 
     /// Get typed in value
@@ -74,8 +72,23 @@ impl Autocomplete {
 
     /// Sets text value
     pub fn value(mut self, initial: &str) -> Self {
-        self.get_edit_view_mut().set_content(initial);
+        self.set_value(initial);
+        self
+    }
+
+    /// Sets text value
+    ///
+    /// Non-chainable variant.
+    pub fn set_value(&mut self, value: &str) {
+        self.get_edit_view_mut().set_content(value);
+        self.typed_value = Rc::new(value.to_string());
+        self.suggestion_offset = 0;
         self.refresh_listing();
+    }
+
+    /// Sets text shown while value is empty
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.get_edit_mut().set_placeholder(placeholder);
         self
     }
 
@@ -86,14 +99,14 @@ impl Autocomplete {
         let shown_count = self.shown_count as usize;
         let select = self.get_select_view_mut();
         select.clear();
-        select.add_all_str((*feeder).query(text.as_ref(), 0, shown_count).into_iter());
+        select.add_all_str((*feeder).query(text.as_ref(), 0, shown_count));
     }
 
     /// Copy selected text to edit view
     fn selection_to_edit(&mut self) {
         if !self.get_select_view().is_empty() {
             let selection = self.get_select_view_mut().selection();
-            self.get_edit_view_mut().set_content((&*selection).clone());
+            self.get_edit_view_mut().set_content((*selection).clone());
         }
     }
 
@@ -103,24 +116,32 @@ impl Autocomplete {
         is_value_from_select(select, to_check)
     }
 
-    fn get_edit_view(&self) -> &EditView {
+    fn get_edit(&self) -> &EditWithPlaceholder {
         self.view
             .get_child(0)
             .unwrap()
             .as_any()
-            .downcast_ref::<EditView>()
+            .downcast_ref::<EditWithPlaceholder>()
             .unwrap()
     }
 
-    fn get_edit_view_mut(&mut self) -> &mut EditView {
+    fn get_edit_mut(&mut self) -> &mut EditWithPlaceholder {
         self.view
             .get_child_mut(0)
             .unwrap()
             .as_any_mut()
-            .downcast_mut::<EditView>()
+            .downcast_mut::<EditWithPlaceholder>()
             .unwrap()
     }
 
+    fn get_edit_view(&self) -> &EditView {
+        self.get_edit().get_inner()
+    }
+
+    fn get_edit_view_mut(&mut self) -> &mut EditView {
+        self.get_edit_mut().get_inner_mut()
+    }
+
     fn get_select_view(&self) -> &SelectView {
         self.view
             .get_child(1)
@@ -147,7 +168,7 @@ impl Autocomplete {
         if data.len() == shown_count {
             let select = self.get_select_view_mut();
             select.clear();
-            select.add_all_str(data.into_iter());
+            select.add_all_str(data);
             true
         } else {
             false
@@ -237,7 +258,7 @@ impl ViewWrapper for Autocomplete {
                 let to_submit = self.get_edit_view().get_content();
 
                 if !self.submit_anything {
-                    let from_select = self.is_value_from_select(&*to_submit);
+                    let from_select = self.is_value_from_select(&to_submit);
                     if !from_select {
                         return EventResult::Ignored;
                     }
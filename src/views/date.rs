@@ -0,0 +1,91 @@
+use cursive::event::{Event, EventResult, Key};
+use cursive::traits::View;
+use cursive::view::ViewWrapper;
+use cursive::views::EditView;
+
+use utils::{add_days, format_date, parse_date, today};
+use super::EditWithPlaceholder;
+
+/// Format shown while `DateEdit` is empty.
+const FORMAT: &str = "YYYY-MM-DD";
+
+/// `EditView` for dates in `YYYY-MM-DD` format.
+///
+/// Accepts only digits (separators are added while typing) and changes date by a day with Up &
+/// Down keys.
+pub struct DateEdit {
+    view: EditWithPlaceholder,
+}
+
+impl Default for DateEdit {
+    fn default() -> Self {
+        DateEdit::new()
+    }
+}
+
+impl DateEdit {
+    /// Creates a new empty `DateEdit`.
+    pub fn new() -> Self {
+        DateEdit {
+            view: EditWithPlaceholder::new(EditView::new()).placeholder(FORMAT),
+        }
+    }
+
+    /// Sets text value
+    pub fn value(mut self, value: &str) -> Self {
+        self.view.get_inner_mut().set_content(value);
+        self
+    }
+
+    inner_getters!(self.view: EditWithPlaceholder);
+
+    fn content(&self) -> String {
+        (*self.view.get_inner().get_content()).clone()
+    }
+
+    /// Inserts digit `c` preceded by separator when it's expected.
+    fn type_digit(&mut self, c: char) {
+        let len = self.content().len();
+        if len >= FORMAT.len() {
+            return;
+        }
+        let edit = self.view.get_inner_mut();
+        if len == 4 || len == 7 {
+            edit.insert('-');
+        }
+        edit.insert(c);
+    }
+
+    /// Changes date by `days`, empty (or invalid) value starts from today.
+    fn change_by(&mut self, days: i64) {
+        let date = match parse_date(&self.content()) {
+            Some(date) => add_days(date, days),
+            None => today(),
+        };
+        self.view.get_inner_mut().set_content(format_date(date));
+    }
+}
+
+impl ViewWrapper for DateEdit {
+    wrap_impl!(self.view: EditWithPlaceholder);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Char(c) if c.is_ascii_digit() => {
+                self.type_digit(c);
+                EventResult::Consumed(None)
+            }
+            Event::Char('-') => self.view.on_event(event),
+            Event::Char(_) => EventResult::Consumed(None),
+            Event::Key(Key::Up) => {
+                self.change_by(1);
+                EventResult::Consumed(None)
+            }
+            Event::Key(Key::Down) => {
+                self.change_by(-1);
+                EventResult::Consumed(None)
+            }
+            _ => self.view.on_event(event),
+        }
+    }
+}
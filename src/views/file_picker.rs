@@ -0,0 +1,373 @@
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use cursive::Cursive;
+use cursive::event::{Callback, Event, EventResult, Key};
+use cursive::traits::{Boxable, View};
+use cursive::vec::Vec2;
+use cursive::view::ViewWrapper;
+use cursive::views::{Dialog, EditView, SelectView};
+
+use utils::has_extension;
+use super::EditWithPlaceholder;
+
+type OnSubmit = Option<Rc<Fn(&mut Cursive, PathBuf)>>;
+
+/// Size of popup with `FileTree`.
+const POPUP_WIDTH: usize = 50;
+const POPUP_HEIGHT: usize = 20;
+
+struct Entry {
+    path: PathBuf,
+    depth: usize,
+    is_dir: bool,
+    expanded: bool,
+}
+
+/// Navigable tree of files & dirs.
+///
+/// Right (Left) expands (collapses) dir, Enter picks selected item (or toggles dir when only files
+/// can be picked).
+pub struct FileTree {
+    view: SelectView<usize>,
+    root: PathBuf,
+    entries: Vec<Entry>,
+    dirs_only: bool,
+    extensions: Vec<String>,
+    on_submit: OnSubmit,
+}
+
+impl FileTree {
+    /// Creates a new `FileTree` showing content of `root`.
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        let mut tree = FileTree {
+            view: SelectView::new(),
+            root: root.as_ref().to_path_buf(),
+            entries: Vec::new(),
+            dirs_only: false,
+            extensions: Vec::new(),
+            on_submit: None,
+        };
+        tree.reload();
+        tree
+    }
+
+    /// Shows (and allows to pick) only dirs.
+    pub fn dirs_only(mut self) -> Self {
+        self.dirs_only = true;
+        self.reload();
+        self
+    }
+
+    /// Shows only files with one of `extensions` (like `txt` or `tar.gz`).
+    pub fn extensions(mut self, extensions: Vec<String>) -> Self {
+        self.extensions = extensions;
+        self.reload();
+        self
+    }
+
+    /// Sets the function to be called when item is picked.
+    pub fn on_submit<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, PathBuf) + 'static,
+    {
+        self.on_submit = Some(Rc::new(callback));
+        self
+    }
+
+    /// Returns path of selected item.
+    pub fn selection(&self) -> Option<PathBuf> {
+        self.view
+            .selected_id()
+            .map(|idx| self.entries[idx].path.clone())
+    }
+
+    fn reload(&mut self) {
+        self.entries = self.read_dir(&self.root, 0);
+        self.refresh(0);
+    }
+
+    /// Lists `dir` (dirs first, hidden items are skipped).
+    fn read_dir(&self, dir: &Path, depth: usize) -> Vec<Entry> {
+        let read = match fs::read_dir(dir) {
+            Ok(read) => read,
+            Err(_) => return Vec::new(),
+        };
+        let mut entries = read.filter_map(|e| e.ok())
+            .filter(|e| !e.file_name().to_string_lossy().starts_with('.'))
+            .map(|e| {
+                let path = if dir == Path::new(".") {
+                    PathBuf::from(e.file_name())
+                } else {
+                    e.path()
+                };
+                let is_dir = path.is_dir();
+                Entry {
+                    path,
+                    depth,
+                    is_dir,
+                    expanded: false,
+                }
+            })
+            .filter(|e| {
+                if e.is_dir {
+                    true
+                } else {
+                    !self.dirs_only && has_extension(&e.path, &self.extensions)
+                }
+            })
+            .collect::<Vec<Entry>>();
+        entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then(a.path.cmp(&b.path)));
+        entries
+    }
+
+    /// Rebuilds listing and selects item at `idx`.
+    fn refresh(&mut self, idx: usize) {
+        self.view.clear();
+        for (i, entry) in self.entries.iter().enumerate() {
+            let name = entry
+                .path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or(format!("{}", entry.path.display()));
+            let label = match (entry.is_dir, entry.expanded) {
+                (true, true) => format!("{}- {}/", "  ".repeat(entry.depth), name),
+                (true, false) => format!("{}+ {}/", "  ".repeat(entry.depth), name),
+                (false, _) => format!("{}  {}", "  ".repeat(entry.depth), name),
+            };
+            self.view.add_item(label, i);
+        }
+        if !self.entries.is_empty() {
+            self.view.set_selection(idx.min(self.entries.len() - 1));
+        }
+    }
+
+    fn expand(&mut self, idx: usize) {
+        if !self.entries[idx].is_dir || self.entries[idx].expanded {
+            return;
+        }
+        let children = {
+            let entry = &self.entries[idx];
+            self.read_dir(&entry.path, entry.depth + 1)
+        };
+        self.entries[idx].expanded = true;
+        let tail = self.entries.split_off(idx + 1);
+        self.entries.extend(children);
+        self.entries.extend(tail);
+        self.refresh(idx);
+    }
+
+    fn collapse(&mut self, idx: usize) {
+        if !self.entries[idx].expanded {
+            return;
+        }
+        let depth = self.entries[idx].depth;
+        let end = (idx + 1..self.entries.len())
+            .find(|&i| self.entries[i].depth <= depth)
+            .unwrap_or(self.entries.len());
+        self.entries.drain(idx + 1..end);
+        self.entries[idx].expanded = false;
+        self.refresh(idx);
+    }
+
+    /// Collapses dir or moves selection to its parent.
+    fn event_left(&mut self, idx: usize) {
+        if self.entries[idx].expanded {
+            self.collapse(idx);
+            return;
+        }
+        let depth = self.entries[idx].depth;
+        if let Some(parent) = (0..idx).rev().find(|&i| self.entries[i].depth < depth) {
+            self.view.set_selection(parent);
+        }
+    }
+
+    fn event_submit(&mut self, idx: usize) -> EventResult {
+        let entry_is_dir = self.entries[idx].is_dir;
+        if entry_is_dir && !self.dirs_only {
+            if self.entries[idx].expanded {
+                self.collapse(idx);
+            } else {
+                self.expand(idx);
+            }
+            return EventResult::Consumed(None);
+        }
+        let path = self.entries[idx].path.clone();
+        let cb = self.on_submit
+            .clone()
+            .map(|on_submit| Callback::from_fn(move |c| on_submit(c, path.clone())));
+        EventResult::Consumed(cb)
+    }
+}
+
+impl ViewWrapper for FileTree {
+    wrap_impl!(self.view: SelectView<usize>);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let idx = match self.view.selected_id() {
+            Some(idx) => idx,
+            None => return self.view.on_event(event),
+        };
+        match event {
+            Event::Key(Key::Right) => {
+                self.expand(idx);
+                EventResult::Consumed(None)
+            }
+            Event::Key(Key::Left) => {
+                self.event_left(idx);
+                EventResult::Consumed(None)
+            }
+            Event::Key(Key::Enter) => self.event_submit(idx),
+            _ => self.view.on_event(event),
+        }
+    }
+}
+
+/// `EditView` with path which opens `FileTree` popup with Enter key.
+pub struct FilePickerEdit {
+    view: EditWithPlaceholder,
+    dirs_only: bool,
+    extensions: Vec<String>,
+    // path picked in popup, it's moved to `view` during the next layout
+    picked: Rc<RefCell<Option<String>>>,
+}
+
+impl Default for FilePickerEdit {
+    fn default() -> Self {
+        FilePickerEdit::new()
+    }
+}
+
+impl FilePickerEdit {
+    /// Creates a new `FilePickerEdit` for files.
+    pub fn new() -> Self {
+        FilePickerEdit {
+            view: EditWithPlaceholder::new(EditView::new()),
+            dirs_only: false,
+            extensions: Vec::new(),
+            picked: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Picks only dirs.
+    pub fn dirs_only(mut self) -> Self {
+        self.dirs_only = true;
+        self
+    }
+
+    /// Shows only files with one of `extensions` in popup.
+    pub fn extensions(mut self, extensions: Vec<String>) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Sets text shown (dimmed) while view is empty.
+    pub fn placeholder<IS: Into<String>>(mut self, placeholder: IS) -> Self {
+        self.view = self.view.placeholder(placeholder);
+        self
+    }
+
+    /// Sets text value
+    pub fn value(mut self, value: &str) -> Self {
+        self.view.get_inner_mut().set_content(value);
+        self
+    }
+
+    inner_getters!(self.view: EditWithPlaceholder);
+
+    /// Builds `FileTree` starting from dir of current value (or current dir).
+    fn build_tree(&self) -> FileTree {
+        let content = self.view.get_inner().get_content();
+        let current = Path::new(content.as_str());
+        let root = if current.is_dir() {
+            current.to_path_buf()
+        } else {
+            current
+                .parent()
+                .filter(|p| p.is_dir())
+                .map(|p| p.to_path_buf())
+                .unwrap_or(PathBuf::from("."))
+        };
+        let mut tree = FileTree::new(root).extensions(self.extensions.clone());
+        if self.dirs_only {
+            tree = tree.dirs_only();
+        }
+        let picked = Rc::clone(&self.picked);
+        tree.on_submit(move |c, path| {
+            *picked.borrow_mut() = Some(format!("{}", path.display()));
+            c.pop_layer();
+        })
+    }
+
+    fn event_open(&mut self) -> EventResult {
+        let tree = self.build_tree();
+        let title = if self.dirs_only {
+            "Select dir"
+        } else {
+            "Select file"
+        };
+        let tree = RefCell::new(Some(tree));
+        let cb = Callback::from_fn(move |c| {
+            if let Some(tree) = tree.borrow_mut().take() {
+                let dialog = Dialog::around(tree.max_size((POPUP_WIDTH, POPUP_HEIGHT)))
+                    .title(title)
+                    .dismiss_button("Cancel");
+                c.add_layer(dialog);
+            }
+        });
+        EventResult::Consumed(Some(cb))
+    }
+}
+
+impl ViewWrapper for FilePickerEdit {
+    wrap_impl!(self.view: EditWithPlaceholder);
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        if let Some(picked) = self.picked.borrow_mut().take() {
+            self.view.get_inner_mut().set_content(picked);
+        }
+        self.view.layout(size);
+    }
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Enter) => self.event_open(),
+            _ => self.view.on_event(event),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsStr;
+
+    #[test]
+    fn tree_lists_dirs_first_and_filters_files() {
+        let tree = FileTree::new("src").extensions(vec!["rs".to_string()]);
+        let paths = tree.entries.iter().map(|e| e.path.clone()).collect::<Vec<_>>();
+        assert_eq!(paths[0], PathBuf::from("src/fields"));
+        assert_eq!(paths[1], PathBuf::from("src/views"));
+        assert!(paths[2..].iter().all(|p| p.extension() == Some(OsStr::new("rs"))));
+
+        let tree = FileTree::new("src").dirs_only();
+        assert!(tree.entries.iter().all(|e| e.is_dir));
+    }
+
+    #[test]
+    fn tree_is_expanded_and_collapsed() {
+        let mut tree = FileTree::new("src");
+        let count = tree.entries.len();
+
+        tree.on_event(Event::Key(Key::Right));
+        tree.on_event(Event::Key(Key::Down));
+        assert_eq!(tree.selection(), Some(PathBuf::from("src/fields/autocomplete.rs")));
+
+        tree.on_event(Event::Key(Key::Left));
+        assert_eq!(tree.selection(), Some(PathBuf::from("src/fields")));
+        tree.on_event(Event::Key(Key::Left));
+        assert_eq!(tree.entries.len(), count);
+    }
+}
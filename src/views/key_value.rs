@@ -0,0 +1,194 @@
+use cursive::event::{Event, EventResult, Key};
+use cursive::traits::{Boxable, View};
+use cursive::view::ViewWrapper;
+use cursive::views::{BoxView, EditView, LinearLayout, SelectView, TextView};
+
+use super::EditWithPlaceholder;
+
+/// Width of key edit.
+const KEY_WIDTH: usize = 16;
+
+/// Editor of key-value pairs.
+///
+/// Pair is added with Enter (pair with the same key is replaced), in the list of added pairs
+/// Delete removes the selected one.
+pub struct KeyValueEdit {
+    view: LinearLayout,
+    pairs: Vec<(String, String)>,
+}
+
+impl Default for KeyValueEdit {
+    fn default() -> Self {
+        KeyValueEdit::new()
+    }
+}
+
+impl KeyValueEdit {
+    /// Creates a new `KeyValueEdit` without pairs.
+    pub fn new() -> Self {
+        let key = EditWithPlaceholder::new(EditView::new()).placeholder("key");
+        let value = EditWithPlaceholder::new(EditView::new()).placeholder("value");
+        let editors = LinearLayout::horizontal()
+            .child(key.fixed_width(KEY_WIDTH))
+            .child(TextView::new(" = "))
+            .child(value.full_width());
+        let view = LinearLayout::vertical()
+            .child(SelectView::<usize>::new())
+            .child(editors);
+        KeyValueEdit {
+            view,
+            pairs: Vec::new(),
+        }
+    }
+
+    /// Sets pairs.
+    pub fn pairs(mut self, pairs: Vec<(String, String)>) -> Self {
+        self.set_pairs(pairs);
+        self
+    }
+
+    /// Sets pairs (and clears editors).
+    ///
+    /// Non-chainable variant.
+    pub fn set_pairs(&mut self, pairs: Vec<(String, String)>) {
+        self.pairs.clear();
+        for (k, v) in pairs {
+            self.add_pair(k, v);
+        }
+        self.get_edit_mut(0).set_content("");
+        self.get_edit_mut(2).set_content("");
+        self.refresh_listing();
+    }
+
+    /// Gets pairs including the one being edited (if it has key).
+    pub fn get_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = self.pairs.clone();
+        let key = self.get_edit(0).get_content().trim().to_string();
+        if !key.is_empty() {
+            let value = (*self.get_edit(2).get_content()).clone();
+            match pairs.iter().position(|(k, _)| *k == key) {
+                Some(idx) => pairs[idx].1 = value,
+                None => pairs.push((key, value)),
+            }
+        }
+        pairs
+    }
+
+    fn add_pair(&mut self, key: String, value: String) {
+        match self.pairs.iter().position(|(k, _)| *k == key) {
+            Some(idx) => self.pairs[idx].1 = value,
+            None => self.pairs.push((key, value)),
+        }
+    }
+
+    /// Moves edited pair to list, returns false if key is missing.
+    fn commit_edited(&mut self) -> bool {
+        let key = self.get_edit(0).get_content().trim().to_string();
+        if key.is_empty() {
+            return false;
+        }
+        let value = (*self.get_edit(2).get_content()).clone();
+        self.add_pair(key, value);
+        self.get_edit_mut(0).set_content("");
+        self.get_edit_mut(2).set_content("");
+        self.refresh_listing();
+        true
+    }
+
+    fn remove_selected(&mut self) {
+        let selected = self.get_select().selected_id();
+        if let Some(idx) = selected {
+            self.pairs.remove(idx);
+            self.refresh_listing();
+            if !self.pairs.is_empty() {
+                let last = self.pairs.len() - 1;
+                self.get_select_mut().set_selection(idx.min(last));
+            }
+        }
+    }
+
+    fn refresh_listing(&mut self) {
+        let labels = self.pairs
+            .iter()
+            .map(|(k, v)| format!("{} = {}", k, v))
+            .collect::<Vec<String>>();
+        let select = self.get_select_mut();
+        select.clear();
+        for (idx, label) in labels.into_iter().enumerate() {
+            select.add_item(label, idx);
+        }
+    }
+
+    fn get_select(&self) -> &SelectView<usize> {
+        self.view
+            .get_child(0)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SelectView<usize>>()
+            .unwrap()
+    }
+
+    fn get_select_mut(&mut self) -> &mut SelectView<usize> {
+        self.view
+            .get_child_mut(0)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<SelectView<usize>>()
+            .unwrap()
+    }
+
+    /// Gets editor of key (`idx` = 0) or value (`idx` = 2).
+    fn get_edit(&self, idx: usize) -> &EditView {
+        self.view
+            .get_child(1)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap()
+            .get_child(idx)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<BoxView<EditWithPlaceholder>>()
+            .unwrap()
+            .get_inner()
+            .get_inner()
+    }
+
+    fn get_edit_mut(&mut self, idx: usize) -> &mut EditView {
+        self.view
+            .get_child_mut(1)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap()
+            .get_child_mut(idx)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<BoxView<EditWithPlaceholder>>()
+            .unwrap()
+            .get_inner_mut()
+            .get_inner_mut()
+    }
+}
+
+impl ViewWrapper for KeyValueEdit {
+    wrap_impl!(self.view: LinearLayout);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let is_list_focused = self.view.get_focus_index() == 0;
+        match event {
+            Event::Key(Key::Enter) if !is_list_focused => {
+                if self.commit_edited() {
+                    EventResult::Consumed(None)
+                } else {
+                    EventResult::Ignored
+                }
+            }
+            Event::Key(Key::Del) if is_list_focused => {
+                self.remove_selected();
+                EventResult::Consumed(None)
+            }
+            _ => self.view.on_event(event),
+        }
+    }
+}
@@ -1,9 +1,25 @@
 //! Contains views which are building blocks for `fields`.
 mod autocomplete;
+mod date;
+mod file_picker;
+mod key_value;
 mod multiselect;
+mod number;
+mod placeholder;
+mod slider;
+mod tags;
+mod time;
 
 pub use self::autocomplete::Autocomplete;
+pub use self::date::DateEdit;
+pub use self::file_picker::{FilePickerEdit, FileTree};
+pub use self::key_value::KeyValueEdit;
 pub use self::multiselect::Multiselect;
+pub use self::number::NumberEdit;
+pub use self::placeholder::EditWithPlaceholder;
+pub use self::slider::SliderEdit;
+pub use self::tags::TagsEdit;
+pub use self::time::TimeEdit;
 
 use cursive::views::SelectView;
 
@@ -16,5 +32,5 @@ fn is_value_from_select(select: &SelectView, to_check: &str) -> bool {
             return true;
         }
     }
-    return false;
+    false
 }
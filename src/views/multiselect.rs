@@ -93,7 +93,7 @@ impl Multiselect {
     fn select_item(&mut self) -> Rc<String> {
         let selected_text = self.get_options_view().get_value();
         self.get_selected_view_mut()
-            .add_item_str((&*selected_text).clone());
+            .add_item_str((*selected_text).clone());
         selected_text
     }
 
@@ -115,6 +115,11 @@ impl Multiselect {
         selected_view.add_all_str(items);
     }
 
+    /// Removes all selected items
+    pub fn clear_selection(&mut self) {
+        self.get_selected_view_mut().clear();
+    }
+
     /// Checks if `to_check` is already selected
     pub fn is_value_selected(&self, to_check: &str) -> bool {
         let select = self.get_selected_view();
@@ -124,9 +129,9 @@ impl Multiselect {
     /// Returns successfully selected item or None
     fn try_select_item(&mut self) -> Option<Rc<String>> {
         let typed_value = self.get_options_view().get_value();
-        let from_select = self.get_options_view().is_value_from_select(&*typed_value);
-        if (typed_value.len() > 0) & (self.select_anything | from_select) {
-            if !self.redundant_selection & self.is_value_selected(&*typed_value) {
+        let from_select = self.get_options_view().is_value_from_select(&typed_value);
+        if (!typed_value.is_empty()) & (self.select_anything | from_select) {
+            if !self.redundant_selection & self.is_value_selected(&typed_value) {
                 None
             } else {
                 Some(self.select_item())
@@ -141,7 +146,7 @@ impl Multiselect {
         if let Some(idx) = selected_view.selected_id() {
             let item = selected_view.selection();
             selected_view.remove_item(idx);
-            return Some(item);
+            Some(item)
         } else {
             None
         }
@@ -196,6 +201,21 @@ impl Multiselect {
     }
 }
 
+impl Multiselect {
+    fn event_deselect(&mut self) -> EventResult {
+        if let Some(deselected) = self.deselect_item() {
+            let cb = self.on_deselect.clone().map(|on_deselect| {
+                Callback::from_fn(move |c| {
+                    on_deselect(c, deselected.clone());
+                })
+            });
+            EventResult::Consumed(cb)
+        } else {
+            EventResult::Consumed(None)
+        }
+    }
+}
+
 impl ViewWrapper for Multiselect {
     wrap_impl!(self.view: LinearLayout);
 
@@ -218,18 +238,13 @@ impl ViewWrapper for Multiselect {
                     }
                 }
                 if focused == self.selected_idx as usize {
-                    // on deselect
-                    if let Some(deselected) = self.deselect_item() {
-                        let cb = self.on_deselect.clone().map(|on_deselect| {
-                            Callback::from_fn(move |c| {
-                                on_deselect(c, deselected.clone());
-                            })
-                        });
-                        return EventResult::Consumed(cb);
-                    }
+                    return self.event_deselect();
                 }
                 EventResult::Consumed(None)
             }
+            Event::Char(' ') if self.view.get_focus_index() == self.selected_idx as usize => {
+                self.event_deselect()
+            }
             _ => self.with_view_mut(|v| v.on_event(event))
                 .unwrap_or(EventResult::Ignored),
         }
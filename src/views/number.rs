@@ -0,0 +1,103 @@
+use cursive::event::{Event, EventResult, Key};
+use cursive::traits::View;
+use cursive::view::ViewWrapper;
+use cursive::views::EditView;
+
+/// `EditView` which accepts only numbers and changes them by `step` with Up & Down keys.
+pub struct NumberEdit {
+    view: EditView,
+    integer: bool,
+    min: Option<f64>,
+    max: Option<f64>,
+    step: f64,
+}
+
+impl NumberEdit {
+    /// Creates a new `NumberEdit` for integers (if `integer`) or floats.
+    pub fn new(integer: bool) -> Self {
+        NumberEdit {
+            view: EditView::new(),
+            integer,
+            min: None,
+            max: None,
+            step: 1.0,
+        }
+    }
+
+    /// Sets the lowest value reachable with Down key.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the highest value reachable with Up key.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets value added (subtracted) with Up (Down) key.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets text value
+    pub fn value(mut self, value: &str) -> Self {
+        self.view.set_content(value);
+        self
+    }
+
+    inner_getters!(self.view: EditView);
+
+    fn accepts(&self, c: char) -> bool {
+        match c {
+            c if c.is_ascii_digit() => true,
+            '-' => self.min.map(|min| min < 0.0).unwrap_or(true),
+            '.' => !self.integer && !self.view.get_content().contains('.'),
+            _ => false,
+        }
+    }
+
+    /// Changes value by `delta` keeping it in range, empty value starts from `min` (or 0).
+    fn change_by(&mut self, delta: f64) {
+        let current = self.view.get_content().parse::<f64>().ok();
+        let mut value = match current {
+            Some(v) => v + delta,
+            None => self.min.unwrap_or(0.0),
+        };
+        if let Some(min) = self.min {
+            value = value.max(min);
+        }
+        if let Some(max) = self.max {
+            value = value.min(max);
+        }
+        let text = if self.integer {
+            format!("{}", value.round() as i64)
+        } else {
+            format!("{}", value)
+        };
+        self.view.set_content(text);
+    }
+}
+
+impl ViewWrapper for NumberEdit {
+    wrap_impl!(self.view: EditView);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Char(c) if !self.accepts(c) => EventResult::Consumed(None),
+            Event::Key(Key::Up) => {
+                let step = self.step;
+                self.change_by(step);
+                EventResult::Consumed(None)
+            }
+            Event::Key(Key::Down) => {
+                let step = self.step;
+                self.change_by(-step);
+                EventResult::Consumed(None)
+            }
+            _ => self.view.on_event(event),
+        }
+    }
+}
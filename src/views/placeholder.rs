@@ -0,0 +1,65 @@
+use cursive::Printer;
+use cursive::theme::{ColorStyle, Effect};
+use cursive::traits::View;
+use cursive::view::ViewWrapper;
+use cursive::views::EditView;
+
+/// `EditView` which shows dimmed `placeholder` while its content is empty.
+///
+/// `placeholder` is only drawn, it never becomes the content.
+pub struct EditWithPlaceholder {
+    view: EditView,
+    placeholder: String,
+}
+
+impl EditWithPlaceholder {
+    /// Creates a new `EditWithPlaceholder` wrapping `view`.
+    pub fn new(view: EditView) -> Self {
+        EditWithPlaceholder {
+            view,
+            placeholder: "".to_string(),
+        }
+    }
+
+    /// Sets text shown while content is empty.
+    pub fn placeholder<IS: Into<String>>(mut self, placeholder: IS) -> Self {
+        self.set_placeholder(placeholder);
+        self
+    }
+
+    /// Sets text shown while content is empty.
+    ///
+    /// Non-chainable variant.
+    pub fn set_placeholder<IS: Into<String>>(&mut self, placeholder: IS) {
+        self.placeholder = placeholder.into();
+    }
+
+    /// Gets text shown while content is empty.
+    pub fn get_placeholder(&self) -> &str {
+        &self.placeholder
+    }
+
+    inner_getters!(self.view: EditView);
+}
+
+impl ViewWrapper for EditWithPlaceholder {
+    wrap_impl!(self.view: EditView);
+
+    fn wrap_draw(&self, printer: &Printer) {
+        self.view.draw(printer);
+        if self.placeholder.is_empty() || !self.view.get_content().is_empty() {
+            return;
+        }
+        // keep cursor visible
+        let start = if printer.focused { 1 } else { 0 };
+        let text = self.placeholder
+            .chars()
+            .take(printer.size.x.saturating_sub(start))
+            .collect::<String>();
+        printer.with_color(ColorStyle::tertiary(), |printer| {
+            printer.with_effect(Effect::Reverse, |printer| {
+                printer.print((start, 0), &text);
+            });
+        });
+    }
+}
@@ -0,0 +1,115 @@
+use cursive::event::{Event, EventResult, Key, MouseButton, MouseEvent};
+use cursive::traits::View;
+use cursive::view::ViewWrapper;
+use cursive::views::{LinearLayout, SliderView, TextView};
+
+/// `SliderView` picking values from `min` to `max` (by `step`) with current value shown aside.
+pub struct SliderEdit {
+    view: LinearLayout,
+    min: f64,
+    step: f64,
+    count: usize,
+    position: usize,
+}
+
+impl SliderEdit {
+    /// Creates a new `SliderEdit` set to `min`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` isn't positive or `max` is less than `min`.
+    pub fn new(min: f64, max: f64, step: f64) -> Self {
+        assert!(step > 0.0, "SliderEdit's step must be positive, got: {}", step);
+        assert!(max >= min, "SliderEdit's max ({}) is less than min ({})", max, min);
+        let count = ((max - min) / step).floor().max(0.0) as usize + 1;
+        let view = LinearLayout::horizontal()
+            .child(SliderView::horizontal(count))
+            .child(TextView::new(""));
+        let mut slider = SliderEdit {
+            view,
+            min,
+            step,
+            count,
+            position: 0,
+        };
+        slider.move_to(0);
+        slider
+    }
+
+    /// Sets value (it's rounded to the closest step).
+    pub fn value(mut self, value: f64) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Sets value (it's rounded to the closest step).
+    ///
+    /// Non-chainable variant.
+    pub fn set_value(&mut self, value: f64) {
+        let position = ((value - self.min) / self.step).round().max(0.0) as usize;
+        self.move_to(position);
+    }
+
+    /// Gets current value.
+    pub fn get_value(&self) -> f64 {
+        let value = self.min + self.position as f64 * self.step;
+        // drop float errors (e.g. 0.30000000000000004 for 3 * 0.1)
+        let precision = decimals(self.min).max(decimals(self.step));
+        format!("{:.*}", precision, value).parse().unwrap_or(value)
+    }
+
+    fn move_to(&mut self, position: usize) {
+        self.position = position.min(self.count - 1);
+        let text = format!(" {}", self.get_value());
+        let position = self.position;
+        self.view
+            .get_child_mut(0)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<SliderView>()
+            .unwrap()
+            .set_value(position);
+        self.view
+            .get_child_mut(1)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<TextView>()
+            .unwrap()
+            .set_content(text);
+    }
+}
+
+/// Counts digits after decimal point.
+fn decimals(value: f64) -> usize {
+    let text = format!("{}", value);
+    text.find('.').map(|idx| text.len() - idx - 1).unwrap_or(0)
+}
+
+impl ViewWrapper for SliderEdit {
+    wrap_impl!(self.view: LinearLayout);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let position = match event {
+            Event::Key(Key::Left) if self.position > 0 => self.position - 1,
+            Event::Key(Key::Right) if self.position + 1 < self.count => self.position + 1,
+            Event::Key(Key::Home) => 0,
+            Event::Key(Key::End) => self.count - 1,
+            Event::Mouse {
+                event: MouseEvent::Press(MouseButton::Left),
+                position,
+                offset,
+            }
+            | Event::Mouse {
+                event: MouseEvent::Hold(MouseButton::Left),
+                position,
+                offset,
+            } if position.fits_in_rect(offset, (self.count, 1)) =>
+            {
+                position.x - offset.x
+            }
+            _ => return self.view.on_event(event),
+        };
+        self.move_to(position);
+        EventResult::Consumed(None)
+    }
+}
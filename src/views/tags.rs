@@ -0,0 +1,142 @@
+use cursive::event::{Event, EventResult, Key};
+use cursive::traits::View;
+use cursive::view::ViewWrapper;
+use cursive::views::{EditView, LinearLayout, TextView};
+
+use super::EditWithPlaceholder;
+
+/// `EditView` collecting typed values as tags (shown above as `[tag]` chips).
+///
+/// Tag is added with `,` or Enter, Backspace on empty text removes the last one.
+pub struct TagsEdit {
+    view: LinearLayout,
+    tags: Vec<String>,
+}
+
+impl Default for TagsEdit {
+    fn default() -> Self {
+        TagsEdit::new()
+    }
+}
+
+impl TagsEdit {
+    /// Creates a new `TagsEdit` without tags.
+    pub fn new() -> Self {
+        let view = LinearLayout::vertical()
+            .child(TextView::new(""))
+            .child(EditWithPlaceholder::new(EditView::new()).placeholder("add with , or Enter"));
+        TagsEdit {
+            view,
+            tags: Vec::new(),
+        }
+    }
+
+    /// Sets tags.
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.set_tags(tags);
+        self
+    }
+
+    /// Sets tags (and clears typed text).
+    ///
+    /// Non-chainable variant.
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        self.tags.clear();
+        for tag in tags {
+            self.add_tag(&tag);
+        }
+        self.get_edit_mut().set_content("");
+        self.refresh_chips();
+    }
+
+    /// Gets tags including the one being typed.
+    pub fn get_tags(&self) -> Vec<String> {
+        let mut tags = self.tags.clone();
+        let typed = self.get_edit().get_content();
+        let typed = typed.trim();
+        if !typed.is_empty() && !tags.iter().any(|t| t == typed) {
+            tags.push(typed.to_string());
+        }
+        tags
+    }
+
+    fn add_tag(&mut self, tag: &str) {
+        let tag = tag.trim();
+        if !tag.is_empty() && !self.tags.iter().any(|t| t == tag) {
+            self.tags.push(tag.to_string());
+        }
+    }
+
+    /// Moves typed text to tags, returns false if there was nothing to move.
+    fn commit_typed(&mut self) -> bool {
+        let typed = self.get_edit().get_content();
+        if typed.trim().is_empty() {
+            return false;
+        }
+        self.add_tag(&typed);
+        self.get_edit_mut().set_content("");
+        self.refresh_chips();
+        true
+    }
+
+    fn refresh_chips(&mut self) {
+        let chips = self.tags
+            .iter()
+            .map(|t| format!("[{}]", t))
+            .collect::<Vec<String>>()
+            .join(" ");
+        self.view
+            .get_child_mut(0)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<TextView>()
+            .unwrap()
+            .set_content(chips);
+    }
+
+    fn get_edit(&self) -> &EditView {
+        self.view
+            .get_child(1)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<EditWithPlaceholder>()
+            .unwrap()
+            .get_inner()
+    }
+
+    fn get_edit_mut(&mut self) -> &mut EditView {
+        self.view
+            .get_child_mut(1)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<EditWithPlaceholder>()
+            .unwrap()
+            .get_inner_mut()
+    }
+}
+
+impl ViewWrapper for TagsEdit {
+    wrap_impl!(self.view: LinearLayout);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Char(',') => {
+                self.commit_typed();
+                EventResult::Consumed(None)
+            }
+            Event::Key(Key::Enter) => {
+                if self.commit_typed() {
+                    EventResult::Consumed(None)
+                } else {
+                    EventResult::Ignored
+                }
+            }
+            Event::Key(Key::Backspace) if self.get_edit().get_content().is_empty() => {
+                self.tags.pop();
+                self.refresh_chips();
+                EventResult::Consumed(None)
+            }
+            _ => self.view.on_event(event),
+        }
+    }
+}
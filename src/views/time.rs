@@ -0,0 +1,81 @@
+use cursive::traits::Boxable;
+use cursive::view::ViewWrapper;
+use cursive::views::{BoxView, LinearLayout, TextView};
+
+use super::NumberEdit;
+
+/// Width of hour (minute) edit, one cell is left for cursor.
+const PART_WIDTH: usize = 3;
+
+/// Time entry (`HH:MM`) made of hour & minute spinners (changed with Up & Down keys).
+pub struct TimeEdit {
+    view: LinearLayout,
+}
+
+impl Default for TimeEdit {
+    fn default() -> Self {
+        TimeEdit::new()
+    }
+}
+
+impl TimeEdit {
+    /// Creates a new empty `TimeEdit`.
+    pub fn new() -> Self {
+        let view = LinearLayout::horizontal()
+            .child(NumberEdit::new(true).min(0.0).max(23.0).fixed_width(PART_WIDTH))
+            .child(TextView::new(":"))
+            .child(NumberEdit::new(true).min(0.0).max(59.0).fixed_width(PART_WIDTH));
+        TimeEdit { view }
+    }
+
+    /// Sets value (`HH:MM`)
+    pub fn value(mut self, value: &str) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Sets value (`HH:MM`), seconds are dropped.
+    ///
+    /// Non-chainable variant.
+    pub fn set_value(&mut self, value: &str) {
+        let mut parts = value.split(':');
+        let hour = parts.next().unwrap_or("").to_string();
+        let minute = parts.next().unwrap_or("").to_string();
+        self.get_part_mut(0).get_inner_mut().set_content(hour);
+        self.get_part_mut(2).get_inner_mut().set_content(minute);
+    }
+
+    /// Gets value as `HH:MM` (empty if nothing was entered).
+    pub fn get_value(&self) -> String {
+        let hour = self.get_part(0).get_inner().get_content();
+        let minute = self.get_part(2).get_inner().get_content();
+        if hour.is_empty() && minute.is_empty() {
+            return "".to_string();
+        }
+        format!("{:0>2}:{:0>2}", hour, minute)
+    }
+
+    fn get_part(&self, idx: usize) -> &NumberEdit {
+        self.view
+            .get_child(idx)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<BoxView<NumberEdit>>()
+            .unwrap()
+            .get_inner()
+    }
+
+    fn get_part_mut(&mut self, idx: usize) -> &mut NumberEdit {
+        self.view
+            .get_child_mut(idx)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<BoxView<NumberEdit>>()
+            .unwrap()
+            .get_inner_mut()
+    }
+}
+
+impl ViewWrapper for TimeEdit {
+    wrap_impl!(self.view: LinearLayout);
+}
@@ -0,0 +1,272 @@
+//! Contains `Wizard` which leads user through several forms.
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use cursive::Cursive;
+use cursive::Printer;
+use cursive::direction::Direction;
+use cursive::event::{Event, EventResult};
+use cursive::vec::Vec2;
+use cursive::view::{Selector, View};
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use form::FormView;
+
+type OnSubmit = Option<Rc<Fn(&mut Cursive, Value)>>;
+type OnCancel = Option<Rc<Fn(&mut Cursive)>>;
+
+/// Change of step requested by step's callbacks, it's applied on next layout (or event).
+#[derive(Debug, PartialEq)]
+enum Move {
+    Next,
+    Back,
+}
+
+struct State {
+    // data submitted by steps (so far)
+    data: Vec<Value>,
+    steps: usize,
+    pending: Option<Move>,
+    on_submit: OnSubmit,
+    on_cancel: OnCancel,
+}
+
+impl State {
+    /// Merges data of all submitted steps, later steps override keys of earlier ones.
+    fn merged(&self) -> Value {
+        let mut merged = Map::new();
+        for data in &self.data {
+            if let Value::Object(ref map) = *data {
+                merged.extend(map.clone());
+            }
+        }
+        Value::Object(merged)
+    }
+}
+
+/// Shows `FormView`s one after another (`Next` submits the step, `Back` returns to the previous
+/// one) and calls `on_submit` with merged data of all steps once the last one is submitted.
+///
+/// Steps' `on_submit` & `on_cancel` callbacks are replaced by the wizard's.
+///
+/// # Panics
+///
+/// Wizard without any step panics when it's shown.
+///
+/// Examples
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::fields::{Checkbox, Text};
+/// # use fui::form::FormView;
+/// # use fui::wizard::Wizard;
+/// # fn main() {
+/// let wizard = Wizard::new()
+///     .step(FormView::new().title("Source").field(Text::new("src")))
+///     .step(FormView::new().title("Options").field(Checkbox::new("compress")))
+///     .on_submit(|c, data| {
+///         // data is like {"src": .., "compress": ..}
+///         c.quit();
+///     })
+///     .on_cancel(|c| c.quit());
+/// # }
+/// ```
+pub struct Wizard {
+    steps: Vec<FormView>,
+    current: usize,
+    state: Rc<RefCell<State>>,
+}
+
+impl Default for Wizard {
+    fn default() -> Self {
+        Wizard::new()
+    }
+}
+
+impl Wizard {
+    /// Creates a new `Wizard` without steps.
+    pub fn new() -> Self {
+        let state = State {
+            data: Vec::new(),
+            steps: 0,
+            pending: None,
+            on_submit: None,
+            on_cancel: None,
+        };
+        Wizard {
+            steps: Vec::new(),
+            current: 0,
+            state: Rc::new(RefCell::new(state)),
+        }
+    }
+
+    /// Appends `form` as the last step.
+    pub fn step(mut self, form: FormView) -> Self {
+        let idx = self.steps.len();
+        if let Some(previous) = self.steps.pop() {
+            self.steps.push(previous.submit_label("Next (Ctrl+f)"));
+        }
+        let state = Rc::clone(&self.state);
+        let mut form = form.on_submit(move |c, data| {
+            let (on_submit, merged) = {
+                let mut state = state.borrow_mut();
+                state.data.truncate(idx);
+                state.data.push(data);
+                if idx + 1 < state.steps {
+                    state.pending = Some(Move::Next);
+                    return;
+                }
+                (state.on_submit.clone(), state.merged())
+            };
+            if let Some(cb) = on_submit {
+                cb(c, merged);
+            }
+        });
+        let state = Rc::clone(&self.state);
+        form.set_on_cancel(move |c| {
+            let on_cancel = {
+                let mut state = state.borrow_mut();
+                if idx > 0 {
+                    state.pending = Some(Move::Back);
+                    return;
+                }
+                state.on_cancel.clone()
+            };
+            if let Some(cb) = on_cancel {
+                cb(c);
+            }
+        });
+        if idx > 0 {
+            form = form.cancel_label("Back");
+        }
+        self.steps.push(form);
+        self.state.borrow_mut().steps = self.steps.len();
+        self
+    }
+
+    /// Sets the function to be called with merged data of all steps when the last one is
+    /// submitted.
+    pub fn on_submit<F>(self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, Value) + 'static,
+    {
+        self.state.borrow_mut().on_submit = Some(Rc::new(callback));
+        self
+    }
+
+    /// Sets the function to be called when the first step is canceled.
+    pub fn on_cancel<F>(self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive) + 'static,
+    {
+        self.state.borrow_mut().on_cancel = Some(Rc::new(callback));
+        self
+    }
+
+    /// Gets merged data of steps submitted so far.
+    pub fn data(&self) -> Value {
+        self.state.borrow().merged()
+    }
+
+    /// Gets index of the shown step.
+    pub fn current_step(&self) -> usize {
+        self.current
+    }
+
+    /// Applies move requested by step's callbacks.
+    fn apply_move(&mut self) {
+        let pending = self.state.borrow_mut().pending.take();
+        match pending {
+            Some(Move::Next) if self.current + 1 < self.steps.len() => self.current += 1,
+            Some(Move::Back) if self.current > 0 => self.current -= 1,
+            _ => return,
+        }
+        self.steps[self.current].take_focus(Direction::front());
+    }
+}
+
+impl View for Wizard {
+    fn draw(&self, printer: &Printer) {
+        self.steps[self.current].draw(printer);
+    }
+
+    fn required_size(&mut self, req: Vec2) -> Vec2 {
+        self.apply_move();
+        self.steps[self.current].required_size(req)
+    }
+
+    fn needs_relayout(&self) -> bool {
+        self.state.borrow().pending.is_some() || self.steps[self.current].needs_relayout()
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.apply_move();
+        self.steps[self.current].layout(size);
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        self.apply_move();
+        self.steps[self.current].on_event(event)
+    }
+
+    fn take_focus(&mut self, source: Direction) -> bool {
+        self.steps[self.current].take_focus(source)
+    }
+
+    fn call_on_any<'a>(&mut self, selector: &Selector, mut callback: Box<FnMut(&mut Any) + 'a>) {
+        for step in &mut self.steps {
+            step.call_on_any(selector, Box::new(|any| callback(any)));
+        }
+    }
+
+    fn focus_view(&mut self, selector: &Selector) -> Result<(), ()> {
+        self.steps[self.current].focus_view(selector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fields::Text;
+
+    fn button_labels(form: &mut FormView) -> Vec<String> {
+        let mut labels = Vec::new();
+        let taken = ::std::mem::take(form);
+        *form = taken.with_dialog(|d| {
+            labels = d.buttons_mut().map(|b| b.label().to_owned()).collect();
+        });
+        labels
+    }
+
+    #[test]
+    fn steps_are_changed_and_data_merged() {
+        let mut wizard = Wizard::new()
+            .step(FormView::new().field(Text::new("src")))
+            .step(FormView::new().field(Text::new("dst")));
+        assert_eq!(button_labels(&mut wizard.steps[0])[0], "<Cancel>");
+        assert_eq!(button_labels(&mut wizard.steps[0])[2], "<Next (Ctrl+f)>");
+        assert_eq!(button_labels(&mut wizard.steps[1])[0], "<Back>");
+        assert_eq!(button_labels(&mut wizard.steps[1])[2], "<Submit (Ctrl+f)>");
+
+        {
+            let mut state = wizard.state.borrow_mut();
+            state.data.push(::serde_json::from_str(r#"{"src": "a", "dst": "old"}"#).unwrap());
+            state.data.push(::serde_json::from_str(r#"{"dst": "b"}"#).unwrap());
+            state.pending = Some(Move::Next);
+        }
+        wizard.layout(Vec2::new(80, 20));
+        assert_eq!(wizard.current_step(), 1);
+        let exp: Value = ::serde_json::from_str(r#"{"src": "a", "dst": "b"}"#).unwrap();
+        assert_eq!(wizard.data(), exp);
+
+        wizard.state.borrow_mut().pending = Some(Move::Next);
+        wizard.layout(Vec2::new(80, 20));
+        assert_eq!(wizard.current_step(), 1);
+
+        wizard.state.borrow_mut().pending = Some(Move::Back);
+        wizard.layout(Vec2::new(80, 20));
+        assert_eq!(wizard.current_step(), 0);
+    }
+}